@@ -0,0 +1,71 @@
+//! Memory retained by a large job response.
+//!
+//! Run with and without the `compact` feature to compare:
+//!
+//! ```sh
+//! cargo bench --bench compact
+//! cargo bench --bench compact --features compact
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::PackageStatus;
+
+const PACKAGES: usize = 50_000;
+const LICENSES: &[&str] = &["MIT", "Apache-2.0", "BSD-3-Clause", "ISC", "GPL-3.0-only"];
+
+struct CountingAllocator;
+
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        LIVE_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn job_json() -> String {
+    let packages: Vec<String> = (0..PACKAGES)
+        .map(|i| {
+            format!(
+                r#"{{"name":"package-{i}","version":"1.0.{i}","status":"complete","last_updated":1650000000,"license":"{}","package_score":0.9,"num_dependencies":3}}"#,
+                LICENSES[i % LICENSES.len()]
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{"job_id":"59482a54-423b-448d-8325-f171c9dc336b","ecosystems":["npm"],"user_id":"86f8f5ab-1f18-4a4d-b2c4-d5d9c5b1a3f0","user_email":"developer@example.com","created_at":1650000000,"status":"complete","pass":true,"msg":"Project met threshold requirements","num_incomplete":0,"last_updated":1650000123,"project":"0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8","project_name":"storefront","label":null,"packages":[{}]}}"#,
+        packages.join(",")
+    )
+}
+
+fn main() {
+    let json = job_json();
+
+    let before = LIVE_BYTES.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let job: JobStatusResponse<PackageStatus> = serde_json::from_str(&json).unwrap();
+    let elapsed = start.elapsed();
+    let retained = LIVE_BYTES.load(Ordering::Relaxed) - before;
+
+    println!(
+        "compact: {}, packages: {}, retained: {} KiB, deserialized in {:?}",
+        cfg!(feature = "compact"),
+        job.packages.len(),
+        retained / 1024,
+        elapsed
+    );
+}
@@ -0,0 +1,54 @@
+//! Time to parse a large job response with serde_json and with simd-json.
+//!
+//! ```sh
+//! cargo bench --bench parse --features simd-json
+//! ```
+
+use std::time::{Duration, Instant};
+
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::PackageStatus;
+
+const PACKAGES: usize = 50_000;
+const ROUNDS: u32 = 10;
+
+fn job_json() -> String {
+    let packages: Vec<String> = (0..PACKAGES)
+        .map(|i| {
+            format!(
+                r#"{{"name":"package-{i}","version":"1.0.{i}","status":"complete","last_updated":1650000000,"license":"MIT","package_score":0.9,"num_dependencies":3}}"#
+            )
+        })
+        .collect();
+
+    format!(
+        r#"{{"job_id":"59482a54-423b-448d-8325-f171c9dc336b","ecosystems":["npm"],"user_id":"86f8f5ab-1f18-4a4d-b2c4-d5d9c5b1a3f0","user_email":"developer@example.com","created_at":1650000000,"status":"complete","pass":true,"msg":"Project met threshold requirements","num_incomplete":0,"last_updated":1650000123,"project":"0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8","project_name":"storefront","label":null,"packages":[{}]}}"#,
+        packages.join(",")
+    )
+}
+
+fn time(mut parse: impl FnMut() -> JobStatusResponse<PackageStatus>) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        assert_eq!(parse().packages.len(), PACKAGES);
+    }
+    start.elapsed() / ROUNDS
+}
+
+fn main() {
+    let json = job_json();
+
+    let serde_json = time(|| serde_json::from_str(&json).unwrap());
+    let simd_json = time(|| {
+        // simd-json parses in place, so every round needs a fresh buffer
+        let mut bytes = json.clone().into_bytes();
+        JobStatusResponse::from_slice(&mut bytes).unwrap()
+    });
+
+    println!(
+        "{} KiB, serde_json: {:?}, simd-json: {:?}",
+        json.len() / 1024,
+        serde_json,
+        simd_json
+    );
+}
@@ -0,0 +1,18 @@
+//! Sets `cfg(deny_unknown_fields)` when API types reject unknown fields: with
+//! the `strict` feature, unless `preserve-unknown` captures them instead.
+//!
+//! Types opt in with
+//! `#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]`, so the
+//! condition lives here rather than on each of them.
+
+use std::env;
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(deny_unknown_fields)");
+
+    let strict = env::var_os("CARGO_FEATURE_STRICT").is_some();
+    let preserve_unknown = env::var_os("CARGO_FEATURE_PRESERVE_UNKNOWN").is_some();
+    if strict && !preserve_unknown {
+        println!("cargo:rustc-cfg=deny_unknown_fields");
+    }
+}
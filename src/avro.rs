@@ -0,0 +1,619 @@
+//! Avro schemas for landing API responses in Avro encoded streams, such as
+//! Kafka topics.
+//!
+//! The schemas are derived from the JSON schemas of the types, so they stay
+//! in step with the serde definitions. Records have the wire field names,
+//! fields which may be absent are unions with `null`, and values of any
+//! shape, such as raw heuristic output, are strings carrying the JSON text.
+//! Those strings are marked with a `"format": "json"` attribute, which Avro
+//! keeps as metadata.
+//!
+//! The JSON serialization of a type is not Avro's JSON encoding, which tags
+//! the branch of every union. [`to_avro_json`] converts between the two, and
+//! [`validate`] checks that a serialized value can be converted.
+//!
+//! ```
+//! use phylum_types::avro;
+//! use phylum_types::types::package::Package;
+//!
+//! let schema = avro::schema_for::<Package>();
+//! assert_eq!(schema["type"], "record");
+//! assert_eq!(schema["name"], "Package");
+//!
+//! let package = Package { name: "left-pad".into(), ..Default::default() };
+//! let value = serde_json::to_value(&package).unwrap();
+//! assert!(avro::validate(&schema, &value).is_ok());
+//!
+//! let encoded = avro::to_avro_json(&schema, &value).unwrap();
+//! // Optional fields are unions with `null`, tagged with the branch taken
+//! assert_eq!(encoded["name"], serde_json::json!({ "string": "left-pad" }));
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use schemars::gen::SchemaGenerator;
+use schemars::JsonSchema;
+use serde_json::{json, Map, Value};
+
+/// Namespace of every named type in the generated schemas
+pub const NAMESPACE: &str = "phylum";
+
+/// The Avro schema of `T`, named after its JSON schema.
+pub fn schema_for<T: JsonSchema>() -> Value {
+    let name = T::schema_name();
+    let root = SchemaGenerator::default().into_root_schema_for::<T>();
+    let mut root = match serde_json::to_value(root) {
+        Ok(Value::Object(root)) => root,
+        _ => return json_text(),
+    };
+    let definitions = match root.remove("definitions") {
+        Some(Value::Object(definitions)) => definitions,
+        _ => Map::new(),
+    };
+
+    let mut converter = Converter::new(definitions);
+    converter.reserved.insert(sanitize(&name));
+    converter.emit(&name, &Value::Object(root))
+}
+
+/// Strings holding JSON text, for values of any shape.
+fn json_text() -> Value {
+    json!({ "type": "string", "format": "json" })
+}
+
+fn is_json_text(object: &Map<String, Value>) -> bool {
+    object.get("type").and_then(Value::as_str) == Some("string")
+        && object.get("format").and_then(Value::as_str) == Some("json")
+}
+
+/// A valid Avro name, with anything else replaced by underscores.
+fn sanitize(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && sanitize(name) == name
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+struct Converter {
+    definitions: Map<String, Value>,
+    /// Names of definitions, kept free for them
+    reserved: BTreeSet<String>,
+    /// Names given to anonymous types
+    used: BTreeSet<String>,
+    /// Full names of the named definitions already written
+    emitted: BTreeMap<String, Value>,
+}
+
+impl Converter {
+    fn new(definitions: Map<String, Value>) -> Self {
+        let reserved = definitions.keys().map(|name| sanitize(name)).collect();
+        Self {
+            definitions,
+            reserved,
+            used: BTreeSet::new(),
+            emitted: BTreeMap::new(),
+        }
+    }
+
+    /// An unused name for an anonymous type.
+    fn fresh(&mut self, name: &str) -> String {
+        let base = sanitize(name);
+        let mut name = base.clone();
+        let mut counter = 1;
+        while self.reserved.contains(&name) || self.used.contains(&name) {
+            counter += 1;
+            name = format!("{base}{counter}");
+        }
+        self.used.insert(name.clone());
+        name
+    }
+
+    /// A definition in full on its first use, and by name afterwards.
+    fn definition(&mut self, name: &str) -> Value {
+        if let Some(emitted) = self.emitted.get(name) {
+            return emitted.clone();
+        }
+        match self.definitions.get(name).cloned() {
+            Some(schema) => self.emit(name, &schema),
+            None => json_text(),
+        }
+    }
+
+    fn emit(&mut self, name: &str, schema: &Value) -> Value {
+        // Refer to the type by name while converting it, for recursive types
+        let avro_name = sanitize(name);
+        let full_name = Value::String(format!("{NAMESPACE}.{avro_name}"));
+        self.emitted.insert(name.into(), full_name.clone());
+
+        let converted = self.convert(schema, &avro_name);
+        let is_named = matches!(converted["type"].as_str(), Some("record" | "enum"));
+        if !is_named {
+            // Converted again on each use, as it may define named types which
+            // later uses must only refer to
+            self.emitted.remove(name);
+        }
+        converted
+    }
+
+    /// Convert a JSON schema, using `name` should it become a named type.
+    fn convert(&mut self, schema: &Value, name: &str) -> Value {
+        let object = match schema {
+            Value::Object(object) => object,
+            _ => return json_text(),
+        };
+
+        if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            return self.definition(reference.trim_start_matches("#/definitions/"));
+        }
+        if let Some([schema]) = object
+            .get("allOf")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+        {
+            return self.convert(schema, name);
+        }
+        let branches = object.get("anyOf").or_else(|| object.get("oneOf"));
+        if let Some(branches) = branches.and_then(Value::as_array) {
+            return self.union(branches, object, name);
+        }
+
+        match object.get("type") {
+            Some(Value::String(instance_type)) => self.typed(instance_type, object, name),
+            Some(Value::Array(instance_types)) => {
+                let branches: Vec<_> = instance_types
+                    .iter()
+                    .map(|instance_type| {
+                        let mut branch = object.clone();
+                        branch.insert("type".into(), instance_type.clone());
+                        Value::Object(branch)
+                    })
+                    .collect();
+                self.union(&branches, object, name)
+            }
+            _ => json_text(),
+        }
+    }
+
+    fn union(&mut self, branches: &[Value], object: &Map<String, Value>, name: &str) -> Value {
+        // Enums with documented variants are a union of single symbol enums
+        let symbols: Option<Vec<Value>> = branches
+            .iter()
+            .map(
+                |branch| match (branch["type"].as_str(), branch["enum"].as_array()) {
+                    (Some("string"), Some(symbols)) => Some(symbols.clone()),
+                    _ => None,
+                },
+            )
+            .collect::<Option<Vec<_>>>()
+            .map(|symbols| symbols.concat());
+        if let Some(symbols) = symbols {
+            let mut merged = object.clone();
+            merged.remove("anyOf");
+            merged.remove("oneOf");
+            merged.insert("enum".into(), Value::Array(symbols));
+            return self.typed("string", &merged, name);
+        }
+
+        let mut types = Vec::new();
+        let single = branches.len() == 1;
+        for branch in branches {
+            let branch_name = if single {
+                name.to_owned()
+            } else {
+                self.fresh(name)
+            };
+            match self.convert(branch, &branch_name) {
+                Value::Array(nested) => types.extend(nested),
+                converted => types.push(converted),
+            }
+        }
+
+        let mut union: Vec<Value> = Vec::new();
+        for branch in types {
+            if !union.contains(&branch) {
+                union.push(branch);
+            }
+        }
+        if let Some(null) = union.iter().position(|branch| branch == "null") {
+            union.remove(null);
+            union.insert(0, "null".into());
+        }
+        match union.len() {
+            1 => union.remove(0),
+            _ => Value::Array(union),
+        }
+    }
+
+    fn typed(&mut self, instance_type: &str, object: &Map<String, Value>, name: &str) -> Value {
+        match instance_type {
+            "null" => "null".into(),
+            "boolean" => "boolean".into(),
+            "integer" => "long".into(),
+            "number" if object.get("format").and_then(Value::as_str) == Some("float") => {
+                "float".into()
+            }
+            "number" => "double".into(),
+            "string" => match object.get("enum").and_then(Value::as_array) {
+                Some(symbols) => self.enumeration(symbols, object, name),
+                None => "string".into(),
+            },
+            "array" => {
+                let items = match object.get("items") {
+                    Some(items) => {
+                        let item_name = self.fresh(&format!("{name}Item"));
+                        self.convert(items, &item_name)
+                    }
+                    None => json_text(),
+                };
+                json!({ "type": "array", "items": items })
+            }
+            "object" => match object.get("properties").and_then(Value::as_object) {
+                Some(properties) if !properties.is_empty() => self.record(properties, object, name),
+                _ => {
+                    let values = match object.get("additionalProperties") {
+                        Some(Value::Object(values)) => {
+                            let value_name = self.fresh(&format!("{name}Value"));
+                            self.convert(&Value::Object(values.clone()), &value_name)
+                        }
+                        _ => json_text(),
+                    };
+                    json!({ "type": "map", "values": values })
+                }
+            },
+            _ => json_text(),
+        }
+    }
+
+    fn enumeration(&mut self, symbols: &[Value], object: &Map<String, Value>, name: &str) -> Value {
+        let symbols: Option<Vec<&str>> = symbols.iter().map(Value::as_str).collect();
+        match symbols {
+            Some(symbols) if symbols.iter().all(|symbol| is_valid_name(symbol)) => {
+                let mut enumeration = json!({
+                    "type": "enum",
+                    "name": name,
+                    "namespace": NAMESPACE,
+                    "symbols": symbols,
+                });
+                with_doc(&mut enumeration, object);
+                enumeration
+            }
+            _ => "string".into(),
+        }
+    }
+
+    fn record(
+        &mut self,
+        properties: &Map<String, Value>,
+        object: &Map<String, Value>,
+        name: &str,
+    ) -> Value {
+        let required: BTreeSet<&str> = object
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let fields: Vec<Value> = properties
+            .iter()
+            .map(|(field_name, schema)| {
+                let type_name = self.fresh(&format!("{name}{}", pascal_case(field_name)));
+                let mut field_type = self.convert(schema, &type_name);
+                let mut field = json!({ "name": field_name });
+                if !required.contains(field_name.as_str()) {
+                    field_type = nullable(field_type);
+                    field["default"] = Value::Null;
+                }
+                field["type"] = field_type;
+                if let Some(schema) = schema.as_object() {
+                    with_doc(&mut field, schema);
+                }
+                field
+            })
+            .collect();
+
+        let mut record = json!({
+            "type": "record",
+            "name": name,
+            "namespace": NAMESPACE,
+            "fields": fields,
+        });
+        with_doc(&mut record, object);
+        record
+    }
+}
+
+/// A union of `schema` and `null`, with `null` first so it can be the
+/// default.
+fn nullable(schema: Value) -> Value {
+    let mut union = match schema {
+        Value::Array(union) => union,
+        schema => vec![schema],
+    };
+    union.retain(|branch| branch != "null");
+    union.insert(0, "null".into());
+    Value::Array(union)
+}
+
+fn with_doc(schema: &mut Value, object: &Map<String, Value>) {
+    if let Some(description) = object.get("description") {
+        schema["doc"] = description.clone();
+    }
+}
+
+/// A serialized value which does not fit a schema
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Mismatch {
+    /// Location of the value, such as `packages[0].license`
+    pub path: String,
+    /// What the schema allows there
+    pub expected: String,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "value"
+        } else {
+            &self.path
+        };
+        write!(
+            f,
+            "{path} does not match the schema: expected {}",
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Check that a serialized value, such as the JSON of an API response, fits
+/// an Avro schema.
+///
+/// Absent record fields are taken to be `null`, and fields which the schema
+/// does not have are mismatches.
+pub fn validate(schema: &Value, value: &Value) -> Result<(), Mismatch> {
+    to_avro_json(schema, value).map(|_| ())
+}
+
+/// Convert a serialized value, such as the JSON of an API response, to the
+/// Avro JSON encoding of an Avro schema.
+///
+/// Values in unions other than `null` are wrapped in an object keyed by the
+/// name of their branch, every record field is written, with absent ones as
+/// `null`, and values of any shape become their JSON text.
+pub fn to_avro_json(schema: &Value, value: &Value) -> Result<Value, Mismatch> {
+    let mut named = BTreeMap::new();
+    collect_named(schema, &mut named);
+    Encoder { named }.encode(schema, value, "")
+}
+
+fn collect_named<'a>(schema: &'a Value, named: &mut BTreeMap<String, &'a Value>) {
+    match schema {
+        Value::Array(union) => union.iter().for_each(|branch| collect_named(branch, named)),
+        Value::Object(object) => {
+            if let Some(name) = object.get("name").and_then(Value::as_str) {
+                let namespace = object.get("namespace").and_then(Value::as_str);
+                let full_name = match namespace {
+                    Some(namespace) => format!("{namespace}.{name}"),
+                    None => name.to_owned(),
+                };
+                named.insert(full_name, schema);
+            }
+            for key in ["items", "values"] {
+                if let Some(nested) = object.get(key) {
+                    collect_named(nested, named);
+                }
+            }
+            for field in object
+                .get("fields")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+            {
+                collect_named(&field["type"], named);
+            }
+        }
+        _ => (),
+    }
+}
+
+struct Encoder<'a> {
+    named: BTreeMap<String, &'a Value>,
+}
+
+impl Encoder<'_> {
+    fn resolve(&self, name: &str) -> Option<&Value> {
+        let full_name = format!("{NAMESPACE}.{name}");
+        self.named
+            .get(name)
+            .or_else(|| self.named.get(&full_name))
+            .copied()
+    }
+
+    fn encode(&self, schema: &Value, value: &Value, path: &str) -> Result<Value, Mismatch> {
+        let mismatch = |expected: &str| {
+            Err(Mismatch {
+                path: path.into(),
+                expected: expected.into(),
+            })
+        };
+
+        match schema {
+            Value::String(name) => {
+                let fits = match name.as_str() {
+                    "null" => value.is_null(),
+                    "boolean" => value.is_boolean(),
+                    "int" | "long" => value.is_i64() || value.is_u64(),
+                    "float" | "double" => value.is_number(),
+                    "string" | "bytes" => value.is_string(),
+                    name => {
+                        return match self.resolve(name) {
+                            Some(named) => self.encode(named, value, path),
+                            None => mismatch(&format!("the unknown type {name}")),
+                        };
+                    }
+                };
+                if fits {
+                    Ok(value.clone())
+                } else {
+                    mismatch(name)
+                }
+            }
+            Value::Array(union) => {
+                // Report the branch which got furthest into the value
+                let mut closest: Option<Mismatch> = None;
+                for branch in union {
+                    match self.encode(branch, value, path) {
+                        Ok(Value::Null) => return Ok(Value::Null),
+                        Ok(encoded) => {
+                            let mut tagged = Map::new();
+                            tagged.insert(self.branch_name(branch), encoded);
+                            return Ok(Value::Object(tagged));
+                        }
+                        Err(error) => {
+                            let further = closest
+                                .as_ref()
+                                .is_none_or(|closest| error.path.len() > closest.path.len());
+                            if further {
+                                closest = Some(error);
+                            }
+                        }
+                    }
+                }
+                match closest {
+                    Some(error) if error.path != path => Err(error),
+                    _ => mismatch(&format!("one of {}", Value::Array(union.clone()))),
+                }
+            }
+            Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+                _ if is_json_text(object) => Ok(Value::String(value.to_string())),
+                Some("record") => self.encode_record(object, value, path),
+                Some("enum") => {
+                    let symbols = object["symbols"].as_array().into_iter().flatten();
+                    if symbols.into_iter().any(|symbol| symbol == value) {
+                        Ok(value.clone())
+                    } else {
+                        mismatch(&format!("a symbol of {}", object["name"]))
+                    }
+                }
+                Some("array") => match value.as_array() {
+                    Some(items) => items
+                        .iter()
+                        .enumerate()
+                        .map(|(index, item)| {
+                            self.encode(&object["items"], item, &format!("{path}[{index}]"))
+                        })
+                        .collect::<Result<_, _>>()
+                        .map(Value::Array),
+                    None => mismatch("array"),
+                },
+                Some("map") => match value.as_object() {
+                    Some(entries) => entries
+                        .iter()
+                        .map(|(key, entry)| {
+                            let encoded = self.encode(&object["values"], entry, &child(path, key));
+                            encoded.map(|encoded| (key.clone(), encoded))
+                        })
+                        .collect::<Result<_, _>>()
+                        .map(Value::Object),
+                    None => mismatch("map"),
+                },
+                Some(primitive) => self.encode(&primitive.into(), value, path),
+                None => mismatch("a valid schema"),
+            },
+            _ => mismatch("a valid schema"),
+        }
+    }
+
+    /// The name tagging values of a union branch in the JSON encoding.
+    fn branch_name(&self, branch: &Value) -> String {
+        match branch {
+            Value::String(name) => match self.resolve(name) {
+                Some(named) => self.branch_name(named),
+                None => name.clone(),
+            },
+            Value::Object(object) => match object.get("type").and_then(Value::as_str) {
+                Some("record" | "enum") => {
+                    let name = object["name"].as_str().unwrap_or_default();
+                    match object.get("namespace").and_then(Value::as_str) {
+                        Some(namespace) => format!("{namespace}.{name}"),
+                        None => name.to_owned(),
+                    }
+                }
+                Some(instance_type) => instance_type.to_owned(),
+                None => String::new(),
+            },
+            _ => String::new(),
+        }
+    }
+
+    fn encode_record(
+        &self,
+        record: &Map<String, Value>,
+        value: &Value,
+        path: &str,
+    ) -> Result<Value, Mismatch> {
+        let object = match value.as_object() {
+            Some(object) => object,
+            None => {
+                return Err(Mismatch {
+                    path: path.into(),
+                    expected: format!("record {}", record["name"]),
+                })
+            }
+        };
+
+        let fields = record["fields"]
+            .as_array()
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let mut encoded = Map::new();
+        for field in fields {
+            let name = field["name"].as_str().unwrap_or_default();
+            let field_value = object.get(name).unwrap_or(&Value::Null);
+            let field_value = self.encode(&field["type"], field_value, &child(path, name))?;
+            encoded.insert(name.to_owned(), field_value);
+        }
+
+        match object
+            .keys()
+            .find(|key| !fields.iter().any(|field| field["name"] == **key))
+        {
+            Some(key) => Err(Mismatch {
+                path: child(path, key),
+                expected: format!("no such field in record {}", record["name"]),
+            }),
+            None => Ok(Value::Object(encoded)),
+        }
+    }
+}
+
+fn child(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.into()
+    } else {
+        format!("{path}.{name}")
+    }
+}
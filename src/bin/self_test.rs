@@ -0,0 +1,172 @@
+//! Round-trips every bundled fixture, and optionally API captures, through
+//! the types of this crate and reports any data which is lost or changed.
+//!
+//! Usage: `phylum-types-self-test [CAPTURE_DIR]`
+//!
+//! Captured files are matched to types by file name prefix, using the same
+//! names as the bundled fixtures (e.g. `package-npm-express.json`).
+
+use std::path::Path;
+use std::process::ExitCode;
+use std::{env, fs};
+
+use phylum_types::fixtures;
+use phylum_types::types::common::ApiErrorResponse;
+use phylum_types::types::job::{
+    AllJobsStatusResponse, JobStatusResponseVariant, SubmitPackageRequest, SubmitPackageResponse,
+};
+use phylum_types::types::package::Package;
+use phylum_types::types::project::ProjectSummaryResponse;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+type Check = fn(&str) -> Result<Vec<String>, String>;
+
+/// Type checks by file name prefix; longer prefixes must come first.
+const CHECKS: &[(&str, Check)] = &[
+    (
+        "all_jobs_status_response",
+        round_trip::<AllJobsStatusResponse>,
+    ),
+    ("api_error", round_trip::<ApiErrorResponse>),
+    ("job_status", round_trip::<JobStatusResponseVariant>),
+    ("package", round_trip::<Package>),
+    (
+        "project_summaries",
+        round_trip::<Vec<ProjectSummaryResponse>>,
+    ),
+    ("submit_package_request", round_trip::<SubmitPackageRequest>),
+    (
+        "submit_package_response",
+        round_trip::<SubmitPackageResponse>,
+    ),
+];
+
+fn main() -> ExitCode {
+    let mut inputs: Vec<(String, String)> = fixtures::ALL
+        .iter()
+        .map(|(name, json)| (format!("fixture {name}"), json.to_string()))
+        .collect();
+
+    if let Some(dir) = env::args().nth(1) {
+        match read_captures(Path::new(&dir)) {
+            Ok(captures) => inputs.extend(captures),
+            Err(err) => {
+                eprintln!("Unable to read {dir}: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let mut failures = 0;
+    for (name, json) in &inputs {
+        let file_name = name.rsplit(['/', ' ']).next().unwrap_or(name);
+        let check = CHECKS
+            .iter()
+            .find(|(prefix, _)| file_name.starts_with(prefix))
+            .map(|(_, check)| check);
+
+        let result = match check {
+            Some(check) => check(json),
+            None => {
+                println!("SKIP {name}: no type matches this file name");
+                continue;
+            }
+        };
+
+        match result {
+            Ok(differences) if differences.is_empty() => println!("OK   {name}"),
+            Ok(differences) => {
+                failures += 1;
+                println!("DIFF {name}");
+                for difference in differences {
+                    println!("       {difference}");
+                }
+            }
+            Err(err) => {
+                failures += 1;
+                println!("FAIL {name}: {err}");
+            }
+        }
+    }
+
+    println!("{} checked, {failures} failed", inputs.len());
+    if failures == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read_captures(dir: &Path) -> std::io::Result<Vec<(String, String)>> {
+    let mut captures = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "json") {
+            captures.push((path.display().to_string(), fs::read_to_string(&path)?));
+        }
+    }
+    captures.sort();
+    Ok(captures)
+}
+
+/// Deserialize `json` as `T`, serialize it again and compare the results.
+fn round_trip<T: DeserializeOwned + Serialize>(json: &str) -> Result<Vec<String>, String> {
+    let original: Value =
+        serde_json::from_str(json).map_err(|err| format!("invalid JSON: {err}"))?;
+    let parsed: T = serde_json::from_value(original.clone()).map_err(|err| err.to_string())?;
+    let reserialized = serde_json::to_value(parsed).map_err(|err| err.to_string())?;
+
+    let mut differences = Vec::new();
+    compare("$", &original, &reserialized, &mut differences);
+    Ok(differences)
+}
+
+/// Record every semantic difference between two JSON values.
+///
+/// A missing key, a `null` value and an empty collection are treated as
+/// equal, as are numbers which only differ by their representation or by the
+/// precision lost to `f32` fields.
+fn compare(path: &str, old: &Value, new: &Value, differences: &mut Vec<String>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let keys = old
+                .keys()
+                .chain(new.keys().filter(|key| !old.contains_key(*key)));
+            for key in keys {
+                let old = old.get(key).unwrap_or(&Value::Null);
+                let new = new.get(key).unwrap_or(&Value::Null);
+                compare(&format!("{path}.{key}"), old, new, differences);
+            }
+        }
+        (Value::Array(old_items), Value::Array(new_items))
+            if old_items.len() == new_items.len() =>
+        {
+            for (i, (old, new)) in old_items.iter().zip(new_items).enumerate() {
+                compare(&format!("{path}[{i}]"), old, new, differences);
+            }
+        }
+        (Value::Number(old), Value::Number(new)) if same_number(old, new) => (),
+        (old, new) if old == new || (is_empty(old) && is_empty(new)) => (),
+        (Value::Null, new) => differences.push(format!("{path}: added {new}")),
+        (old, Value::Null) => differences.push(format!("{path}: dropped {old}")),
+        (old, new) => differences.push(format!("{path}: {old} became {new}")),
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(entries) => entries.is_empty(),
+        _ => false,
+    }
+}
+
+fn same_number(old: &serde_json::Number, new: &serde_json::Number) -> bool {
+    match (old.as_f64(), new.as_f64()) {
+        (Some(old), Some(new)) => (old - new).abs() <= f64::from(f32::EPSILON) * old.abs().max(1.),
+        _ => old == new,
+    }
+}
@@ -0,0 +1,83 @@
+//! Transport of API types in binary formats.
+//!
+//! All types round-trip through self-describing binary formats, such as CBOR
+//! and MessagePack. MessagePack must encode structs as maps, with
+//! `rmp_serde::to_vec_named`, since fields are skipped when empty.
+//!
+//! Formats which are not self-describing, such as bincode, cannot represent
+//! skipped fields, flattened fields or untagged enums. Wrap values in
+//! [`Embedded`] to carry them inside such formats:
+//!
+//! ```
+//! use phylum_types::binary::Embedded;
+//! use phylum_types::types::package::{PackageRef, PackageType};
+//!
+//! let package = PackageRef::new("lodash", "4.17.21", PackageType::Npm);
+//! let bytes = bincode::serialize(&Embedded(&package)).unwrap();
+//! let Embedded(decoded): Embedded<PackageRef> = bincode::deserialize(&bytes).unwrap();
+//! assert_eq!(decoded, package);
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, DeserializeOwned, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A value serialized as CBOR bytes, so that it survives formats which are
+/// not self-describing
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default)]
+pub struct Embedded<T>(pub T);
+
+impl<T> Embedded<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Serialize> Serialize for Embedded<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&self.0, &mut bytes).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Embedded<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(EmbeddedVisitor(Default::default()))
+    }
+}
+
+struct EmbeddedVisitor<T>(std::marker::PhantomData<T>);
+
+impl<T> EmbeddedVisitor<T> {
+    fn decode<E: de::Error>(bytes: &[u8]) -> Result<Embedded<T>, E>
+    where
+        T: DeserializeOwned,
+    {
+        ciborium::de::from_reader(bytes)
+            .map(Embedded)
+            .map_err(E::custom)
+    }
+}
+
+impl<'de, T: DeserializeOwned> Visitor<'de> for EmbeddedVisitor<T> {
+    type Value = Embedded<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("CBOR encoded bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+        Self::decode(bytes)
+    }
+
+    // Formats without a byte type, such as JSON, encode bytes as a sequence
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Self::decode(&bytes)
+    }
+}
@@ -0,0 +1,192 @@
+//! Encoding of request and response bodies, shared by HTTP clients.
+//!
+//! The helpers work on bytes and header values only, so blocking and async
+//! clients alike can use them without this crate depending on either:
+//!
+//! ```
+//! use phylum_types::body::{BodyError, ContentType, FromBody, ToBody};
+//! use phylum_types::types::common::ErrorCode;
+//! use phylum_types::types::job::{SubmitPackageResponse, AllJobsStatusResponse};
+//!
+//! let content_type = ContentType::from_header("application/json; charset=utf-8").unwrap();
+//! let body = br#"{"job_id": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"}"#;
+//! let response = SubmitPackageResponse::from_body(content_type, body).unwrap();
+//! assert_eq!(response.to_body(content_type).unwrap(), br#"{"job_id":"0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"}"#);
+//!
+//! let error = AllJobsStatusResponse::from_response(404, None, b"").unwrap_err();
+//! assert!(matches!(error, BodyError::Api(error) if error.code == ErrorCode::NotFound));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::types::common::{ApiError, ApiErrorResponse, ErrorCode};
+
+/// Encoding of a body
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+#[non_exhaustive]
+pub enum ContentType {
+    #[default]
+    Json,
+}
+
+impl ContentType {
+    /// The media type, for the `Content-Type` and `Accept` headers.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContentType::Json => "application/json",
+        }
+    }
+
+    /// The encoding of a `Content-Type` header value, ignoring parameters such
+    /// as `charset`.
+    pub fn from_header(value: &str) -> Option<Self> {
+        let media_type = value.split(';').next().unwrap_or_default().trim();
+        let media_type = media_type.to_ascii_lowercase();
+        match media_type.as_str() {
+            "application/json" => Some(ContentType::Json),
+            // Structured syntax suffixes, such as `application/problem+json`
+            other if other.starts_with("application/") && other.ends_with("+json") => {
+                Some(ContentType::Json)
+            }
+            _ => None,
+        }
+    }
+
+    /// The preferred supported encoding of an `Accept` header value.
+    ///
+    /// Media ranges are taken in order of their `q` parameter, and wildcards
+    /// select JSON.
+    pub fn negotiate(accept: &str) -> Option<Self> {
+        let mut ranges: Vec<(f32, &str)> = accept
+            .split(',')
+            .map(|range| {
+                let mut parts = range.split(';');
+                let media_range = parts.next().unwrap_or_default().trim();
+                let quality = parts
+                    .filter_map(|parameter| parameter.trim().strip_prefix("q="))
+                    .find_map(|quality| quality.parse().ok())
+                    .unwrap_or(1.0);
+                (quality, media_range)
+            })
+            .filter(|(quality, _)| *quality > 0.0)
+            .collect();
+        // Stable, so ranges of equal quality keep their order
+        ranges.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+
+        ranges
+            .into_iter()
+            .find_map(|(_, media_range)| match media_range {
+                "*/*" | "application/*" => Some(ContentType::Json),
+                media_range => Self::from_header(media_range),
+            })
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContentType {
+    type Err = BodyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_header(s).ok_or_else(|| BodyError::UnsupportedContentType(s.into()))
+    }
+}
+
+/// Reasons a body could not be encoded or decoded.
+#[derive(Debug)]
+pub enum BodyError {
+    /// The body has an encoding this crate cannot handle
+    UnsupportedContentType(String),
+    Encode(serde_json::Error),
+    Decode(serde_json::Error),
+    /// The API responded with an error
+    Api(ApiError),
+}
+
+impl fmt::Display for BodyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BodyError::UnsupportedContentType(content_type) => {
+                write!(f, "unsupported content type {content_type}")
+            }
+            BodyError::Encode(error) => write!(f, "failed to encode body: {error}"),
+            BodyError::Decode(error) => write!(f, "failed to decode body: {error}"),
+            BodyError::Api(error) => write!(f, "API error {error}"),
+        }
+    }
+}
+
+impl std::error::Error for BodyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BodyError::Encode(error) | BodyError::Decode(error) => Some(error),
+            BodyError::Api(error) => Some(error),
+            BodyError::UnsupportedContentType(_) => None,
+        }
+    }
+}
+
+impl From<ApiError> for BodyError {
+    fn from(error: ApiError) -> Self {
+        BodyError::Api(error)
+    }
+}
+
+/// Encoding of request bodies
+pub trait ToBody: Serialize {
+    fn to_body(&self, content_type: ContentType) -> Result<Vec<u8>, BodyError> {
+        match content_type {
+            ContentType::Json => serde_json::to_vec(self).map_err(BodyError::Encode),
+        }
+    }
+}
+
+impl<T: Serialize + ?Sized> ToBody for T {}
+
+/// Decoding of response bodies
+pub trait FromBody: DeserializeOwned {
+    fn from_body(content_type: ContentType, body: &[u8]) -> Result<Self, BodyError> {
+        match content_type {
+            ContentType::Json => serde_json::from_slice(body).map_err(BodyError::Decode),
+        }
+    }
+
+    /// Decode a whole response, mapping unsuccessful statuses to
+    /// [`BodyError::Api`].
+    ///
+    /// A missing `Content-Type` header is taken to be JSON. Error responses
+    /// without a readable error body get the code matching their status.
+    fn from_response(
+        status: u16,
+        content_type: Option<&str>,
+        body: &[u8],
+    ) -> Result<Self, BodyError> {
+        let parsed_type = content_type.map_or(Some(ContentType::Json), ContentType::from_header);
+
+        if (200..300).contains(&status) {
+            let content_type = parsed_type.ok_or_else(|| {
+                BodyError::UnsupportedContentType(content_type.unwrap_or_default().into())
+            })?;
+            return Self::from_body(content_type, body);
+        }
+
+        let error = parsed_type
+            .and_then(|content_type| ApiErrorResponse::from_body(content_type, body).ok())
+            .map(|response| response.error)
+            .unwrap_or_else(|| ApiError {
+                code: ErrorCode::from_status(status),
+                description: String::from_utf8_lossy(body).trim().into(),
+            });
+        Err(BodyError::Api(error))
+    }
+}
+
+impl<T: DeserializeOwned> FromBody for T {}
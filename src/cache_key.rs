@@ -0,0 +1,155 @@
+//! Stable string keys for caching API data.
+//!
+//! Keys are versioned so their format can change without old cache entries
+//! being misread, and every component is escaped so names containing `:`,
+//! such as Maven coordinates, still round-trip:
+//!
+//! ```
+//! use phylum_types::cache_key::CacheKey;
+//! use phylum_types::types::package::{PackageDescriptor, PackageType};
+//!
+//! let key = CacheKey::from(PackageDescriptor::new("left-pad", "1.3.0", PackageType::Npm));
+//! assert_eq!(key.to_string(), "v1:package:npm:left-pad:1.3.0");
+//! assert_eq!(key, "v1:package:npm:left-pad:1.3.0".parse().unwrap());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use uuid::Uuid;
+
+use crate::types::common::JobId;
+#[allow(deprecated)]
+use crate::types::package::{PackageDescriptor, PackageType};
+
+/// Version of the key format produced by this crate.
+pub const KEY_VERSION: &str = "v1";
+
+/// A cache key for a piece of API data.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub enum CacheKey {
+    /// A package version, identified by ecosystem, full name and version
+    Package {
+        package_type: PackageType,
+        name: String,
+        version: String,
+    },
+    Job(JobId),
+    Analysis(Uuid),
+}
+
+#[allow(deprecated)]
+impl From<&PackageDescriptor> for CacheKey {
+    fn from(descriptor: &PackageDescriptor) -> Self {
+        Self::Package {
+            package_type: descriptor.package_type,
+            name: descriptor.full_name(),
+            version: descriptor.version.clone(),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageDescriptor> for CacheKey {
+    fn from(descriptor: PackageDescriptor) -> Self {
+        Self::from(&descriptor)
+    }
+}
+
+#[allow(deprecated)]
+impl PackageDescriptor {
+    /// The cache key for this package.
+    ///
+    /// Packages have the same key whether their namespace is structured or
+    /// part of the name.
+    pub fn cache_key(&self) -> CacheKey {
+        self.into()
+    }
+}
+
+impl fmt::Display for CacheKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CacheKey::Package {
+                package_type,
+                name,
+                version,
+            } => write!(
+                f,
+                "{KEY_VERSION}:package:{package_type}:{}:{}",
+                escape(name),
+                escape(version)
+            ),
+            CacheKey::Job(id) => write!(f, "{KEY_VERSION}:job:{id}"),
+            CacheKey::Analysis(id) => write!(f, "{KEY_VERSION}:analysis:{id}"),
+        }
+    }
+}
+
+/// Reasons a string is not a valid cache key.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum CacheKeyError {
+    /// The key was produced by an unknown version of the key format
+    UnsupportedVersion(String),
+    /// The key does not match the expected format
+    Malformed(String),
+}
+
+impl fmt::Display for CacheKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CacheKeyError::UnsupportedVersion(version) => {
+                write!(f, "unsupported cache key version {version}")
+            }
+            CacheKeyError::Malformed(key) => write!(f, "malformed cache key {key}"),
+        }
+    }
+}
+
+impl std::error::Error for CacheKeyError {}
+
+impl FromStr for CacheKey {
+    type Err = CacheKeyError;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        let malformed = || CacheKeyError::Malformed(key.into());
+        let parts: Vec<&str> = key.split(':').collect();
+
+        match parts.as_slice() {
+            [KEY_VERSION, rest @ ..] => match rest {
+                ["package", package_type, name, version] => Ok(CacheKey::Package {
+                    package_type: package_type.parse().map_err(|()| malformed())?,
+                    name: unescape(name).ok_or_else(malformed)?,
+                    version: unescape(version).ok_or_else(malformed)?,
+                }),
+                ["job", id] => id.parse().map(CacheKey::Job).map_err(|_| malformed()),
+                ["analysis", id] => id.parse().map(CacheKey::Analysis).map_err(|_| malformed()),
+                _ => Err(malformed()),
+            },
+            [version, ..] if version.starts_with('v') => {
+                Err(CacheKeyError::UnsupportedVersion(version.to_string()))
+            }
+            _ => Err(malformed()),
+        }
+    }
+}
+
+fn escape(component: &str) -> String {
+    component.replace('%', "%25").replace(':', "%3A")
+}
+
+fn unescape(component: &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(component.len());
+    let mut rest = component;
+    while let Some(i) = rest.find('%') {
+        unescaped.push_str(&rest[..i]);
+        match rest.get(i + 1..i + 3) {
+            Some("25") => unescaped.push('%'),
+            Some("3A") => unescaped.push(':'),
+            _ => return None,
+        }
+        rest = &rest[i + 3..];
+    }
+    unescaped.push_str(rest);
+    Some(unescaped)
+}
@@ -0,0 +1,199 @@
+//! Which API responses may be cached, and for how long.
+//!
+//! Finished jobs and analyzed packages never change, so they can be kept
+//! forever. Listings change whenever a job is submitted and are only fresh
+//! briefly, while anything still in progress must be fetched again:
+//!
+//! ```
+//! use std::time::Duration;
+//!
+//! use phylum_types::cache_policy::{CachePolicy, Cacheable};
+//! use phylum_types::types::package::Package;
+//!
+//! let pending = Package::default();
+//! assert!(!pending.is_cacheable());
+//!
+//! let analyzed = Package { complete: Some(true), ..Default::default() };
+//! assert_eq!(analyzed.cache_policy(), CachePolicy::Immutable);
+//!
+//! // The server may ask for less, but never gets more than the type allows
+//! let policy = analyzed.cache_policy_with(Some("max-age=60"));
+//! assert_eq!(policy, CachePolicy::MaxAge(Duration::from_secs(60)));
+//! assert_eq!(pending.cache_policy_with(Some("immutable")), CachePolicy::NoStore);
+//! ```
+
+use std::fmt;
+use std::time::Duration;
+
+use crate::types::common::{ApiErrorResponse, Status};
+use crate::types::health::{HealthResponse, VersionResponse};
+use crate::types::job::{AllJobsStatusResponse, JobStatusResponse, JobStatusResponseVariant};
+use crate::types::package::{Package, PackageStatus, PackageStatusExtended};
+use crate::types::project::ProjectSummaryResponse;
+
+/// How long listings, such as recent jobs, are fresh for
+pub const LISTING_MAX_AGE: Duration = Duration::from_secs(30);
+
+/// Whether, and for how long, a response may be reused
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub enum CachePolicy {
+    /// Must be fetched again every time
+    NoStore,
+    /// Fresh for the given time after it was received
+    MaxAge(Duration),
+    /// Never changes
+    Immutable,
+}
+
+impl CachePolicy {
+    pub fn is_cacheable(&self) -> bool {
+        match self {
+            CachePolicy::NoStore => false,
+            CachePolicy::MaxAge(max_age) => !max_age.is_zero(),
+            CachePolicy::Immutable => true,
+        }
+    }
+
+    /// How long a response is fresh for; `None` if forever.
+    pub fn max_age(&self) -> Option<Duration> {
+        match self {
+            CachePolicy::NoStore => Some(Duration::ZERO),
+            CachePolicy::MaxAge(max_age) => Some(*max_age),
+            CachePolicy::Immutable => None,
+        }
+    }
+
+    /// The more restrictive of two policies.
+    pub fn min(self, other: Self) -> Self {
+        Ord::min(self, other)
+    }
+
+    /// The policy of a `Cache-Control` header value.
+    ///
+    /// `no-store`, `no-cache` and `private` forbid caching, and directives
+    /// this crate does not know are ignored. Without any known directive the
+    /// header allows anything.
+    pub fn from_header(cache_control: &str) -> Self {
+        let mut policy = CachePolicy::Immutable;
+        for directive in cache_control.split(',') {
+            let directive = directive.trim().to_ascii_lowercase();
+            let directive_policy = match directive.split_once('=') {
+                Some(("max-age", seconds)) => match seconds.trim_matches('"').parse() {
+                    Ok(seconds) => CachePolicy::MaxAge(Duration::from_secs(seconds)),
+                    Err(_) => CachePolicy::NoStore,
+                },
+                _ => match directive.as_str() {
+                    "no-store" | "no-cache" | "private" => CachePolicy::NoStore,
+                    _ => continue,
+                },
+            };
+            policy = policy.min(directive_policy);
+        }
+        policy
+    }
+}
+
+/// Formats as a `Cache-Control` header value.
+impl fmt::Display for CachePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CachePolicy::NoStore => f.write_str("no-store"),
+            CachePolicy::MaxAge(max_age) => write!(f, "max-age={}", max_age.as_secs()),
+            CachePolicy::Immutable => f.write_str("max-age=31536000, immutable"),
+        }
+    }
+}
+
+/// Responses which know whether they may be cached
+pub trait Cacheable {
+    /// The policy the contents of the response allow.
+    fn cache_policy(&self) -> CachePolicy;
+
+    fn is_cacheable(&self) -> bool {
+        self.cache_policy().is_cacheable()
+    }
+
+    /// The policy allowed by both the contents and the `Cache-Control`
+    /// header of the response.
+    fn cache_policy_with(&self, cache_control: Option<&str>) -> CachePolicy {
+        let policy = self.cache_policy();
+        match cache_control {
+            Some(cache_control) => policy.min(CachePolicy::from_header(cache_control)),
+            None => policy,
+        }
+    }
+}
+
+fn complete_or_no_store(status: Status) -> CachePolicy {
+    match status {
+        Status::Complete => CachePolicy::Immutable,
+        Status::Incomplete => CachePolicy::NoStore,
+    }
+}
+
+impl<T> Cacheable for JobStatusResponse<T> {
+    fn cache_policy(&self) -> CachePolicy {
+        complete_or_no_store(self.status)
+    }
+}
+
+impl Cacheable for JobStatusResponseVariant {
+    fn cache_policy(&self) -> CachePolicy {
+        match self {
+            JobStatusResponseVariant::Basic(job) => job.cache_policy(),
+            JobStatusResponseVariant::Extended(job) => job.cache_policy(),
+        }
+    }
+}
+
+impl Cacheable for PackageStatus {
+    fn cache_policy(&self) -> CachePolicy {
+        complete_or_no_store(self.status)
+    }
+}
+
+impl Cacheable for PackageStatusExtended {
+    fn cache_policy(&self) -> CachePolicy {
+        self.basic_status.cache_policy()
+    }
+}
+
+impl Cacheable for Package {
+    fn cache_policy(&self) -> CachePolicy {
+        if self.is_complete() {
+            CachePolicy::Immutable
+        } else {
+            CachePolicy::NoStore
+        }
+    }
+}
+
+impl Cacheable for AllJobsStatusResponse {
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::MaxAge(LISTING_MAX_AGE)
+    }
+}
+
+impl Cacheable for Vec<ProjectSummaryResponse> {
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::MaxAge(LISTING_MAX_AGE)
+    }
+}
+
+impl Cacheable for HealthResponse {
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::NoStore
+    }
+}
+
+impl Cacheable for VersionResponse {
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::MaxAge(LISTING_MAX_AGE)
+    }
+}
+
+impl Cacheable for ApiErrorResponse {
+    fn cache_policy(&self) -> CachePolicy {
+        CachePolicy::NoStore
+    }
+}
@@ -0,0 +1,438 @@
+//! Alternative field casing profiles for serialization.
+//!
+//! The API mixes camelCase and snake_case field names. Systems expecting a
+//! single convention can wrap any value in [`SnakeCase`] to serialize every
+//! struct field name as snake_case:
+//!
+//! ```
+//! use phylum_types::casing::SnakeCase;
+//! use phylum_types::types::package::PackageReleaseData;
+//!
+//! let release = PackageReleaseData {
+//!     first_release_date: "2020-01-01".into(),
+//!     last_release_date: "2022-01-01".into(),
+//! };
+//! let json = serde_json::to_string(&SnakeCase(&release)).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"first_release_date":"2020-01-01","last_release_date":"2022-01-01"}"#
+//! );
+//! ```
+//!
+//! Only struct field names are renamed. Map keys, such as package names in
+//! dependency maps, and enum values are emitted unchanged.
+//!
+//! Structs are written as maps, as serde only takes `'static` field names,
+//! so the wrapper is meant for self-describing formats such as JSON. The
+//! fields of externally tagged struct variants, which the API types do not
+//! use, keep their names for the same reason.
+//!
+//! In the other direction no wrapper is needed: every field of the API types
+//! has an alias in its other casing, so payloads in either convention
+//! deserialize, while serializing always produces the canonical names.
+//!
+//! ```
+//! use phylum_types::types::package::PackageReleaseData;
+//!
+//! let release: PackageReleaseData = serde_json::from_str(
+//!     r#"{"first_release_date":"2020-01-01","lastReleaseDate":"2022-01-01"}"#,
+//! )
+//! .unwrap();
+//! assert_eq!(release.first_release_date, "2020-01-01");
+//! ```
+
+use std::borrow::Cow;
+
+use serde::ser::{
+    SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde::{Serialize, Serializer};
+
+/// Serializes the wrapped value with snake_case struct field names.
+#[derive(Clone, Copy, Debug)]
+pub struct SnakeCase<T>(pub T);
+
+impl<T: Serialize> Serialize for SnakeCase<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(SnakeCaseSerializer {
+            inner: serializer,
+            is_field_name: false,
+        })
+    }
+}
+
+/// A map key which is a struct field name, renamed to snake_case.
+struct FieldName<T>(T);
+
+impl<T: Serialize> Serialize for FieldName<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(SnakeCaseSerializer {
+            inner: serializer,
+            is_field_name: true,
+        })
+    }
+}
+
+/// Convert a camelCase or PascalCase name to snake_case.
+///
+/// Names without uppercase letters are returned as they are.
+pub fn to_snake_case(name: &str) -> Cow<'_, str> {
+    if !name.chars().any(char::is_uppercase) {
+        return Cow::Borrowed(name);
+    }
+
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let prev = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            let word_start = match prev {
+                Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+                Some(prev) if prev.is_uppercase() => next.is_some_and(|n| n.is_lowercase()),
+                _ => false,
+            };
+            if word_start {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    Cow::Owned(snake)
+}
+
+/// A serializer which forwards to `S`, renaming struct fields on the way.
+struct SnakeCaseSerializer<S> {
+    inner: S,
+    /// Whether the value being serialized is a field name, which happens for
+    /// structs containing `#[serde(flatten)]` fields.
+    is_field_name: bool,
+}
+
+/// Writes a struct as a map, renaming its fields.
+struct StructMap<M> {
+    inner: M,
+}
+
+/// Forwards a compound serializer, wrapping every element in [`SnakeCase`].
+struct Compound<C> {
+    inner: C,
+    /// Whether map keys are struct field names.
+    ///
+    /// Structs with flattened fields are serialized as maps of unknown
+    /// length, while real maps always know their length, so this is how the
+    /// two are told apart.
+    keys_are_fields: bool,
+}
+
+impl<C> Compound<C> {
+    fn new(inner: C) -> Self {
+        Self {
+            inner,
+            keys_are_fields: false,
+        }
+    }
+}
+
+impl<S: Serializer> Serializer for SnakeCaseSerializer<S> {
+    type Error = S::Error;
+    type Ok = S::Ok;
+    type SerializeMap = Compound<S::SerializeMap>;
+    type SerializeSeq = Compound<S::SerializeSeq>;
+    type SerializeStruct = StructMap<S::SerializeMap>;
+    type SerializeStructVariant = Compound<S::SerializeStructVariant>;
+    type SerializeTuple = Compound<S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<S::SerializeTupleVariant>;
+
+    fn serialize_bool(self, v: bool) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bool(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i8(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i16(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i32(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i64(v)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_i128(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u8(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u16(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u32(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u64(v)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_u128(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f32(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_f64(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_char(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<S::Ok, S::Error> {
+        if self.is_field_name {
+            self.inner.serialize_str(&to_snake_case(v))
+        } else {
+            self.inner.serialize_str(v)
+        }
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_bytes(v)
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_some(&SnakeCase(value))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit_struct(name)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_unit_variant(name, variant_index, variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_struct(name, &SnakeCase(value))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_newtype_variant(name, variant_index, variant, &SnakeCase(value))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        self.inner.serialize_seq(len).map(Compound::new)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        self.inner.serialize_tuple(len).map(Compound::new)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        self.inner
+            .serialize_tuple_struct(name, len)
+            .map(Compound::new)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        self.inner
+            .serialize_tuple_variant(name, variant_index, variant, len)
+            .map(Compound::new)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        let inner = self.inner.serialize_map(len)?;
+        Ok(Compound {
+            inner,
+            keys_are_fields: len.is_none(),
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        let inner = self.inner.serialize_map(Some(len))?;
+        Ok(StructMap { inner })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        self.inner
+            .serialize_struct_variant(name, variant_index, variant, len)
+            .map(Compound::new)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<C: SerializeSeq> SerializeSeq for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&SnakeCase(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTuple> SerializeTuple for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&SnakeCase(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleStruct> SerializeTupleStruct for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&SnakeCase(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeTupleVariant> SerializeTupleVariant for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&SnakeCase(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeMap> SerializeMap for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), C::Error> {
+        if self.keys_are_fields {
+            self.inner.serialize_key(&FieldName(key))
+        } else {
+            self.inner.serialize_key(key)
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_value(&SnakeCase(value))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<M: SerializeMap> SerializeStruct for StructMap<M> {
+    type Error = M::Error;
+    type Ok = M::Ok;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), M::Error> {
+        self.inner
+            .serialize_entry(to_snake_case(key).as_ref(), &SnakeCase(value))
+    }
+
+    fn end(self) -> Result<M::Ok, M::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: SerializeStructVariant> SerializeStructVariant for Compound<C> {
+    type Error = C::Error;
+    type Ok = C::Ok;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner.serialize_field(key, &SnakeCase(value))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(key)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
@@ -0,0 +1,181 @@
+//! Structured differences between two values of the same type.
+//!
+//! Both values are compared through their serialized form, so the paths of
+//! the changes use the wire field names. Changes are listed in a
+//! deterministic order: object fields sorted by name, array elements by index.
+//!
+//! ```
+//! use phylum_types::diff::{diff, ChangeKind};
+//! use phylum_types::types::package::{PackageRef, PackageType};
+//!
+//! let old = PackageRef::new("lodash", "4.17.20", PackageType::Npm);
+//! let new = PackageRef::new("lodash", "4.17.21", PackageType::Npm);
+//!
+//! let changes = diff(&old, &new).unwrap();
+//! assert_eq!(changes.len(), 1);
+//! assert_eq!(changes[0].path.to_string(), "version");
+//! assert_eq!(changes[0].kind(), ChangeKind::Modified);
+//! ```
+
+use std::fmt;
+use std::ops::Deref;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// One step into a serialized value
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// Location of a change, such as `packages[0].license`
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default)]
+pub struct FieldPath(pub Vec<PathSegment>);
+
+impl FieldPath {
+    /// Whether this path is `prefix` or lies below it.
+    pub fn starts_with(&self, prefix: &FieldPath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    fn child(&self, segment: PathSegment) -> FieldPath {
+        let mut path = self.clone();
+        path.0.push(segment);
+        path
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => f.write_str(name)?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for FieldPath {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Whether a value was added, removed or modified
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// A single difference between two values
+#[derive(PartialEq, Clone, Debug, Serialize)]
+pub struct Change {
+    pub path: FieldPath,
+    /// The old value, absent if added
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    /// The new value, absent if removed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+impl Change {
+    pub fn kind(&self) -> ChangeKind {
+        match (&self.old, &self.new) {
+            (None, _) => ChangeKind::Added,
+            (_, None) => ChangeKind::Removed,
+            _ => ChangeKind::Modified,
+        }
+    }
+}
+
+/// All differences between two values, in deterministic order
+#[derive(PartialEq, Clone, Debug, Default, Serialize)]
+#[serde(transparent)]
+pub struct Diff(pub Vec<Change>);
+
+impl Diff {
+    /// Changes at or below `prefix`.
+    pub fn under<'a>(&'a self, prefix: &'a FieldPath) -> impl Iterator<Item = &'a Change> {
+        self.0
+            .iter()
+            .filter(move |change| change.path.starts_with(prefix))
+    }
+}
+
+impl Deref for Diff {
+    type Target = [Change];
+
+    fn deref(&self) -> &[Change] {
+        &self.0
+    }
+}
+
+impl IntoIterator for Diff {
+    type IntoIter = std::vec::IntoIter<Change>;
+    type Item = Change;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Compute the differences from `old` to `new`.
+///
+/// Fails only if either value cannot be serialized to JSON.
+pub fn diff<T: Serialize + ?Sized>(old: &T, new: &T) -> Result<Diff, serde_json::Error> {
+    let old = serde_json::to_value(old)?;
+    let new = serde_json::to_value(new)?;
+
+    let mut changes = Vec::new();
+    diff_values(FieldPath::default(), &old, &new, &mut changes);
+    Ok(Diff(changes))
+}
+
+fn diff_values(path: FieldPath, old: &Value, new: &Value, changes: &mut Vec<Change>) {
+    match (old, new) {
+        (Value::Object(old), Value::Object(new)) => {
+            let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+            keys.sort();
+            keys.dedup();
+
+            for key in keys {
+                let path = path.child(PathSegment::Field(key.clone()));
+                match (old.get(key), new.get(key)) {
+                    (Some(old), Some(new)) => diff_values(path, old, new, changes),
+                    (old, new) => changes.push(Change {
+                        path,
+                        old: old.cloned(),
+                        new: new.cloned(),
+                    }),
+                }
+            }
+        }
+        (Value::Array(old), Value::Array(new)) => {
+            for index in 0..old.len().max(new.len()) {
+                let path = path.child(PathSegment::Index(index));
+                match (old.get(index), new.get(index)) {
+                    (Some(old), Some(new)) => diff_values(path, old, new, changes),
+                    (old, new) => changes.push(Change {
+                        path,
+                        old: old.cloned(),
+                        new: new.cloned(),
+                    }),
+                }
+            }
+        }
+        (old, new) if old != new => changes.push(Change {
+            path,
+            old: Some(old.clone()),
+            new: Some(new.clone()),
+        }),
+        _ => (),
+    }
+}
@@ -0,0 +1,309 @@
+//! Descriptions of API operations for generic HTTP clients.
+//!
+//! Each operation is a marker type implementing [`Endpoint`], which ties its
+//! method and path to the types of its path parameters, query, request body
+//! and response. A client can then send any of them with one function:
+//!
+//! ```
+//! use phylum_types::endpoint::{Endpoint, GetJobStatus, Method};
+//! use uuid::Uuid;
+//!
+//! let job_id = Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap();
+//! assert_eq!(GetJobStatus::METHOD, Method::Get);
+//! assert_eq!(GetJobStatus::PATH, "/data/jobs/{job_id}");
+//! assert_eq!(
+//!     GetJobStatus::path(&job_id),
+//!     "/data/jobs/0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"
+//! );
+//! ```
+//!
+//! Operations without a query or a request body use `()` for those types.
+//! Paths are relative to the API base URL, such as `https://api.phylum.io/api/v0`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::types::common::{JobId, PageParams, Paginated, ProjectId, ReportId};
+use crate::types::group::{CreateGroupRequest, CreateGroupResponse, ListUserGroupsResponse};
+use crate::types::health::{HealthResponse, VersionResponse};
+use crate::types::job::{
+    AllJobsStatusResponse, BatchSubmitRequest, BatchSubmitResponse, CancelJobRequest,
+    CancelJobResponse, JobListParams, JobStatusResponseVariant, SubmitPackageRequest,
+    SubmitPackageResponse,
+};
+use crate::types::package::{Package, PackageRef};
+use crate::types::project::{
+    CreateProjectRequest, CreateProjectResponse, ProjectPatch, ProjectSummaryResponse,
+    UpdateProjectRequest, UpdateProjectResponse,
+};
+use crate::types::report::{ReportDownloadInfo, ReportRequest, ReportStatus};
+use crate::types::user_settings::UserSettings;
+
+/// HTTP request method
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+pub enum Method {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl Method {
+    /// The method as sent on the wire, such as `GET`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Patch => "PATCH",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+impl fmt::Display for Method {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Method {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "GET" => Ok(Method::Get),
+            "POST" => Ok(Method::Post),
+            "PUT" => Ok(Method::Put),
+            "PATCH" => Ok(Method::Patch),
+            "DELETE" => Ok(Method::Delete),
+            _ => Err(()),
+        }
+    }
+}
+
+/// An API operation.
+pub trait Endpoint {
+    /// Values substituted into the path template
+    type Params;
+    /// Query string parameters, serializable with `serde_urlencoded`
+    type Query: Serialize;
+    /// The JSON request body
+    type Request: Serialize;
+    /// The JSON response body
+    type Response: DeserializeOwned;
+
+    const METHOD: Method;
+    /// The path, with parameters in braces, such as `/data/jobs/{job_id}`
+    const PATH: &'static str;
+
+    /// The path with the given parameters substituted and percent-encoded.
+    fn path(params: &Self::Params) -> String;
+}
+
+/// Fill the `{...}` placeholders of `template`, in order, with `values`.
+fn fill(template: &str, values: &[&str]) -> String {
+    let mut path = String::with_capacity(template.len());
+    let mut values = values.iter();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        path.push_str(&rest[..start]);
+        let end = start
+            + rest[start..]
+                .find('}')
+                .expect("unterminated path parameter");
+        let value = values.next().expect("missing path parameter");
+        path.push_str(&encode_segment(value));
+        rest = &rest[end + 1..];
+    }
+    path.push_str(rest);
+    path
+}
+
+/// Percent-encode everything but unreserved characters, so a value such as
+/// `@types/node` stays a single path segment.
+fn encode_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// The name of a package including any namespace, such as `@types/node`.
+fn package_name(package: &PackageRef) -> String {
+    let separator = package
+        .package_type()
+        .and_then(|package_type| package_type.namespace_separator());
+    match (&package.namespace, separator) {
+        (Some(namespace), Some(separator)) => format!("{namespace}{separator}{}", package.name),
+        _ => package.name.clone(),
+    }
+}
+
+macro_rules! endpoint {
+    (
+        $(#[$attr:meta])*
+        $name:ident: $method:ident $path:literal,
+        |$params:ident: $params_ty:ty| [$($value:expr),* $(,)?],
+        $query:ty, $request:ty => $response:ty
+    ) => {
+        $(#[$attr])*
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+        pub struct $name;
+
+        impl Endpoint for $name {
+            type Params = $params_ty;
+            type Query = $query;
+            type Request = $request;
+            type Response = $response;
+
+            const METHOD: Method = Method::$method;
+            const PATH: &'static str = $path;
+
+            #[allow(unused_variables)]
+            fn path($params: &Self::Params) -> String {
+                fill(Self::PATH, &[$(&$value),*])
+            }
+        }
+    };
+}
+
+endpoint! {
+    /// Service health
+    GetHealth: Get "/health",
+    |params: ()| [],
+    (), () => HealthResponse
+}
+
+endpoint! {
+    /// Deployed service version
+    GetVersion: Get "/version",
+    |params: ()| [],
+    (), () => VersionResponse
+}
+
+endpoint! {
+    /// Submit packages for analysis
+    SubmitPackages: Post "/data/jobs",
+    |params: ()| [],
+    (), SubmitPackageRequest => SubmitPackageResponse
+}
+
+endpoint! {
+    /// Submit packages for analysis in several projects or labels at once
+    SubmitBatch: Post "/data/jobs/batch",
+    |params: ()| [],
+    (), BatchSubmitRequest => BatchSubmitResponse
+}
+
+endpoint! {
+    /// List recent jobs
+    ListJobs: Get "/data/jobs",
+    |params: ()| [],
+    JobListParams, () => AllJobsStatusResponse
+}
+
+endpoint! {
+    /// The status of a job and its packages
+    GetJobStatus: Get "/data/jobs/{job_id}",
+    |params: JobId| [params.to_string()],
+    (), () => JobStatusResponseVariant
+}
+
+endpoint! {
+    /// Cancel a running job
+    CancelJob: Delete "/data/jobs/{job_id}",
+    |params: JobId| [params.to_string()],
+    (), CancelJobRequest => CancelJobResponse
+}
+
+endpoint! {
+    /// Details of a package version
+    GetPackage: Get "/data/packages/{type}/{name}/{version}",
+    |params: PackageRef| [params.ecosystem.to_string(), package_name(params), params.version],
+    (), () => Package
+}
+
+endpoint! {
+    /// Projects visible to the user
+    ListProjects: Get "/data/projects/overview",
+    |params: ()| [],
+    (), () => Vec<ProjectSummaryResponse>
+}
+
+endpoint! {
+    /// Projects visible to the user, a page at a time
+    ListProjectsPage: Get "/data/projects",
+    |params: ()| [],
+    PageParams, () => Paginated<ProjectSummaryResponse>
+}
+
+endpoint! {
+    CreateProject: Post "/data/projects",
+    |params: ()| [],
+    (), CreateProjectRequest => CreateProjectResponse
+}
+
+endpoint! {
+    UpdateProject: Put "/data/projects/{project_id}",
+    |params: ProjectId| [params.to_string()],
+    (), UpdateProjectRequest => UpdateProjectResponse
+}
+
+endpoint! {
+    /// Change some fields of a project, leaving the others unchanged
+    PatchProject: Patch "/data/projects/{project_id}",
+    |params: ProjectId| [params.to_string()],
+    (), ProjectPatch => UpdateProjectResponse
+}
+
+endpoint! {
+    /// Start generating a report
+    GenerateReport: Post "/data/reports",
+    |params: ()| [],
+    (), ReportRequest => ReportStatus
+}
+
+endpoint! {
+    /// The status of a report being generated
+    GetReportStatus: Get "/data/reports/{report_id}",
+    |params: ReportId| [params.to_string()],
+    (), () => ReportStatus
+}
+
+endpoint! {
+    /// Where to download a generated report from
+    GetReportDownload: Get "/data/reports/{report_id}/download",
+    |params: ReportId| [params.to_string()],
+    (), () => ReportDownloadInfo
+}
+
+endpoint! {
+    /// Settings of the authenticated user
+    GetUserSettings: Get "/settings/current-user",
+    |params: ()| [],
+    (), () => UserSettings
+}
+
+endpoint! {
+    /// Groups the user belongs to
+    ListUserGroups: Get "/groups",
+    |params: ()| [],
+    (), () => ListUserGroupsResponse
+}
+
+endpoint! {
+    CreateGroup: Post "/groups",
+    |params: ()| [],
+    (), CreateGroupRequest => CreateGroupResponse
+}
@@ -0,0 +1,245 @@
+//! Helpers for policy code which must handle every risk domain and level.
+//!
+//! A `match` over [`RiskDomain`] or [`RiskLevel`] stops compiling when a
+//! variant is added, but a table of thresholds or weights does not: a list of
+//! pairs simply lacks the new entry, and lookups quietly fall back to some
+//! default. [`ByRiskDomain`] and [`ByRiskLevel`] have one field per variant,
+//! so building one with a struct literal fails to compile until a value for
+//! the new variant is given:
+//!
+//! ```
+//! use phylum_types::all_risk_levels;
+//! use phylum_types::exhaustive::{ByRiskLevel, Exhaustive};
+//! use phylum_types::types::package::RiskLevel;
+//!
+//! let max_issues = ByRiskLevel { info: 100, low: 10, medium: 5, high: 1, critical: 0 };
+//! assert_eq!(max_issues[RiskLevel::Medium], 5);
+//!
+//! // One value per level, in declaration order
+//! let names: [String; 5] = all_risk_levels!(|level| level.to_string());
+//! assert_eq!(names[4], "critical");
+//!
+//! // Fails to compile once there are more levels
+//! const _: () = assert!(RiskLevel::COUNT == 5);
+//! ```
+
+use std::ops::{Index, IndexMut};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::package::{RiskDomain, RiskLevel};
+
+/// Every risk domain, in declaration order, as an array.
+///
+/// With a closure-like argument, the array holds the value of the body for
+/// each domain instead:
+///
+/// ```
+/// use phylum_types::all_risk_domains;
+///
+/// let names = all_risk_domains!(|domain| domain.to_string());
+/// assert_eq!(names.len(), all_risk_domains!().len());
+/// ```
+#[macro_export]
+macro_rules! all_risk_domains {
+    () => {
+        $crate::all_risk_domains!(|domain| domain)
+    };
+    (|$domain:ident| $value:expr) => {
+        [
+            {
+                let $domain = $crate::types::package::RiskDomain::AuthorRisk;
+                $value
+            },
+            {
+                let $domain = $crate::types::package::RiskDomain::EngineeringRisk;
+                $value
+            },
+            {
+                let $domain = $crate::types::package::RiskDomain::Malicious;
+                $value
+            },
+            {
+                let $domain = $crate::types::package::RiskDomain::Vulnerabilities;
+                $value
+            },
+            {
+                let $domain = $crate::types::package::RiskDomain::LicenseRisk;
+                $value
+            },
+        ]
+    };
+}
+
+/// Every risk level, from least to most severe, as an array.
+///
+/// With a closure-like argument, the array holds the value of the body for
+/// each level instead.
+#[macro_export]
+macro_rules! all_risk_levels {
+    () => {
+        $crate::all_risk_levels!(|level| level)
+    };
+    (|$level:ident| $value:expr) => {
+        [
+            {
+                let $level = $crate::types::package::RiskLevel::Info;
+                $value
+            },
+            {
+                let $level = $crate::types::package::RiskLevel::Low;
+                $value
+            },
+            {
+                let $level = $crate::types::package::RiskLevel::Medium;
+                $value
+            },
+            {
+                let $level = $crate::types::package::RiskLevel::High;
+                $value
+            },
+            {
+                let $level = $crate::types::package::RiskLevel::Critical;
+                $value
+            },
+        ]
+    };
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::RiskDomain {}
+    impl Sealed for super::RiskLevel {}
+}
+
+/// Enums whose variants this crate can list.
+///
+/// Sealed, so that adding a variant only has to update the implementations
+/// here.
+pub trait Exhaustive: sealed::Sealed + Copy + 'static {
+    /// Every variant, in declaration order
+    const ALL: &'static [Self];
+    const COUNT: usize = Self::ALL.len();
+
+    /// Position of the variant in [`Exhaustive::ALL`].
+    fn index(self) -> usize;
+}
+
+// The matches in `index` are exhaustive, so a new variant fails to compile
+// here until it is added to the macros above as well.
+
+impl Exhaustive for RiskDomain {
+    const ALL: &'static [Self] = &all_risk_domains!();
+
+    fn index(self) -> usize {
+        match self {
+            RiskDomain::AuthorRisk => 0,
+            RiskDomain::EngineeringRisk => 1,
+            RiskDomain::Malicious => 2,
+            RiskDomain::Vulnerabilities => 3,
+            RiskDomain::LicenseRisk => 4,
+        }
+    }
+}
+
+impl Exhaustive for RiskLevel {
+    const ALL: &'static [Self] = &all_risk_levels!();
+
+    fn index(self) -> usize {
+        match self {
+            RiskLevel::Info => 0,
+            RiskLevel::Low => 1,
+            RiskLevel::Medium => 2,
+            RiskLevel::High => 3,
+            RiskLevel::Critical => 4,
+        }
+    }
+}
+
+macro_rules! table {
+    (
+        $(#[$meta:meta])*
+        $name:ident<$key:ident> {
+            $($(#[$field_meta:meta])* $field:ident => $variant:ident,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(
+            PartialEq, Eq, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+        )]
+        pub struct $name<T> {
+            $($(#[$field_meta])* pub $field: T,)+
+        }
+
+        impl<T> $name<T> {
+            /// A table holding `f` of each variant.
+            pub fn from_fn<F: FnMut($key) -> T>(mut f: F) -> Self {
+                Self {
+                    $($field: f($key::$variant),)+
+                }
+            }
+
+            pub fn get(&self, key: $key) -> &T {
+                match key {
+                    $($key::$variant => &self.$field,)+
+                }
+            }
+
+            pub fn get_mut(&mut self, key: $key) -> &mut T {
+                match key {
+                    $($key::$variant => &mut self.$field,)+
+                }
+            }
+
+            pub fn map<U, F: FnMut(T) -> U>(self, mut f: F) -> $name<U> {
+                $name {
+                    $($field: f(self.$field),)+
+                }
+            }
+
+            /// The entries of the table, in declaration order.
+            pub fn iter(&self) -> impl Iterator<Item = ($key, &T)> {
+                IntoIterator::into_iter([$(($key::$variant, &self.$field),)+])
+            }
+        }
+
+        impl<T> Index<$key> for $name<T> {
+            type Output = T;
+
+            fn index(&self, key: $key) -> &T {
+                self.get(key)
+            }
+        }
+
+        impl<T> IndexMut<$key> for $name<T> {
+            fn index_mut(&mut self, key: $key) -> &mut T {
+                self.get_mut(key)
+            }
+        }
+    };
+}
+
+table! {
+    /// A value for every risk domain
+    ByRiskDomain<RiskDomain> {
+        author => AuthorRisk,
+        engineering => EngineeringRisk,
+        #[serde(rename = "malicious_code", alias = "malicious")]
+        malicious => Malicious,
+        vulnerability => Vulnerabilities,
+        license => LicenseRisk,
+    }
+}
+
+table! {
+    /// A value for every risk level
+    ByRiskLevel<RiskLevel> {
+        info => Info,
+        low => Low,
+        medium => Medium,
+        high => High,
+        critical => Critical,
+    }
+}
@@ -0,0 +1,121 @@
+//! `#[repr(C)]` mirrors of the core enums, for agents embedding this crate
+//! behind a C ABI.
+//!
+//! Every variant has a stable numeric code which never changes or gets
+//! reused, so values can cross the boundary as plain integers. The exported
+//! `phylum_*_name` functions take such a code rather than the enum itself,
+//! which keeps out of range values coming from C well defined.
+//!
+//! ```
+//! use phylum_types::ffi;
+//! use phylum_types::types::package::PackageType;
+//!
+//! let code = ffi::PackageType::from(PackageType::Cargo).code();
+//! assert_eq!(code, 5);
+//! assert_eq!(ffi::PackageType::from_code(code).map(PackageType::from), Some(PackageType::Cargo));
+//! ```
+
+use std::os::raw::c_char;
+
+use crate::types::package;
+
+macro_rules! mirror {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $name_fn:ident {
+            $($variant:ident = $code:literal => $label:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[repr(C)]
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug)]
+        pub enum $name {
+            $($variant = $code,)+
+        }
+
+        impl $name {
+            /// The stable numeric code of this value.
+            pub const fn code(self) -> u32 {
+                self as u32
+            }
+
+            pub const fn from_code(code: u32) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// The wire name of this value.
+            pub const fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $label,)+
+                }
+            }
+        }
+
+        impl From<package::$name> for $name {
+            fn from(value: package::$name) -> Self {
+                match value {
+                    $(package::$name::$variant => Self::$variant,)+
+                }
+            }
+        }
+
+        impl From<$name> for package::$name {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => Self::$variant,)+
+                }
+            }
+        }
+
+        /// The NUL terminated wire name for a code, or null for unknown codes.
+        ///
+        /// The returned string is static and must not be freed.
+        #[no_mangle]
+        pub extern "C" fn $name_fn(code: u32) -> *const c_char {
+            match code {
+                $($code => concat!($label, "\0").as_ptr().cast(),)+
+                _ => std::ptr::null(),
+            }
+        }
+    };
+}
+
+mirror! {
+    /// Mirror of [`package::RiskLevel`]
+    RiskLevel, phylum_risk_level_name {
+        Info = 0 => "info",
+        Low = 1 => "low",
+        Medium = 2 => "medium",
+        High = 3 => "high",
+        Critical = 4 => "critical",
+    }
+}
+
+mirror! {
+    /// Mirror of [`package::RiskDomain`]
+    RiskDomain, phylum_risk_domain_name {
+        AuthorRisk = 0 => "author",
+        EngineeringRisk = 1 => "engineering",
+        Malicious = 2 => "malicious_code",
+        Vulnerabilities = 3 => "vulnerability",
+        LicenseRisk = 4 => "license",
+    }
+}
+
+mirror! {
+    /// Mirror of [`package::PackageType`]
+    PackageType, phylum_package_type_name {
+        Npm = 0 => "npm",
+        PyPi = 1 => "pypi",
+        Maven = 2 => "maven",
+        RubyGems = 3 => "rubygems",
+        Nuget = 4 => "nuget",
+        Cargo = 5 => "cargo",
+        Golang = 6 => "golang",
+        Composer = 7 => "composer",
+        Conan = 8 => "conan",
+    }
+}
@@ -0,0 +1,113 @@
+//! Canned API payloads recorded from the wire, for tests of code using this
+//! crate.
+//!
+//! Each payload is available as raw JSON, so tests can exercise their own
+//! parsing, or parsed into the matching type:
+//!
+//! ```
+//! use phylum_types::fixtures;
+//!
+//! let job = fixtures::job_status_extended();
+//! assert_eq!(job.packages[0].basic_status.name, "express");
+//! assert!(fixtures::JOB_STATUS_EXTENDED.contains("express"));
+//! ```
+//!
+//! These are the same payloads the crate's own round-trip tests check the
+//! types against, so they parse with every combination of features.
+
+use crate::types::common::ApiErrorResponse;
+use crate::types::job::{
+    AllJobsStatusResponse, JobStatusResponse, SubmitPackageRequest, SubmitPackageResponse,
+};
+use crate::types::package::{Package, PackageStatus, PackageStatusExtended};
+use crate::types::project::ProjectSummaryResponse;
+
+/// An error for a job which does not exist
+pub const API_ERROR_NOT_FOUND: &str = include_str!("../tests/fixtures/api_error_not_found.json");
+
+/// A listing of a user's recent jobs
+pub const ALL_JOBS_STATUS_RESPONSE: &str =
+    include_str!("../tests/fixtures/all_jobs_status_response.json");
+
+/// An incomplete job with basic package statuses
+pub const JOB_STATUS_BASIC: &str = include_str!("../tests/fixtures/job_status_basic.json");
+
+/// A complete job with extended package statuses and issues
+pub const JOB_STATUS_EXTENDED: &str = include_str!("../tests/fixtures/job_status_extended.json");
+
+/// A fully analyzed package
+pub const PACKAGE: &str = include_str!("../tests/fixtures/package.json");
+
+/// A listing of a user's projects
+pub const PROJECT_SUMMARIES: &str = include_str!("../tests/fixtures/project_summaries.json");
+
+/// Packages submitted for analysis
+pub const SUBMIT_PACKAGE_REQUEST: &str =
+    include_str!("../tests/fixtures/submit_package_request.json");
+
+/// The response to submitting packages for analysis
+pub const SUBMIT_PACKAGE_RESPONSE: &str =
+    include_str!("../tests/fixtures/submit_package_response.json");
+
+/// Every payload, by its file name.
+pub const ALL: &[(&str, &str)] = &[
+    ("all_jobs_status_response.json", ALL_JOBS_STATUS_RESPONSE),
+    ("api_error_not_found.json", API_ERROR_NOT_FOUND),
+    ("job_status_basic.json", JOB_STATUS_BASIC),
+    ("job_status_extended.json", JOB_STATUS_EXTENDED),
+    ("package.json", PACKAGE),
+    ("project_summaries.json", PROJECT_SUMMARIES),
+    ("submit_package_request.json", SUBMIT_PACKAGE_REQUEST),
+    ("submit_package_response.json", SUBMIT_PACKAGE_RESPONSE),
+];
+
+/// The payload with the given file name, such as `package.json`.
+pub fn get(name: &str) -> Option<&'static str> {
+    ALL.iter()
+        .find(|(file_name, _)| *file_name == name)
+        .map(|(_, json)| *json)
+}
+
+fn parse<T: serde::de::DeserializeOwned>(json: &str) -> T {
+    serde_json::from_str(json).expect("bundled fixtures are valid")
+}
+
+/// [`ALL_JOBS_STATUS_RESPONSE`], parsed.
+pub fn all_jobs_status_response() -> AllJobsStatusResponse {
+    parse(ALL_JOBS_STATUS_RESPONSE)
+}
+
+/// [`API_ERROR_NOT_FOUND`], parsed.
+pub fn api_error_not_found() -> ApiErrorResponse {
+    parse(API_ERROR_NOT_FOUND)
+}
+
+/// [`JOB_STATUS_BASIC`], parsed.
+pub fn job_status_basic() -> JobStatusResponse<PackageStatus> {
+    parse(JOB_STATUS_BASIC)
+}
+
+/// [`JOB_STATUS_EXTENDED`], parsed.
+pub fn job_status_extended() -> JobStatusResponse<PackageStatusExtended> {
+    parse(JOB_STATUS_EXTENDED)
+}
+
+/// [`PACKAGE`], parsed.
+pub fn package() -> Package {
+    parse(PACKAGE)
+}
+
+/// [`PROJECT_SUMMARIES`], parsed.
+pub fn project_summaries() -> Vec<ProjectSummaryResponse> {
+    parse(PROJECT_SUMMARIES)
+}
+
+/// [`SUBMIT_PACKAGE_REQUEST`], parsed.
+pub fn submit_package_request() -> SubmitPackageRequest {
+    parse(SUBMIT_PACKAGE_REQUEST)
+}
+
+/// [`SUBMIT_PACKAGE_RESPONSE`], parsed.
+pub fn submit_package_response() -> SubmitPackageResponse {
+    parse(SUBMIT_PACKAGE_RESPONSE)
+}
@@ -0,0 +1,459 @@
+//! Traversal of the dependency tree contained in a [`Package`], and of the
+//! dependency maps of the packages of a job.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+
+use crate::types::job::JobStatusResponse;
+#[allow(deprecated)]
+use crate::types::package::{
+    Package, PackageRef, PackageSpecifier, PackageStatusExtended, PackageType, RiskScores,
+};
+
+#[allow(deprecated)]
+impl From<&Package> for PackageSpecifier {
+    fn from(package: &Package) -> Self {
+        Self {
+            registry: package.registry.to_string(),
+            name: package.name.clone(),
+            version: package.version.clone(),
+        }
+    }
+}
+
+impl Package {
+    /// A graph view of this package's dependencies.
+    pub fn dependency_graph(&self) -> DependencyGraph<'_> {
+        DependencyGraph::new(self)
+    }
+
+    fn children(&self) -> &[Package] {
+        self.dependencies.as_deref().unwrap_or_default()
+    }
+
+    fn is_same_package(&self, other: &Package) -> bool {
+        self.registry == other.registry && self.name == other.name && self.version == other.version
+    }
+}
+
+/// A package found while walking the dependency graph.
+#[derive(Clone, Copy, Debug)]
+pub struct DependencyNode<'a> {
+    pub package: &'a Package,
+    /// Distance from the root; direct dependencies have a depth of 1.
+    pub depth: usize,
+}
+
+/// Read only view over the dependency tree of a root package.
+#[derive(Clone, Copy, Debug)]
+pub struct DependencyGraph<'a> {
+    root: &'a Package,
+}
+
+#[allow(deprecated)]
+impl<'a> DependencyGraph<'a> {
+    pub fn new(root: &'a Package) -> Self {
+        Self { root }
+    }
+
+    pub fn root(&self) -> &'a Package {
+        self.root
+    }
+
+    /// Every dependency in depth first pre-order, excluding the root.
+    pub fn depth_first(&self) -> DepthFirst<'a> {
+        DepthFirst {
+            stack: self
+                .root
+                .children()
+                .iter()
+                .rev()
+                .map(|package| DependencyNode { package, depth: 1 })
+                .collect(),
+        }
+    }
+
+    /// Dependencies of the root package itself.
+    pub fn direct(&self) -> impl Iterator<Item = &'a Package> {
+        self.root.children().iter()
+    }
+
+    /// Dependencies only reachable through other dependencies.
+    pub fn transitive(&self) -> impl Iterator<Item = DependencyNode<'a>> {
+        self.depth_first().filter(|node| node.depth > 1)
+    }
+
+    /// All paths from a direct dependency down to packages named `name`.
+    ///
+    /// Answers questions like "why is left-pad here?"; each path ends with
+    /// the matching package.
+    pub fn paths_to(&self, name: &str) -> Vec<Vec<&'a Package>> {
+        let mut paths = Vec::new();
+        let mut path = Vec::new();
+        for child in self.root.children() {
+            collect_paths(child, name, &mut path, &mut paths);
+        }
+        paths
+    }
+
+    /// Chains of packages which depend on themselves.
+    ///
+    /// The tree cannot contain real cycles, so they show up as a package
+    /// appearing as its own descendant. Each returned chain starts and ends
+    /// with the repeated package.
+    pub fn cycles(&self) -> Vec<Vec<PackageSpecifier>> {
+        let mut cycles = Vec::new();
+        let mut path = vec![self.root];
+        find_cycles(self.root, &mut path, &mut cycles);
+        cycles
+    }
+
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles().is_empty()
+    }
+
+    /// Every distinct dependency, sorted.
+    pub fn specifiers(&self) -> Vec<PackageSpecifier> {
+        self.depth_first()
+            .map(|node| PackageSpecifier::from(node.package))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+}
+
+#[allow(deprecated)]
+impl DependencyGraph<'_> {
+    /// Render the graph in Graphviz DOT format, coloring nodes by their total
+    /// risk score.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dependencies {\n    node [style=filled];\n");
+        self.walk_edges(&mut |edge| {
+            let Edge {
+                id,
+                package,
+                parent,
+                new,
+            } = edge;
+            if new {
+                let _ = writeln!(
+                    dot,
+                    "    n{id} [label=\"{}\", fillcolor=\"{}\"];",
+                    escape_dot(&node_label(package)),
+                    risk_color(package.risk_scores),
+                );
+            }
+            if let Some(parent) = parent {
+                let _ = writeln!(dot, "    n{parent} -> n{id};");
+            }
+        });
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the graph as a Mermaid flowchart, coloring nodes by their total
+    /// risk score.
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("graph TD\n");
+        self.walk_edges(&mut |edge| {
+            let Edge {
+                id,
+                package,
+                parent,
+                new,
+            } = edge;
+            if new {
+                let _ = writeln!(
+                    mermaid,
+                    "    n{id}[\"{}\"]",
+                    node_label(package).replace('"', "#quot;")
+                );
+                let _ = writeln!(
+                    mermaid,
+                    "    style n{id} fill:{}",
+                    risk_color(package.risk_scores)
+                );
+            }
+            if let Some(parent) = parent {
+                let _ = writeln!(mermaid, "    n{parent} --> n{id}");
+            }
+        });
+        mermaid
+    }
+
+    /// Visit every edge of the graph, with each package given a numeric id.
+    ///
+    /// A package is only `new` the first time it is seen; packages appearing
+    /// in several places of the tree share a single node.
+    fn walk_edges(&self, visit: &mut dyn FnMut(Edge<'_>)) {
+        let mut ids = HashMap::new();
+        let mut stack = vec![(self.root, None)];
+        while let Some((package, parent)) = stack.pop() {
+            let next_id = ids.len();
+            let id = *ids
+                .entry(PackageSpecifier::from(package))
+                .or_insert(next_id);
+            let new = id == next_id;
+            visit(Edge {
+                id,
+                package,
+                parent,
+                new,
+            });
+            if new {
+                stack.extend(
+                    package
+                        .children()
+                        .iter()
+                        .rev()
+                        .map(|child| (child, Some(id))),
+                );
+            }
+        }
+    }
+}
+
+struct Edge<'a> {
+    id: usize,
+    package: &'a Package,
+    parent: Option<usize>,
+    new: bool,
+}
+
+fn node_label(package: &Package) -> String {
+    format!("{}@{}", package.name, package.version)
+}
+
+fn escape_dot(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Fill color for a node, from green for safe packages to red for risky ones,
+/// and grey for packages which have not been scored.
+fn risk_color(scores: Option<RiskScores>) -> &'static str {
+    match scores.map(|scores| scores.total) {
+        None => "#d3d3d3",
+        Some(score) if score >= 0.8 => "#8fd694",
+        Some(score) if score >= 0.6 => "#f5e663",
+        Some(score) if score >= 0.4 => "#f5a962",
+        _ => "#f07167",
+    }
+}
+
+fn collect_paths<'a>(
+    package: &'a Package,
+    name: &str,
+    path: &mut Vec<&'a Package>,
+    paths: &mut Vec<Vec<&'a Package>>,
+) {
+    path.push(package);
+    if package.name == name {
+        paths.push(path.clone());
+    }
+    for child in package.children() {
+        collect_paths(child, name, path, paths);
+    }
+    path.pop();
+}
+
+#[allow(deprecated)]
+fn find_cycles<'a>(
+    package: &'a Package,
+    path: &mut Vec<&'a Package>,
+    cycles: &mut Vec<Vec<PackageSpecifier>>,
+) {
+    for child in package.children() {
+        match path
+            .iter()
+            .position(|ancestor| ancestor.is_same_package(child))
+        {
+            Some(start) => {
+                let mut cycle: Vec<_> = path[start..].iter().map(|p| (*p).into()).collect();
+                cycle.push(child.into());
+                cycles.push(cycle);
+            }
+            None => {
+                path.push(child);
+                find_cycles(child, path, cycles);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Iterator returned by [`DependencyGraph::depth_first`].
+#[derive(Clone, Debug)]
+pub struct DepthFirst<'a> {
+    stack: Vec<DependencyNode<'a>>,
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = DependencyNode<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(
+            node.package
+                .children()
+                .iter()
+                .rev()
+                .map(|package| DependencyNode {
+                    package,
+                    depth: node.depth + 1,
+                }),
+        );
+        Some(node)
+    }
+}
+
+/// A package of a job, by ecosystem, name and version
+type JobNode<'a> = (PackageType, &'a str, &'a str);
+
+fn job_node(package: &PackageStatusExtended) -> JobNode<'_> {
+    (
+        package.package_type,
+        &package.basic_status.name,
+        &package.basic_status.version,
+    )
+}
+
+impl JobStatusResponse<PackageStatusExtended> {
+    /// The shortest chain of dependencies through which the job depends on a
+    /// package version, from a top level package down to the package itself.
+    ///
+    /// Top level packages are those which no other package of the job
+    /// depends on. Returns `None` if the package is not part of the job or
+    /// not reachable from a top level package.
+    ///
+    /// Dependencies are resolved as described on [`fill_introduced_by`].
+    ///
+    /// [`fill_introduced_by`]: Self::fill_introduced_by
+    pub fn introduced_by(&self, package: &PackageRef) -> Option<Vec<PackageRef>> {
+        let node = (
+            package.package_type()?,
+            package.name.as_str(),
+            package.version.as_str(),
+        );
+        JobGraph::new(&self.packages).path_to(node)
+    }
+
+    /// Set `introduced_by` of every issue which does not have one yet.
+    ///
+    /// The versions in a package's `dependencies` may be requirements, such
+    /// as `~1.3.8`, rather than exact versions. A dependency resolves to the
+    /// package of the job with the same ecosystem and name whose version is
+    /// exactly the one given, or else to the only version of that name in
+    /// the job. Requirements are not evaluated, so a dependency matching
+    /// none or several versions of the job is left out of the graph.
+    pub fn fill_introduced_by(&mut self) {
+        let paths: Vec<_> = {
+            let graph = JobGraph::new(&self.packages);
+            self.packages
+                .iter()
+                .map(|package| graph.path_to(job_node(package)))
+                .collect()
+        };
+
+        for (package, path) in self.packages.iter_mut().zip(paths) {
+            let path = match path {
+                Some(path) => path,
+                None => continue,
+            };
+            for status in &mut package.issues {
+                if status.issue.introduced_by.is_empty() {
+                    status.issue.introduced_by = path.clone();
+                }
+            }
+        }
+    }
+}
+
+/// A dependency as a package of the job, if it resolves to exactly one
+fn resolve<'a>(
+    index: &HashMap<JobNode<'a>, &'a PackageStatusExtended>,
+    versions: &HashMap<(PackageType, &'a str), Vec<JobNode<'a>>>,
+    package_type: PackageType,
+    name: &'a str,
+    version: &'a str,
+) -> Option<JobNode<'a>> {
+    match index.get_key_value(&(package_type, name, version)) {
+        Some((&node, _)) => Some(node),
+        None => match versions.get(&(package_type, name))?.as_slice() {
+            [node] => Some(*node),
+            _ => None,
+        },
+    }
+}
+
+/// The packages of a job, each with the package it was first reached from,
+/// breadth first from the top level packages
+struct JobGraph<'a> {
+    packages: HashMap<JobNode<'a>, &'a PackageStatusExtended>,
+    parents: HashMap<JobNode<'a>, Option<JobNode<'a>>>,
+}
+
+impl<'a> JobGraph<'a> {
+    fn new(packages: &'a [PackageStatusExtended]) -> Self {
+        let index: HashMap<_, _> = packages
+            .iter()
+            .map(|package| (job_node(package), package))
+            .collect();
+        let mut versions: HashMap<(PackageType, &str), Vec<JobNode<'a>>> = HashMap::new();
+        for &node in index.keys() {
+            versions.entry((node.0, node.1)).or_default().push(node);
+        }
+
+        let children = |package: &'a PackageStatusExtended| {
+            let (index, versions) = (&index, &versions);
+            package
+                .dependencies
+                .iter()
+                .filter_map(move |(name, version)| {
+                    resolve(index, versions, package.package_type, name, version)
+                })
+        };
+
+        let dependencies: HashSet<JobNode<'a>> = packages.iter().flat_map(children).collect();
+
+        let mut parents = HashMap::new();
+        let mut queue = VecDeque::new();
+        for package in packages {
+            let node = job_node(package);
+            if !dependencies.contains(&node) && !parents.contains_key(&node) {
+                parents.insert(node, None);
+                queue.push_back(package);
+            }
+        }
+
+        while let Some(package) = queue.pop_front() {
+            for child in children(package) {
+                if parents.contains_key(&child) {
+                    continue;
+                }
+                parents.insert(child, Some(job_node(package)));
+                queue.push_back(index[&child]);
+            }
+        }
+
+        Self {
+            packages: index,
+            parents,
+        }
+    }
+
+    fn path_to(&self, node: JobNode<'_>) -> Option<Vec<PackageRef>> {
+        let mut path = Vec::new();
+        let mut next = Some(node);
+        while let Some(node) = next {
+            let package = self.packages.get(&node)?;
+            path.push(PackageRef::new(
+                package.basic_status.name.clone(),
+                package.basic_status.version.clone(),
+                package.package_type,
+            ));
+            next = *self.parents.get(&node)?;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
@@ -1,5 +1,35 @@
 //! This crate contains common types used to share data between the Phylum API
 //! and CLI tool.
 
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "binary")]
+pub mod binary;
+pub mod body;
+pub mod cache_key;
+pub mod cache_policy;
+pub mod casing;
+#[cfg(feature = "diff")]
+pub mod diff;
 pub mod ecosystems;
+pub mod endpoint;
+pub mod exhaustive;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod graph;
+#[cfg(feature = "mock")]
+pub mod mock;
+#[cfg(feature = "mock-server")]
+pub mod mock_server;
+pub mod pagination;
+#[cfg(feature = "sarif")]
+pub mod sarif;
+pub mod siem;
+#[cfg(feature = "simd-json")]
+pub mod simd;
+#[cfg(feature = "csv")]
+pub mod tabular;
 pub mod types;
+pub mod validation;
@@ -0,0 +1,395 @@
+//! Fake data generation for tests and demos.
+//!
+//! All generators are driven by a [`FakeRng`], so the same seed always
+//! produces the same data.
+
+use indexmap::IndexMap;
+use uuid::Uuid;
+
+use crate::types::common::Status;
+use crate::types::job::{JobMessageCode, JobStatusResponse};
+use crate::types::label::Label;
+use crate::types::package::*;
+use crate::types::timestamp::{self, Duration, Timestamp};
+
+const WORDS: &[&str] = &[
+    "left", "pad", "fast", "json", "parse", "util", "core", "http", "async", "color", "string",
+    "stream", "glob", "mini", "yaml", "log", "test", "cli", "date", "crypto", "path", "cache",
+];
+
+const LICENSES: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "BSD-3-Clause",
+    "ISC",
+    "GPL-3.0",
+    "MPL-2.0",
+];
+
+const ECOSYSTEMS: &[PackageType] = &[
+    PackageType::Npm,
+    PackageType::PyPi,
+    PackageType::Maven,
+    PackageType::RubyGems,
+    PackageType::Nuget,
+    PackageType::Cargo,
+    PackageType::Golang,
+    PackageType::Composer,
+    PackageType::Conan,
+];
+
+const DOMAINS: &[RiskDomain] = &crate::all_risk_domains!();
+
+const EXPLOIT_MATURITIES: &[ExploitMaturity] = &[
+    ExploitMaturity::Unproven,
+    ExploitMaturity::Unproven,
+    ExploitMaturity::ProofOfConcept,
+    ExploitMaturity::Functional,
+    ExploitMaturity::Weaponized,
+];
+
+/// Small deterministic pseudo random number generator (SplitMix64).
+#[derive(Clone, Debug, Default)]
+pub struct FakeRng {
+    state: u64,
+}
+
+impl FakeRng {
+    pub fn seeded(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..upper`.
+    pub fn below(&mut self, upper: u64) -> u64 {
+        self.next_u64() % upper.max(1)
+    }
+
+    /// A number in `0.0..1.0`.
+    pub fn unit(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    fn uuid(&mut self) -> Uuid {
+        Uuid::from_u64_pair(self.next_u64(), self.next_u64())
+    }
+
+    fn date(&mut self) -> Timestamp {
+        // Somewhere between 2015 and 2023.
+        let secs = 1_420_070_400 + self.below(8 * 365 * 24 * 3600) as i64;
+        timestamp::from_epoch_seconds(secs).unwrap()
+    }
+
+    fn word(&mut self) -> &'static str {
+        WORDS[self.below(WORDS.len() as u64) as usize]
+    }
+}
+
+/// Types which can generate plausible fake instances of themselves.
+pub trait Fake: Sized {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self;
+
+    fn fake() -> Self {
+        Self::fake_with_rng(&mut FakeRng::default())
+    }
+
+    fn fake_seeded(seed: u64) -> Self {
+        Self::fake_with_rng(&mut FakeRng::seeded(seed))
+    }
+}
+
+/// Generate a package name in the style of the given ecosystem.
+pub fn fake_name(rng: &mut FakeRng, package_type: PackageType) -> String {
+    let (first, second) = (rng.word(), rng.word());
+    match package_type {
+        PackageType::Npm if rng.below(4) == 0 => format!("@{first}/{second}"),
+        PackageType::Npm | PackageType::PyPi => format!("{first}-{second}"),
+        PackageType::Composer => format!("{first}/{second}"),
+        PackageType::Maven => format!("org.{first}:{first}-{second}"),
+        PackageType::RubyGems | PackageType::Cargo | PackageType::Conan => {
+            format!("{first}_{second}")
+        }
+        PackageType::Nuget => format!("{}.{}", capitalize(first), capitalize(second)),
+        PackageType::Golang => format!("github.com/{first}/{second}"),
+    }
+}
+
+/// Generate a version in the style of the given ecosystem.
+pub fn fake_version(rng: &mut FakeRng, package_type: PackageType) -> String {
+    let (major, minor, patch) = (rng.below(5), rng.below(20), rng.below(30));
+    match package_type {
+        PackageType::Golang => format!("v{major}.{minor}.{patch}"),
+        PackageType::Maven if rng.below(5) == 0 => format!("{major}.{minor}.{patch}.Final"),
+        _ => format!("{major}.{minor}.{patch}"),
+    }
+}
+
+/// Generate issues, with vulnerabilities more common in large ecosystems.
+pub fn fake_issues(rng: &mut FakeRng, package_type: PackageType) -> Vec<Issue> {
+    let max_issues = match package_type {
+        PackageType::Npm | PackageType::PyPi => 5,
+        _ => 3,
+    };
+    (0..rng.below(max_issues + 1))
+        .map(|_| Issue::fake_with_rng(rng))
+        .collect()
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|c| c.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn fake_level(rng: &mut FakeRng) -> RiskLevel {
+    // Skewed towards the less severe end, as on real data.
+    match rng.below(100) {
+        0..=39 => RiskLevel::Info,
+        40..=69 => RiskLevel::Low,
+        70..=86 => RiskLevel::Medium,
+        87..=96 => RiskLevel::High,
+        _ => RiskLevel::Critical,
+    }
+}
+
+fn fake_score(rng: &mut FakeRng) -> f32 {
+    (0.3 + rng.unit() * 0.7) as f32
+}
+
+impl Fake for PackageType {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        *rng.pick(ECOSYSTEMS)
+    }
+}
+
+#[allow(deprecated)]
+impl Fake for PackageDescriptor {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let package_type = PackageType::fake_with_rng(rng);
+        Self {
+            name: fake_name(rng, package_type),
+            version: fake_version(rng, package_type),
+            package_type,
+            namespace: None,
+        }
+    }
+}
+
+impl Fake for RiskScores {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let mut scores = Self {
+            total: 0.,
+            vulnerability: fake_score(rng),
+            malicious: fake_score(rng),
+            author: fake_score(rng),
+            engineering: fake_score(rng),
+            license: fake_score(rng),
+        };
+        scores.total = [
+            scores.vulnerability,
+            scores.malicious,
+            scores.author,
+            scores.engineering,
+            scores.license,
+        ]
+        .iter()
+        .copied()
+        .fold(1., f32::min);
+        scores
+    }
+}
+
+impl Fake for Issue {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let domain = *rng.pick(DOMAINS);
+        let severity = fake_level(rng);
+        let (tag, title) = match domain {
+            RiskDomain::Vulnerabilities => {
+                let id = format!("CVE-{}-{}", 2015 + rng.below(9), 1000 + rng.below(40000));
+                let title = format!("{id} in {}", rng.word());
+                (id, title)
+            }
+            _ => (
+                format!("HM{:04}", rng.below(100)),
+                format!("Suspicious {} behavior", rng.word()),
+            ),
+        };
+        let description = format!("{title}. This is a generated issue.");
+        let issue = Issue::new(title, severity, domain)
+            .with_id(rng.uuid().to_string())
+            .with_tag(tag)
+            .with_description(description);
+        match domain {
+            RiskDomain::Vulnerabilities => {
+                issue.with_exploit_maturity(*rng.pick(EXPLOIT_MATURITIES))
+            }
+            _ => issue,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Fake for Package {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let descriptor = PackageDescriptor::fake_with_rng(rng);
+        let issues_details = fake_issues(rng, descriptor.package_type);
+        let issues = issues_details
+            .iter()
+            .map(|issue| IssuesListItem {
+                risk_type: issue.domain.into(),
+                score: issue.severity.score(),
+                impact: issue.severity,
+                description: issue.description.clone(),
+                title: issue.title.clone(),
+                tag: issue.tag.clone(),
+                id: issue.id.clone(),
+                ignored: None,
+            })
+            .collect();
+        let published = rng.date();
+        Self {
+            purl: None,
+            id: format!(
+                "{}:{}:{}",
+                descriptor.package_type, descriptor.name, descriptor.version
+            ),
+            registry: descriptor.package_type.to_string().as_str().into(),
+            published_date: Some(timestamp::to_rfc3339(&published)),
+            latest_version: Some(descriptor.version.clone()),
+            versions: vec![ScoredVersion {
+                version: descriptor.version.clone(),
+                total_risk_score: Some(fake_score(rng)),
+            }],
+            description: Some(format!("A {} {} library", rng.word(), rng.word())),
+            license: Some((*rng.pick(LICENSES)).into()),
+            download_count: rng.below(10_000_000),
+            risk_scores: Some(RiskScores::fake_with_rng(rng)),
+            issues_details,
+            issues,
+            complete: Some(true),
+            name: descriptor.name,
+            version: descriptor.version,
+            ..Default::default()
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Fake for PackageStatus {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let descriptor = PackageDescriptor::fake_with_rng(rng);
+        Self {
+            purl: None,
+            name: descriptor.name,
+            version: descriptor.version,
+            status: Status::Complete,
+            last_updated: timestamp::to_epoch_seconds(&rng.date()) as u64,
+            license: Some((*rng.pick(LICENSES)).into()),
+            package_score: Some(fake_score(rng) as f64),
+            num_dependencies: rng.below(50) as u32,
+            num_vulnerabilities: Some(rng.below(4) as u32),
+            lockfiles: Vec::new(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl Fake for PackageStatusExtended {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let package_type = PackageType::fake_with_rng(rng);
+        let mut basic_status = PackageStatus::fake_with_rng(rng);
+        basic_status.name = fake_name(rng, package_type);
+        basic_status.version = fake_version(rng, package_type);
+
+        let scores = RiskScores::fake_with_rng(rng);
+        let risk_vectors = IndexMap::from([
+            ("vulnerability".to_string(), scores.vulnerability as f64),
+            ("malicious_code".to_string(), scores.malicious as f64),
+            ("author".to_string(), scores.author as f64),
+            ("engineering".to_string(), scores.engineering as f64),
+            ("license".to_string(), scores.license as f64),
+        ]);
+        let dependencies = (0..rng.below(4))
+            .map(|_| {
+                (
+                    fake_name(rng, package_type),
+                    fake_version(rng, package_type),
+                )
+            })
+            .collect();
+        let issues = fake_issues(rng, package_type)
+            .into_iter()
+            .map(|issue| IssueStatus {
+                issue,
+                ignored: None,
+                #[cfg(deny_unknown_fields)]
+                extra: Default::default(),
+            })
+            .collect();
+
+        Self {
+            basic_status,
+            package_type,
+            risk_vectors,
+            dependencies,
+            issues,
+            #[cfg(deny_unknown_fields)]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl<T: Fake> JobStatusResponse<T> {
+    /// A fake job with `n_packages` packages, using the default seed.
+    pub fn fake_with(n_packages: usize) -> Self {
+        Self::fake_with_packages(&mut FakeRng::default(), n_packages)
+    }
+
+    /// A fake job with `n_packages` packages.
+    pub fn fake_with_packages(rng: &mut FakeRng, n_packages: usize) -> Self {
+        let package_type = PackageType::fake_with_rng(rng);
+        let created_at = rng.date();
+        Self {
+            job_id: rng.uuid(),
+            ecosystems: vec![package_type.into()],
+            user_id: rng.uuid(),
+            user_email: format!("{}@example.com", rng.word()),
+            created_at,
+            status: Status::Complete,
+            pass: rng.below(4) != 0,
+            msg: "Project met threshold requirements".into(),
+            code: Some(JobMessageCode::ThresholdsMet),
+            details: Default::default(),
+            num_incomplete: 0,
+            last_updated: created_at + Duration::seconds(rng.below(3600) as i64),
+            project: rng.uuid(),
+            project_name: format!("{}-{}", rng.word(), rng.word()),
+            label: Label::new("main").ok(),
+            packages: (0..n_packages).map(|_| T::fake_with_rng(rng)).collect(),
+            metadata: Default::default(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+}
+
+impl<T: Fake> Fake for JobStatusResponse<T> {
+    fn fake_with_rng(rng: &mut FakeRng) -> Self {
+        let n_packages = 1 + rng.below(10) as usize;
+        Self::fake_with_packages(rng, n_packages)
+    }
+}
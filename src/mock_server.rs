@@ -0,0 +1,169 @@
+//! Canned request and response pairs for each endpoint, for mock HTTP
+//! servers such as wiremock or httpmock.
+//!
+//! Every pair is built from the recorded payloads in [`crate::fixtures`], and
+//! its path from the [`Endpoint`] it belongs to, so ids in paths match the
+//! payloads they return. A server can be primed with the whole catalog:
+//!
+//! ```
+//! use phylum_types::endpoint::{GetJobStatus, Method};
+//! use phylum_types::mock_server::{self, Fixtures};
+//!
+//! for exchange in mock_server::catalog() {
+//!     // With wiremock, for example:
+//!     // Mock::given(method(exchange.method.as_str()))
+//!     //     .and(path(&exchange.path))
+//!     //     .respond_with(ResponseTemplate::new(exchange.status)
+//!     //         .set_body_raw(exchange.response, "application/json"))
+//!     assert!(exchange.path.starts_with('/'));
+//! }
+//!
+//! let found = mock_server::find(Method::Get, "/data/jobs/59482a54-423b-448d-8325-f171c9dc336b");
+//! assert_eq!(found.unwrap().endpoint, "GetJobStatus");
+//! assert_eq!(GetJobStatus::exchanges().len(), 2);
+//! ```
+
+use uuid::Uuid;
+
+use crate::endpoint::{
+    Endpoint, GetJobStatus, GetPackage, ListJobs, ListProjects, Method, SubmitPackages,
+};
+use crate::fixtures;
+use crate::types::package::PackageRef;
+
+/// A request and the response a mock server should give to it
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Exchange {
+    /// Name of the endpoint type, such as `GetJobStatus`
+    pub endpoint: &'static str,
+    pub method: Method,
+    /// The path, with parameters filled in
+    pub path: String,
+    /// The JSON request body, for endpoints which take one
+    pub request: Option<&'static str>,
+    /// HTTP status of the response
+    pub status: u16,
+    /// The JSON response body
+    pub response: &'static str,
+}
+
+impl Exchange {
+    fn new<E: Endpoint>(
+        endpoint: &'static str,
+        params: &E::Params,
+        request: Option<&'static str>,
+        status: u16,
+        response: &'static str,
+    ) -> Self {
+        Self {
+            endpoint,
+            method: E::METHOD,
+            path: E::path(params),
+            request,
+            status,
+            response,
+        }
+    }
+
+    /// Whether a request with the given method and path, without any query
+    /// string, is answered by this exchange.
+    pub fn matches(&self, method: Method, path: &str) -> bool {
+        self.method == method && self.path == path
+    }
+}
+
+/// Endpoints with canned exchanges
+pub trait Fixtures: Endpoint {
+    fn exchanges() -> Vec<Exchange>;
+}
+
+impl Fixtures for ListJobs {
+    fn exchanges() -> Vec<Exchange> {
+        vec![Exchange::new::<Self>(
+            "ListJobs",
+            &(),
+            None,
+            200,
+            fixtures::ALL_JOBS_STATUS_RESPONSE,
+        )]
+    }
+}
+
+impl Fixtures for GetJobStatus {
+    fn exchanges() -> Vec<Exchange> {
+        let job_id = fixtures::job_status_extended().job_id;
+        vec![
+            Exchange::new::<Self>(
+                "GetJobStatus",
+                &job_id,
+                None,
+                200,
+                fixtures::JOB_STATUS_EXTENDED,
+            ),
+            Exchange::new::<Self>(
+                "GetJobStatus",
+                &Uuid::nil(),
+                None,
+                404,
+                fixtures::API_ERROR_NOT_FOUND,
+            ),
+        ]
+    }
+}
+
+impl Fixtures for SubmitPackages {
+    fn exchanges() -> Vec<Exchange> {
+        vec![Exchange::new::<Self>(
+            "SubmitPackages",
+            &(),
+            Some(fixtures::SUBMIT_PACKAGE_REQUEST),
+            201,
+            fixtures::SUBMIT_PACKAGE_RESPONSE,
+        )]
+    }
+}
+
+impl Fixtures for GetPackage {
+    fn exchanges() -> Vec<Exchange> {
+        let package = fixtures::package();
+        let ecosystem = package.registry.to_string();
+        let package = PackageRef::new(package.name, package.version, ecosystem);
+        vec![Exchange::new::<Self>(
+            "GetPackage",
+            &package,
+            None,
+            200,
+            fixtures::PACKAGE,
+        )]
+    }
+}
+
+impl Fixtures for ListProjects {
+    fn exchanges() -> Vec<Exchange> {
+        vec![Exchange::new::<Self>(
+            "ListProjects",
+            &(),
+            None,
+            200,
+            fixtures::PROJECT_SUMMARIES,
+        )]
+    }
+}
+
+/// The exchanges of every endpoint with fixtures.
+pub fn catalog() -> Vec<Exchange> {
+    let mut exchanges = Vec::new();
+    exchanges.extend(ListJobs::exchanges());
+    exchanges.extend(GetJobStatus::exchanges());
+    exchanges.extend(SubmitPackages::exchanges());
+    exchanges.extend(GetPackage::exchanges());
+    exchanges.extend(ListProjects::exchanges());
+    exchanges
+}
+
+/// The exchange answering a request, if the catalog has one.
+pub fn find(method: Method, path: &str) -> Option<Exchange> {
+    catalog()
+        .into_iter()
+        .find(|exchange| exchange.matches(method, path))
+}
@@ -0,0 +1,94 @@
+//! Walking cursor paginated listings.
+//!
+//! [`PageWalker`] turns an endpoint returning [`Paginated`] values into an
+//! iterator over the values of every page. It only asks for the next page
+//! once the previous one is used up, and the caller's closure does the
+//! fetching, so any blocking client fits:
+//!
+//! ```
+//! use phylum_types::endpoint::ListProjectsPage;
+//! use phylum_types::pagination::PageWalker;
+//! use phylum_types::types::common::PageParams;
+//!
+//! let project = |name| format!(
+//!     r#"{{"name": "{name}", "id": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+//!         "created_at": "2022-01-10T09:00:00Z", "updated_at": "2022-04-15T05:20:00Z",
+//!         "group_name": null, "repository_url": null}}"#
+//! );
+//! let first = format!(r#"{{"values": [{}, {}], "next_cursor": "b"}}"#, project("api"), project("web"));
+//! let last = format!(r#"{{"values": [{}]}}"#, project("cli"));
+//!
+//! let params = PageParams { limit: Some(2), ..Default::default() };
+//! let walker = PageWalker::<ListProjectsPage, _, _, _>::new(params, |params| {
+//!     // Send the request with `params` as its query here
+//!     let body = if params.cursor.is_none() { &first } else { &last };
+//!     serde_json::from_str(body)
+//! });
+//! let names: Vec<_> = walker.map(|project| project.unwrap().name).collect();
+//! assert_eq!(names, ["api", "web", "cli"]);
+//! ```
+//!
+//! Async clients can follow [`Paginated::next_cursor`] the same way in their
+//! own loop.
+
+use std::marker::PhantomData;
+use std::vec;
+
+use crate::endpoint::Endpoint;
+use crate::types::common::{PageParams, Paginated};
+
+/// An iterator over the values of every page of a listing
+pub struct PageWalker<E, T, F, Err> {
+    fetch: F,
+    /// Parameters of the next page to fetch, or `None` after the last page
+    params: Option<PageParams>,
+    values: vec::IntoIter<T>,
+    endpoint: PhantomData<fn() -> (E, Err)>,
+}
+
+impl<E, T, F, Err> PageWalker<E, T, F, Err>
+where
+    E: Endpoint<Query = PageParams, Response = Paginated<T>>,
+    F: FnMut(&PageParams) -> Result<Paginated<T>, Err>,
+{
+    /// Walk pages starting from `params`, fetching each with `fetch`.
+    pub fn new(params: PageParams, fetch: F) -> Self {
+        Self {
+            fetch,
+            params: Some(params),
+            values: Vec::new().into_iter(),
+            endpoint: PhantomData,
+        }
+    }
+}
+
+impl<E, T, F, Err> Iterator for PageWalker<E, T, F, Err>
+where
+    F: FnMut(&PageParams) -> Result<Paginated<T>, Err>,
+{
+    type Item = Result<T, Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                return Some(Ok(value));
+            }
+
+            let params = self.params.take()?;
+            let page = match (self.fetch)(&params) {
+                Ok(page) => page,
+                // Stop after an error, rather than fetching the same page again
+                Err(err) => return Some(Err(err)),
+            };
+
+            // A server repeating its cursor would otherwise be walked forever
+            if page.next_cursor.is_some() && page.next_cursor != params.cursor {
+                self.params = Some(PageParams {
+                    cursor: page.next_cursor,
+                    ..params
+                });
+            }
+            self.values = page.values.into_iter();
+        }
+    }
+}
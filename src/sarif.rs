@@ -0,0 +1,375 @@
+//! Conversion of issues into the SARIF 2.1.0 log format, as consumed by
+//! GitHub code scanning.
+//!
+//! Each distinct issue tag becomes a rule, and each issue a result. Issues
+//! with evidence point at the offending files. Other issues of a package
+//! point at the lockfile it was found in, or at the conventional manifest of
+//! its ecosystem, such as `package.json`, when no lockfile is known.
+//!
+//! ```
+//! use phylum_types::sarif::SarifLog;
+//! use phylum_types::types::package::{Issue, RiskDomain, RiskLevel};
+//!
+//! let issue = Issue::new("Prototype pollution", RiskLevel::High, RiskDomain::Vulnerabilities)
+//!     .with_tag("HV0001")
+//!     .with_description("Merging untrusted objects can modify Object.prototype");
+//!
+//! let log = SarifLog::from_issues(&[issue]);
+//! assert_eq!(log.runs[0].tool.driver.rules[0].id, "HV0001");
+//! assert_eq!(log.runs[0].results[0].level, "error");
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::job::JobStatusResponse;
+use crate::types::package::{Issue, PackageStatusExtended, PackageType, ReferenceKind, RiskLevel};
+
+/// URI of the SARIF 2.1.0 JSON schema.
+pub const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+pub const VERSION: &str = "2.1.0";
+
+/// Root of a SARIF log file
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct SarifLog {
+    #[serde(rename = "$schema")]
+    pub schema: String,
+    pub version: String,
+    pub runs: Vec<Run>,
+}
+
+/// The results of a single run of an analysis tool
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct Run {
+    pub tool: Tool,
+    pub results: Vec<SarifResult>,
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct Tool {
+    pub driver: ToolComponent,
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolComponent {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub information_uri: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<ReportingDescriptor>,
+}
+
+/// A rule, derived from an issue tag
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportingDescriptor {
+    pub id: String,
+    pub short_description: Message,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_description: Option<Message>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub help_uri: Option<String>,
+    pub default_configuration: ReportingConfiguration,
+    #[serde(default)]
+    pub properties: RuleProperties,
+}
+
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct ReportingConfiguration {
+    pub level: String,
+}
+
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RuleProperties {
+    /// CVSS style score from 0.0 to 10.0, used by GitHub to rank alerts
+    #[serde(
+        rename = "security-severity",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub security_severity: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Message {
+    pub text: String,
+}
+
+impl From<String> for Message {
+    fn from(text: String) -> Self {
+        Self { text }
+    }
+}
+
+/// A single finding
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    pub rule_id: String,
+    pub rule_index: usize,
+    pub level: String,
+    pub message: Message,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suppressions: Vec<Suppression>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub physical_location: Option<PhysicalLocation>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub logical_locations: Vec<LogicalLocation>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    pub artifact_location: ArtifactLocation,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct ArtifactLocation {
+    pub uri: String,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<Message>,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LogicalLocation {
+    pub fully_qualified_name: String,
+    pub kind: String,
+}
+
+/// Marks a result as reviewed and ignored
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct Suppression {
+    pub kind: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub justification: Option<String>,
+}
+
+impl SarifLog {
+    /// A log with a single run containing the given results.
+    fn new(rules: Rules, results: Vec<SarifResult>) -> Self {
+        Self {
+            schema: SCHEMA.into(),
+            version: VERSION.into(),
+            runs: vec![Run {
+                tool: Tool {
+                    driver: ToolComponent {
+                        name: "Phylum".into(),
+                        information_uri: Some("https://phylum.io".into()),
+                        rules: rules.descriptors,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+
+    /// A log of issues which are not tied to a package.
+    pub fn from_issues(issues: &[Issue]) -> Self {
+        let mut rules = Rules::default();
+        let results = issues
+            .iter()
+            .map(|issue| rules.result(issue, issue.title.clone(), None))
+            .collect();
+        Self::new(rules, results)
+    }
+}
+
+impl From<&JobStatusResponse<PackageStatusExtended>> for SarifLog {
+    fn from(job: &JobStatusResponse<PackageStatusExtended>) -> Self {
+        let mut rules = Rules::default();
+        let mut results = Vec::new();
+        for package in &job.packages {
+            let status = &package.basic_status;
+            let location = PackageLocation {
+                name: format!("{}@{}", status.name, status.version),
+                uri: match status.lockfiles.first() {
+                    Some(lockfile) => lockfile.path.as_str().into(),
+                    None => manifest(package.package_type).into(),
+                },
+            };
+            for issue_status in &package.issues {
+                let issue = &issue_status.issue;
+                let message = format!("{} in {}", issue.title, location.name);
+                let mut result = rules.result(issue, message, Some(&location));
+                if let Some(justification) = &issue_status.ignored {
+                    result.suppressions.push(Suppression {
+                        kind: "external".into(),
+                        justification: Some(justification.clone()),
+                    });
+                }
+                results.push(result);
+            }
+        }
+        Self::new(rules, results)
+    }
+}
+
+/// Where the issues of a package are reported.
+struct PackageLocation {
+    /// Name and version, such as `lodash@4.17.20`
+    name: String,
+    /// The lockfile or manifest the package is declared in
+    uri: String,
+}
+
+/// Rules collected while converting issues, indexed by id.
+#[derive(Default)]
+struct Rules {
+    descriptors: Vec<ReportingDescriptor>,
+    indices: BTreeMap<String, usize>,
+}
+
+impl Rules {
+    fn result(
+        &mut self,
+        issue: &Issue,
+        message: String,
+        package: Option<&PackageLocation>,
+    ) -> SarifResult {
+        let rule_id = rule_id(issue);
+        let rule_index = self.index(&rule_id, issue);
+
+        let mut locations: Vec<Location> = issue
+            .evidence
+            .iter()
+            .map(|evidence| Location {
+                physical_location: Some(PhysicalLocation {
+                    artifact_location: ArtifactLocation {
+                        uri: evidence.path.clone(),
+                    },
+                    region: Some(Region {
+                        start_line: evidence.lines.map(|lines| lines.start),
+                        end_line: evidence.lines.map(|lines| lines.end),
+                        snippet: evidence.snippet.clone().map(Message::from),
+                    }),
+                }),
+                logical_locations: Vec::new(),
+            })
+            .collect();
+        if let Some(package) = package {
+            let logical = LogicalLocation {
+                fully_qualified_name: package.name.clone(),
+                kind: "package".into(),
+            };
+            match locations.first_mut() {
+                Some(location) => location.logical_locations.push(logical),
+                None => locations.push(Location {
+                    physical_location: Some(PhysicalLocation {
+                        artifact_location: ArtifactLocation {
+                            uri: package.uri.clone(),
+                        },
+                        region: None,
+                    }),
+                    logical_locations: vec![logical],
+                }),
+            }
+        }
+
+        SarifResult {
+            rule_id,
+            rule_index,
+            level: level(issue.severity).into(),
+            message: message.into(),
+            locations,
+            suppressions: Vec::new(),
+        }
+    }
+
+    fn index(&mut self, rule_id: &str, issue: &Issue) -> usize {
+        if let Some(index) = self.indices.get(rule_id) {
+            return *index;
+        }
+
+        let index = self.descriptors.len();
+        self.descriptors.push(ReportingDescriptor {
+            id: rule_id.into(),
+            short_description: issue.title.clone().into(),
+            full_description: Some(issue.description.clone())
+                .filter(|description| !description.is_empty())
+                .map(Message::from),
+            help_uri: issue
+                .references_of(ReferenceKind::Advisory)
+                .chain(&issue.references)
+                .next()
+                .map(|reference| reference.url.clone()),
+            default_configuration: ReportingConfiguration {
+                level: level(issue.severity).into(),
+            },
+            properties: RuleProperties {
+                security_severity: Some(security_severity(issue.severity).into()),
+                tags: vec![
+                    "security".into(),
+                    crate::siem::category(issue.domain).into(),
+                ],
+            },
+        });
+        self.indices.insert(rule_id.into(), index);
+        index
+    }
+}
+
+/// The conventional manifest declaring dependencies of an ecosystem.
+fn manifest(package_type: PackageType) -> &'static str {
+    match package_type {
+        PackageType::Npm => "package.json",
+        PackageType::PyPi => "requirements.txt",
+        PackageType::Maven => "pom.xml",
+        PackageType::RubyGems => "Gemfile",
+        PackageType::Nuget => "packages.config",
+        PackageType::Cargo => "Cargo.toml",
+        PackageType::Golang => "go.mod",
+        PackageType::Composer => "composer.json",
+        PackageType::Conan => "conanfile.txt",
+    }
+}
+
+fn rule_id(issue: &Issue) -> String {
+    issue
+        .tag
+        .clone()
+        .or_else(|| issue.id.clone())
+        .unwrap_or_else(|| issue.title.clone())
+}
+
+/// The SARIF result level for a severity.
+pub fn level(severity: RiskLevel) -> &'static str {
+    match severity {
+        RiskLevel::Critical | RiskLevel::High => "error",
+        RiskLevel::Medium => "warning",
+        RiskLevel::Low | RiskLevel::Info => "note",
+    }
+}
+
+/// The GitHub `security-severity` score for a severity.
+fn security_severity(severity: RiskLevel) -> &'static str {
+    match severity {
+        RiskLevel::Critical => "9.5",
+        RiskLevel::High => "8.0",
+        RiskLevel::Medium => "5.5",
+        RiskLevel::Low => "3.0",
+        RiskLevel::Info => "0.0",
+    }
+}
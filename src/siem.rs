@@ -0,0 +1,241 @@
+//! Conversion of findings and firewall logs into events for SIEM systems.
+//!
+//! [`SiemEvent`] is a flat record which serializes to JSON for generic
+//! ingestion, such as the Splunk HTTP event collector, and can be rendered
+//! in the ArcSight CEF and QRadar LEEF formats:
+//!
+//! ```
+//! use phylum_types::siem::SiemEvent;
+//! use phylum_types::types::package::{Issue, PackageRef, PackageType, RiskDomain, RiskLevel};
+//!
+//! let issue = Issue::new(
+//!     "Install script downloads a binary",
+//!     RiskLevel::High,
+//!     RiskDomain::Malicious,
+//! )
+//! .with_tag("HM0023")
+//! .with_description("Runs curl | sh");
+//! let package = PackageRef::new("left-pad", "1.3.0", PackageType::Npm);
+//!
+//! let event = SiemEvent::from(&issue).with_package(&package);
+//! assert_eq!(
+//!     event.to_cef(),
+//!     "CEF:0|Phylum|Phylum|1|HM0023|Install script downloads a binary|8|\
+//!      cat=malicious_code msg=Runs curl | sh suser=left-pad@1.3.0 cs1Label=ecosystem cs1=npm"
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::firewall::{FirewallAction, FirewallLogEntry};
+#[allow(deprecated)]
+use crate::types::package::{Issue, PackageRef, PackageSpecifier, RiskDomain, RiskLevel};
+use crate::types::timestamp::{self, Timestamp};
+
+/// Vendor reported in CEF and LEEF headers.
+pub const VENDOR: &str = "Phylum";
+/// Product reported in CEF and LEEF headers.
+pub const PRODUCT: &str = "Phylum";
+/// Version of the event layout reported in CEF and LEEF headers.
+pub const EVENT_VERSION: &str = "1";
+
+/// A single security event, in a shape common to SIEM systems.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SiemEvent {
+    /// Identifier of the kind of event, such as an issue tag
+    pub signature_id: String,
+    pub name: String,
+    /// Severity from 0 (lowest) to 10 (highest)
+    pub severity: u8,
+    pub category: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// The affected package as `name@version`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ecosystem: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+}
+
+#[allow(deprecated)]
+impl SiemEvent {
+    /// Attach the package an event refers to.
+    pub fn with_package(mut self, package: &PackageRef) -> Self {
+        let specifier = PackageSpecifier::from(package.clone());
+        self.package = Some(format!("{}@{}", specifier.name, specifier.version));
+        self.ecosystem = Some(package.ecosystem.to_string());
+        self
+    }
+
+    pub fn with_timestamp(mut self, timestamp: Timestamp) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Render this event in ArcSight Common Event Format.
+    pub fn to_cef(&self) -> String {
+        let mut cef = format!(
+            "CEF:0|{}|{}|{}|{}|{}|{}|",
+            escape_cef_header(VENDOR),
+            escape_cef_header(PRODUCT),
+            escape_cef_header(EVENT_VERSION),
+            escape_cef_header(&self.signature_id),
+            escape_cef_header(&self.name),
+            self.severity,
+        );
+
+        let mut extensions = vec![("cat", self.category.clone())];
+        extensions.extend(self.message.clone().map(|message| ("msg", message)));
+        extensions.extend(self.package.clone().map(|package| ("suser", package)));
+        if let Some(ecosystem) = &self.ecosystem {
+            extensions.push(("cs1Label", "ecosystem".into()));
+            extensions.push(("cs1", ecosystem.clone()));
+        }
+        if let Some(timestamp) = self.timestamp {
+            extensions.push(("rt", timestamp::to_epoch_millis(&timestamp).to_string()));
+        }
+
+        for (i, (key, value)) in extensions.iter().enumerate() {
+            if i > 0 {
+                cef.push(' ');
+            }
+            let _ = write!(cef, "{key}={}", escape_cef_value(value));
+        }
+        cef
+    }
+
+    /// Render this event in IBM QRadar Log Event Extended Format 1.0.
+    pub fn to_leef(&self) -> String {
+        let mut leef = format!(
+            "LEEF:1.0|{}|{}|{}|{}|",
+            escape_leef_header(VENDOR),
+            escape_leef_header(PRODUCT),
+            escape_leef_header(EVENT_VERSION),
+            escape_leef_header(&self.signature_id),
+        );
+
+        let mut attributes = vec![
+            ("sev", self.severity.max(1).to_string()),
+            ("cat", self.category.clone()),
+            ("name", self.name.clone()),
+        ];
+        attributes.extend(self.message.clone().map(|message| ("msg", message)));
+        attributes.extend(self.package.clone().map(|package| ("package", package)));
+        attributes.extend(
+            self.ecosystem
+                .clone()
+                .map(|ecosystem| ("ecosystem", ecosystem)),
+        );
+        if let Some(timestamp) = self.timestamp {
+            attributes.push((
+                "devTime",
+                timestamp::to_epoch_millis(&timestamp).to_string(),
+            ));
+            attributes.push(("devTimeFormat", "epoch_millis".into()));
+        }
+
+        for (i, (key, value)) in attributes.iter().enumerate() {
+            if i > 0 {
+                leef.push('\t');
+            }
+            let _ = write!(leef, "{key}={}", escape_leef_value(value));
+        }
+        leef
+    }
+}
+
+impl From<&Issue> for SiemEvent {
+    fn from(issue: &Issue) -> Self {
+        let signature_id = issue
+            .tag
+            .clone()
+            .or_else(|| issue.id.clone())
+            .or_else(|| issue.rule.clone())
+            .unwrap_or_else(|| "issue".into());
+        let message = Some(issue.description.clone()).filter(|message| !message.is_empty());
+
+        Self {
+            signature_id,
+            name: issue.title.clone(),
+            severity: severity(issue.severity),
+            category: category(issue.domain).into(),
+            message,
+            package: None,
+            ecosystem: None,
+            timestamp: None,
+        }
+    }
+}
+
+impl From<&FirewallLogEntry> for SiemEvent {
+    fn from(entry: &FirewallLogEntry) -> Self {
+        let (signature_id, name, severity) = match entry.action {
+            FirewallAction::Allow => ("firewall-allow", "Package allowed", 1),
+            FirewallAction::Deny => ("firewall-deny", "Package denied", 7),
+            FirewallAction::Quarantine => ("firewall-quarantine", "Package quarantined", 5),
+        };
+        let message = Some(entry.reasons.join("; ")).filter(|message| !message.is_empty());
+
+        Self {
+            signature_id: signature_id.into(),
+            name: name.into(),
+            severity,
+            category: "firewall".into(),
+            message,
+            package: None,
+            ecosystem: None,
+            timestamp: None,
+        }
+        .with_package(&entry.package)
+        .with_timestamp(entry.timestamp)
+    }
+}
+
+/// The 0 to 10 severity scale shared by CEF and LEEF.
+pub fn severity(level: RiskLevel) -> u8 {
+    match level {
+        RiskLevel::Info => 1,
+        RiskLevel::Low => 3,
+        RiskLevel::Medium => 5,
+        RiskLevel::High => 8,
+        RiskLevel::Critical => 10,
+    }
+}
+
+/// The wire name of a risk domain, used as the event category.
+pub(crate) fn category(domain: RiskDomain) -> &'static str {
+    match domain {
+        RiskDomain::AuthorRisk => "author",
+        RiskDomain::EngineeringRisk => "engineering",
+        RiskDomain::Malicious => "malicious_code",
+        RiskDomain::Vulnerabilities => "vulnerability",
+        RiskDomain::LicenseRisk => "license",
+    }
+}
+
+fn escape_cef_header(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('|', "\\|")
+}
+
+fn escape_cef_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('=', "\\=")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+        .replace('\r', "\\r")
+}
+
+fn escape_leef_header(value: &str) -> String {
+    value.replace('|', " ")
+}
+
+fn escape_leef_value(value: &str) -> String {
+    value.replace(['\t', '\r', '\n'], " ")
+}
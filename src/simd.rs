@@ -0,0 +1,50 @@
+//! Parsing of large responses with simd-json.
+//!
+//! Job responses with many packages run to several megabytes, and parsing
+//! them with serde_json dominates the latency of the CLI. simd-json can parse
+//! the same JSON faster when built for a CPU with wide SIMD support, such as
+//! with `-C target-cpu=native`, at the cost of modifying the input buffer in
+//! place:
+//!
+//! ```
+//! use phylum_types::types::job::AllJobsStatusResponse;
+//!
+//! let mut body = br#"{"jobs": [], "total_jobs": 0, "count": 0}"#.to_vec();
+//! let response = AllJobsStatusResponse::from_slice(&mut body).unwrap();
+//! assert!(response.jobs.is_empty());
+//! ```
+//!
+//! The result is always the same as parsing with serde_json. Compare the two
+//! on the target machine with `cargo bench --bench parse --features simd-json`.
+
+use serde::de::DeserializeOwned;
+pub use simd_json::Error;
+
+use crate::types::job::{AllJobsStatusResponse, JobStatusResponse};
+use crate::types::package::Package;
+
+/// Deserialize any type from JSON, overwriting `bytes` while parsing.
+pub fn from_slice<T: DeserializeOwned>(bytes: &mut [u8]) -> Result<T, Error> {
+    simd_json::serde::from_slice(bytes)
+}
+
+impl<T: DeserializeOwned> JobStatusResponse<T> {
+    /// Parse a job response, overwriting `bytes` while parsing.
+    pub fn from_slice(bytes: &mut [u8]) -> Result<Self, Error> {
+        from_slice(bytes)
+    }
+}
+
+impl AllJobsStatusResponse {
+    /// Parse a job listing, overwriting `bytes` while parsing.
+    pub fn from_slice(bytes: &mut [u8]) -> Result<Self, Error> {
+        from_slice(bytes)
+    }
+}
+
+impl Package {
+    /// Parse a package response, overwriting `bytes` while parsing.
+    pub fn from_slice(bytes: &mut [u8]) -> Result<Self, Error> {
+        from_slice(bytes)
+    }
+}
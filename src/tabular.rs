@@ -0,0 +1,149 @@
+//! Flat rows of job results, for export to spreadsheets.
+//!
+//! A job flattens into one [`FlatPackageRow`] per package and one
+//! [`FlatIssueRow`] per issue of each package. Both serialize with
+//! `csv::Writer`, and [`write_packages`] and [`write_issues`] write a whole
+//! job including the header row:
+//!
+//! ```
+//! use phylum_types::tabular;
+//! # use phylum_types::types::job::JobStatusResponse;
+//! # use phylum_types::types::package::PackageStatusExtended;
+//! # let job: JobStatusResponse<PackageStatusExtended> =
+//! #     serde_json::from_str(include_str!("../tests/fixtures/job_status_extended.json")).unwrap();
+//!
+//! let mut csv = Vec::new();
+//! tabular::write_packages(&job, &mut csv).unwrap();
+//! let csv = String::from_utf8(csv).unwrap();
+//! assert!(csv.starts_with("job_id,project_name,label,ecosystem,name,version,"));
+//! ```
+
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::common::{JobId, Status};
+use crate::types::job::JobStatusResponse;
+use crate::types::package::{PackageStatusExtended, PackageType, RiskDomain, RiskLevel};
+
+/// Separates multiple values within a single cell.
+pub const LIST_SEPARATOR: &str = ";";
+
+/// A package of a job
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
+pub struct FlatPackageRow {
+    pub job_id: JobId,
+    pub project_name: String,
+    pub label: Option<String>,
+    pub ecosystem: PackageType,
+    pub name: String,
+    pub version: String,
+    pub status: Status,
+    pub license: Option<String>,
+    pub package_score: Option<f64>,
+    pub num_dependencies: u32,
+    pub num_vulnerabilities: Option<u32>,
+    /// Number of issues, including ignored issues
+    pub num_issues: usize,
+    /// Paths of the lockfiles the package was found in, separated by
+    /// [`LIST_SEPARATOR`]
+    pub lockfiles: String,
+}
+
+/// An issue of a package of a job
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize)]
+pub struct FlatIssueRow {
+    pub job_id: JobId,
+    pub project_name: String,
+    pub label: Option<String>,
+    pub ecosystem: PackageType,
+    pub name: String,
+    pub version: String,
+    pub issue_id: Option<String>,
+    pub tag: Option<String>,
+    pub title: String,
+    pub severity: RiskLevel,
+    pub domain: RiskDomain,
+    /// Why the issue is ignored, if it is
+    pub ignored: Option<String>,
+}
+
+/// One row per package of a job.
+pub fn package_rows(job: &JobStatusResponse<PackageStatusExtended>) -> Vec<FlatPackageRow> {
+    job.packages
+        .iter()
+        .map(|package| {
+            let status = &package.basic_status;
+            let lockfiles: Vec<_> = status
+                .lockfiles
+                .iter()
+                .map(|lockfile| lockfile.path.as_str())
+                .collect();
+            FlatPackageRow {
+                job_id: job.job_id,
+                project_name: job.project_name.clone(),
+                label: job.label.as_ref().map(ToString::to_string),
+                ecosystem: package.package_type,
+                name: status.name.clone(),
+                version: status.version.clone(),
+                status: status.status,
+                license: status.license.as_ref().map(ToString::to_string),
+                package_score: status.package_score,
+                num_dependencies: status.num_dependencies,
+                num_vulnerabilities: status.num_vulnerabilities,
+                num_issues: package.issues.len(),
+                lockfiles: lockfiles.join(LIST_SEPARATOR),
+            }
+        })
+        .collect()
+}
+
+/// One row per issue of each package of a job.
+pub fn issue_rows(job: &JobStatusResponse<PackageStatusExtended>) -> Vec<FlatIssueRow> {
+    job.packages
+        .iter()
+        .flat_map(|package| {
+            package.issues.iter().map(move |issue| FlatIssueRow {
+                job_id: job.job_id,
+                project_name: job.project_name.clone(),
+                label: job.label.as_ref().map(ToString::to_string),
+                ecosystem: package.package_type,
+                name: package.basic_status.name.clone(),
+                version: package.basic_status.version.clone(),
+                issue_id: issue.issue.id.clone(),
+                tag: issue.issue.tag.clone(),
+                title: issue.issue.title.clone(),
+                severity: issue.issue.severity,
+                domain: issue.issue.domain,
+                ignored: issue.ignored.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Write the packages of a job as CSV, with a header row unless there are no
+/// packages.
+pub fn write_packages<W: io::Write>(
+    job: &JobStatusResponse<PackageStatusExtended>,
+    writer: W,
+) -> csv::Result<()> {
+    write_rows(package_rows(job), writer)
+}
+
+/// Write the issues of a job as CSV, with a header row unless there are no
+/// issues.
+pub fn write_issues<W: io::Write>(
+    job: &JobStatusResponse<PackageStatusExtended>,
+    writer: W,
+) -> csv::Result<()> {
+    write_rows(issue_rows(job), writer)
+}
+
+fn write_rows<T: Serialize, W: io::Write>(rows: Vec<T>, writer: W) -> csv::Result<()> {
+    let mut writer = csv::Writer::from_writer(writer);
+    for row in rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
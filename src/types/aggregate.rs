@@ -0,0 +1,168 @@
+//! This module contains issues aggregated across packages, so that an issue
+//! such as a single CVE affecting many packages is reported once.
+//!
+//! Issues are the same if they have the same id, or failing that the same tag,
+//! or failing both the same title.
+//!
+//! ```
+//! use phylum_types::types::aggregate::IssueAggregator;
+//! use phylum_types::types::package::{Issue, PackageRef, PackageType, RiskDomain, RiskLevel};
+//!
+//! let issue = Issue::new(
+//!     "Command injection in lodash",
+//!     RiskLevel::High,
+//!     RiskDomain::Vulnerabilities,
+//! )
+//! .with_id("CVE-2021-23337")
+//! .with_description("`template` allows command injection.");
+//!
+//! let mut aggregator = IssueAggregator::default();
+//! aggregator.add(&issue, Some(&PackageRef::new("lodash", "4.17.20", PackageType::Npm)));
+//! aggregator.add(&issue, Some(&PackageRef::new("lodash", "4.17.15", PackageType::Npm)));
+//!
+//! let issues = aggregator.finish();
+//! assert_eq!(issues.len(), 1);
+//! assert_eq!(issues[0].packages.len(), 2);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::job::JobStatusResponse;
+use super::package::{Issue, PackageRef, PackageStatusExtended, Reference, RiskDomain, RiskLevel};
+
+/// An issue merged from all of its occurrences
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct AggregatedIssue {
+    pub id: Option<String>,
+    pub tag: Option<String>,
+    pub title: String,
+    pub domain: RiskDomain,
+    /// The highest severity of any occurrence
+    pub severity: RiskLevel,
+    /// Distinct descriptions, in the order they were first seen
+    pub descriptions: Vec<String>,
+    /// Distinct references of all occurrences
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    /// Distinct packages affected, when known
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<PackageRef>,
+    /// Number of times the issue was seen
+    pub occurrences: usize,
+}
+
+impl AggregatedIssue {
+    fn new(issue: &Issue) -> Self {
+        Self {
+            id: issue.id.clone(),
+            tag: issue.tag.clone(),
+            title: issue.title.clone(),
+            domain: issue.domain,
+            severity: issue.severity,
+            descriptions: Vec::new(),
+            references: Vec::new(),
+            packages: Vec::new(),
+            occurrences: 0,
+        }
+    }
+
+    fn merge(&mut self, issue: &Issue, package: Option<&PackageRef>) {
+        self.occurrences += 1;
+        self.severity = self.severity.max(issue.severity);
+        push_distinct(&mut self.descriptions, &issue.description);
+        for reference in &issue.references {
+            push_distinct(&mut self.references, reference);
+        }
+        if let Some(package) = package {
+            push_distinct(&mut self.packages, package);
+        }
+    }
+}
+
+fn push_distinct<T: PartialEq + Clone>(values: &mut Vec<T>, value: &T) {
+    if !values.contains(value) {
+        values.push(value.clone());
+    }
+}
+
+/// What makes two issues the same.
+#[derive(PartialEq, Eq, Hash, Clone, Debug)]
+enum IssueKey {
+    Id(String),
+    Tag(String),
+    Title(String),
+}
+
+impl IssueKey {
+    fn of(issue: &Issue) -> Self {
+        match (&issue.id, &issue.tag) {
+            (Some(id), _) => Self::Id(id.clone()),
+            (None, Some(tag)) => Self::Tag(tag.clone()),
+            (None, None) => Self::Title(issue.title.clone()),
+        }
+    }
+}
+
+/// Collects issues and the packages they affect
+#[derive(Clone, Debug, Default)]
+pub struct IssueAggregator {
+    issues: Vec<AggregatedIssue>,
+    index: HashMap<IssueKey, usize>,
+}
+
+impl IssueAggregator {
+    pub fn add(&mut self, issue: &Issue, package: Option<&PackageRef>) {
+        let issues = &mut self.issues;
+        let position = *self.index.entry(IssueKey::of(issue)).or_insert_with(|| {
+            issues.push(AggregatedIssue::new(issue));
+            issues.len() - 1
+        });
+        self.issues[position].merge(issue, package);
+    }
+
+    /// Add the issues of a package.
+    pub fn add_package(&mut self, status: &PackageStatusExtended) {
+        let package = PackageRef::new(
+            status.basic_status.name.clone(),
+            status.basic_status.version.clone(),
+            status.package_type,
+        );
+        for issue in &status.issues {
+            self.add(&issue.issue, Some(&package));
+        }
+    }
+
+    /// Add the issues of every package in a job.
+    pub fn add_job(&mut self, job: &JobStatusResponse<PackageStatusExtended>) {
+        for status in &job.packages {
+            self.add_package(status);
+        }
+    }
+
+    /// The aggregated issues, most severe first and otherwise in the order
+    /// they were first seen.
+    pub fn finish(self) -> Vec<AggregatedIssue> {
+        let mut issues = self.issues;
+        issues.sort_by_key(|issue| Reverse(issue.severity));
+        issues
+    }
+}
+
+/// Merge duplicate issues.
+///
+/// The packages of the result are empty, as issues alone don't record them;
+/// use an [`IssueAggregator`] to track the affected packages.
+pub fn dedupe_issues(issues: &[Issue]) -> Vec<AggregatedIssue> {
+    let mut aggregator = IssueAggregator::default();
+    for issue in issues {
+        aggregator.add(issue, None);
+    }
+    aggregator.finish()
+}
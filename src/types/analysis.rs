@@ -0,0 +1,175 @@
+//! This module contains the request and response of the analyze endpoint,
+//! which accepts dependencies already parsed from lockfiles and evaluates the
+//! project's policy against them.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{JobId, ProjectId};
+use super::job::SubmitOptions;
+use super::label::Label;
+use super::lockfile::{AnalysisPackageDescriptor, LockfileFormat, LockfilePath, ParsedLockfile};
+use super::package::{PackageRef, RiskDomain, RiskLevel};
+
+/// A lockfile whose packages are part of an analysis
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct LockfileMetadata {
+    /// Path to the lockfile, as given by the submitter
+    pub path: LockfilePath,
+    pub format: LockfileFormat,
+    /// Number of packages parsed from the lockfile
+    #[serde(alias = "numPackages")]
+    pub num_packages: u32,
+}
+
+impl From<&ParsedLockfile> for LockfileMetadata {
+    fn from(lockfile: &ParsedLockfile) -> Self {
+        Self {
+            path: lockfile.path.clone(),
+            format: lockfile.format,
+            num_packages: lockfile.packages.len() as u32,
+        }
+    }
+}
+
+/// Submit dependencies for analysis
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct AnalyzeRequest {
+    /// The dependencies to analyze
+    pub packages: Vec<AnalysisPackageDescriptor>,
+    /// The project the analysis belongs to
+    pub project: ProjectId,
+    /// The group that owns the project, if applicable
+    #[serde(skip_serializing_if = "Option::is_none", alias = "groupName")]
+    pub group_name: Option<String>,
+    /// A label for this analysis, most often a branch name
+    pub label: Label,
+    /// Was this submitted by a user interactively and not a CI?
+    #[serde(alias = "isUser")]
+    pub is_user: bool,
+    /// The lockfiles the packages were parsed from
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lockfiles: Vec<LockfileMetadata>,
+    /// Options controlling how the analysis is processed
+    #[serde(default, skip_serializing_if = "SubmitOptions::is_default")]
+    pub options: SubmitOptions,
+}
+
+impl AnalyzeRequest {
+    pub fn new(project: ProjectId, label: Label) -> Self {
+        Self {
+            packages: Vec::new(),
+            project,
+            group_name: None,
+            label,
+            is_user: false,
+            lockfiles: Vec::new(),
+            options: SubmitOptions::default(),
+        }
+    }
+
+    pub fn with_packages(mut self, packages: Vec<AnalysisPackageDescriptor>) -> Self {
+        self.packages = packages;
+        self
+    }
+
+    pub fn with_group_name(mut self, group_name: impl Into<String>) -> Self {
+        self.group_name = Some(group_name.into());
+        self
+    }
+
+    pub fn with_is_user(mut self, is_user: bool) -> Self {
+        self.is_user = is_user;
+        self
+    }
+
+    pub fn with_options(mut self, options: SubmitOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Add the packages of a lockfile, and record the lockfile itself.
+    pub fn add_lockfile(&mut self, lockfile: &ParsedLockfile) {
+        self.packages.extend(lockfile.analysis_packages());
+        self.lockfiles.push(lockfile.into());
+    }
+}
+
+/// Progress of an analysis
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AnalysisStatus {
+    /// Accepted, but not yet started
+    Pending,
+    Processing,
+    Complete,
+    Failed,
+}
+
+impl AnalysisStatus {
+    /// Whether the analysis will not change anymore.
+    pub fn is_finished(&self) -> bool {
+        matches!(self, AnalysisStatus::Complete | AnalysisStatus::Failed)
+    }
+}
+
+/// A package rejected by the project's policy
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct PolicyRejection {
+    pub package: PackageRef,
+    /// Why the package was rejected
+    pub message: String,
+    /// The domain of the issue causing the rejection, if any
+    pub domain: Option<RiskDomain>,
+    /// The severity of the issue causing the rejection, if any
+    pub severity: Option<RiskLevel>,
+}
+
+/// Outcome of evaluating the project's policy against an analysis
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AnalysisPolicyResult {
+    /// Whether the dependencies satisfy the policy
+    pub pass: bool,
+    /// Packages not yet analyzed, which the policy could not be evaluated for
+    #[serde(default, alias = "incompleteCount")]
+    pub incomplete_count: u32,
+    #[serde(default)]
+    pub rejections: Vec<PolicyRejection>,
+    /// Link to the full report of the analysis
+    #[serde(alias = "reportUrl")]
+    pub report_url: Option<String>,
+}
+
+impl AnalysisPolicyResult {
+    /// Whether the result may change once the remaining packages are analyzed.
+    pub fn is_incomplete(&self) -> bool {
+        self.incomplete_count > 0
+    }
+}
+
+/// Response of the analyze endpoint
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AnalyzeResponse {
+    /// The id of the job processing the analysis
+    #[serde(alias = "analysisId")]
+    pub analysis_id: JobId,
+    pub status: AnalysisStatus,
+    /// Present once the analysis is complete, if policy evaluation was
+    /// requested
+    pub policy: Option<AnalysisPolicyResult>,
+}
@@ -154,10 +154,13 @@ impl AsRef<str> for IdToken {
     PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Serialize, Deserialize, JsonSchema,
 )]
 pub struct TokenResponse {
+    #[serde(alias = "accessToken")]
     pub access_token: AccessToken,
+    #[serde(alias = "refreshToken")]
     pub refresh_token: RefreshToken,
+    #[serde(alias = "idToken")]
     pub id_token: IdToken,
-    #[serde(rename = "expires_in")]
+    #[serde(rename = "expires_in", alias = "expiresIn")]
     pub expires_in_seconds: u32,
 }
 
@@ -168,7 +171,8 @@ pub struct TokenResponse {
     PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Serialize, Deserialize, JsonSchema,
 )]
 pub struct AccessTokenResponse {
+    #[serde(alias = "accessToken")]
     pub access_token: AccessToken,
-    #[serde(rename = "expires_in")]
+    #[serde(rename = "expires_in", alias = "expiresIn")]
     pub expires_in_seconds: u32,
 }
@@ -0,0 +1,442 @@
+//! Borrowed variants of the most numerous payload types.
+//!
+//! Strings borrow from the input where possible, which avoids most
+//! allocations when processing large job responses. Strings containing
+//! escape sequences still have to be allocated. The generic response types
+//! accept these directly:
+//!
+//! ```
+//! use phylum_types::types::borrowed::PackageStatusRef;
+//! use phylum_types::types::job::JobStatusResponse;
+//!
+//! let json = include_str!("../../tests/fixtures/job_status_basic.json");
+//! let response: JobStatusResponse<PackageStatusRef> = serde_json::from_str(json).unwrap();
+//! let owned = response.map_packages(PackageStatusRef::into_owned);
+//! assert_eq!(owned.packages[0].name, "requests");
+//! ```
+
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "preserve-unknown")]
+use super::common::UnknownFields;
+use super::common::{ProjectId, Status};
+use super::job::{SubmitOptions, SubmitPackageRequest};
+use super::label::Label;
+use super::lockfile::LockfileRef;
+use super::metadata::Metadata;
+#[allow(deprecated)]
+use super::package::{
+    DependencyKind, ExploitMaturity, ExternalReference, Issue, IssueBody, IssueEvidence,
+    PackageDescriptor, PackageDescriptorAndLockfile, PackageRef, PackageRefAndLockfile,
+    PackageStatus, PackageType, Reference, RiskDomain, RiskLevel,
+};
+use super::remediation::RemediationInfo;
+
+/// Deserializers which borrow strings from the input where possible.
+///
+/// Serde only borrows a `Cow<str>` field directly; wrapped in an `Option`, it
+/// always allocates unless deserialized with [`option`].
+mod borrow_cow {
+    use std::borrow::Cow;
+    use std::fmt;
+
+    use serde::de::{self, Deserializer, Visitor};
+
+    struct CowStrVisitor;
+
+    impl<'de> Visitor<'de> for CowStrVisitor {
+        type Value = Cow<'de, str>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string")
+        }
+
+        fn visit_borrowed_str<E: de::Error>(self, value: &'de str) -> Result<Self::Value, E> {
+            Ok(Cow::Borrowed(value))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(value.to_owned()))
+        }
+
+        fn visit_string<E: de::Error>(self, value: String) -> Result<Self::Value, E> {
+            Ok(Cow::Owned(value))
+        }
+    }
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Cow<'de, str>>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a string or null")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_str(CowStrVisitor).map(Some)
+        }
+    }
+
+    pub fn option<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Cow<'de, str>>, D::Error> {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+}
+
+/// Borrowed [`PackageDescriptor`]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageDescriptorRef<'a> {
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub version: Cow<'a, str>,
+    #[serde(rename = "type", alias = "registry")]
+    pub package_type: PackageType,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_cow::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub namespace: Option<Cow<'a, str>>,
+}
+
+#[allow(deprecated)]
+impl PackageDescriptorRef<'_> {
+    pub fn into_owned(self) -> PackageDescriptor {
+        PackageDescriptor {
+            name: self.name.into_owned(),
+            version: self.version.into_owned(),
+            package_type: self.package_type,
+            namespace: self.namespace.map(Cow::into_owned),
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> From<&'a PackageDescriptor> for PackageDescriptorRef<'a> {
+    fn from(descriptor: &'a PackageDescriptor) -> Self {
+        Self {
+            name: Cow::Borrowed(&descriptor.name),
+            version: Cow::Borrowed(&descriptor.version),
+            package_type: descriptor.package_type,
+            namespace: descriptor.namespace.as_deref().map(Cow::Borrowed),
+        }
+    }
+}
+
+/// Borrowed [`PackageStatus`]
+///
+/// Lockfiles are usually few, so they are kept owned.
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct PackageStatusRef<'a> {
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_cow::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub purl: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub version: Cow<'a, str>,
+    pub status: Status,
+    #[serde(alias = "lastUpdated")]
+    pub last_updated: u64,
+    #[serde(borrow, default, deserialize_with = "borrow_cow::option")]
+    pub license: Option<Cow<'a, str>>,
+    #[serde(alias = "packageScore")]
+    pub package_score: Option<f64>,
+    #[serde(alias = "numDependencies")]
+    pub num_dependencies: u32,
+    #[serde(skip_serializing_if = "Option::is_none", alias = "numVulnerabilities")]
+    pub num_vulnerabilities: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lockfiles: Vec<LockfileRef>,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+impl PackageStatusRef<'_> {
+    pub fn into_owned(self) -> PackageStatus {
+        PackageStatus {
+            purl: self.purl.map(Cow::into_owned),
+            name: self.name.into_owned(),
+            version: self.version.into_owned(),
+            status: self.status,
+            last_updated: self.last_updated,
+            license: self.license.as_deref().map(Into::into),
+            package_score: self.package_score,
+            num_dependencies: self.num_dependencies,
+            num_vulnerabilities: self.num_vulnerabilities,
+            lockfiles: self.lockfiles,
+            #[cfg(feature = "preserve-unknown")]
+            extra: self.extra,
+        }
+    }
+}
+
+/// Borrowed [`Issue`]
+///
+/// References, evidence, external references and structured bodies are rare,
+/// so they are kept owned.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct IssueRef<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_cow::option")]
+    pub tag: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_cow::option")]
+    pub id: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub title: Cow<'a, str>,
+    #[serde(borrow)]
+    pub description: Cow<'a, str>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<IssueBody>>,
+    #[serde(alias = "risk_level")]
+    pub severity: RiskLevel,
+    #[serde(alias = "risk_domain")]
+    pub domain: RiskDomain,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "exploitMaturity"
+    )]
+    pub exploit_maturity: Option<ExploitMaturity>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<IssueEvidence>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "externalReferences"
+    )]
+    pub external_references: Vec<ExternalReference>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "remediationDetails"
+    )]
+    pub remediation_details: Option<Box<RemediationInfo>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "introducedBy")]
+    pub introduced_by: Vec<PackageRef>,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+impl IssueRef<'_> {
+    pub fn into_owned(self) -> Issue {
+        Issue {
+            tag: self.tag.map(Cow::into_owned),
+            id: self.id.map(Cow::into_owned),
+            title: self.title.into_owned(),
+            description: self.description.into_owned(),
+            body: self.body,
+            severity: self.severity,
+            domain: self.domain,
+            exploit_maturity: self.exploit_maturity,
+            rule: None,
+            evidence: self.evidence,
+            references: self.references,
+            external_references: self.external_references,
+            remediation_details: self.remediation_details,
+            introduced_by: self.introduced_by,
+            #[cfg(feature = "preserve-unknown")]
+            extra: self.extra,
+        }
+    }
+}
+
+/// Borrowed [`PackageDescriptorAndLockfile`]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageDescriptorAndLockfileRef<'a> {
+    #[serde(borrow, flatten)]
+    pub package_descriptor: PackageDescriptorRef<'a>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_cow::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lockfile: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "DependencyKind::is_runtime")]
+    pub kind: DependencyKind,
+}
+
+#[allow(deprecated)]
+impl PackageDescriptorAndLockfileRef<'_> {
+    pub fn into_owned(self) -> PackageDescriptorAndLockfile {
+        PackageDescriptorAndLockfile {
+            package_descriptor: self.package_descriptor.into_owned(),
+            lockfile: self.lockfile.map(|path| path.into_owned().into()),
+            kind: self.kind,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> From<&'a PackageDescriptorAndLockfile> for PackageDescriptorAndLockfileRef<'a> {
+    fn from(package: &'a PackageDescriptorAndLockfile) -> Self {
+        Self {
+            package_descriptor: (&package.package_descriptor).into(),
+            lockfile: package
+                .lockfile
+                .as_ref()
+                .map(|path| Cow::Borrowed(path.as_str())),
+            kind: package.kind,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl<'a> From<&'a PackageDescriptor> for PackageDescriptorAndLockfileRef<'a> {
+    fn from(descriptor: &'a PackageDescriptor) -> Self {
+        Self {
+            package_descriptor: descriptor.into(),
+            lockfile: None,
+            kind: DependencyKind::Runtime,
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a PackageRefAndLockfile> for PackageDescriptorAndLockfileRef<'a> {
+    type Error = String;
+
+    fn try_from(package: &'a PackageRefAndLockfile) -> Result<Self, Self::Error> {
+        let reference = &package.package;
+        let package_type = reference.package_type().ok_or_else(|| {
+            format!(
+                "Failed to convert registry {} to package type",
+                reference.ecosystem
+            )
+        })?;
+        Ok(Self {
+            package_descriptor: PackageDescriptorRef {
+                name: Cow::Borrowed(&reference.name),
+                version: Cow::Borrowed(&reference.version),
+                package_type,
+                namespace: reference.namespace.as_deref().map(Cow::Borrowed),
+            },
+            lockfile: package
+                .lockfile
+                .as_ref()
+                .map(|path| Cow::Borrowed(path.as_str())),
+            kind: package.kind,
+        })
+    }
+}
+
+/// Borrowed [`SubmitPackageRequest`], which serializes identically.
+///
+/// Lets submitters send packages they already hold without cloning them:
+///
+/// ```
+/// use phylum_types::types::borrowed::SubmitPackageRequestRef;
+/// use phylum_types::types::package::{PackageDescriptor, PackageType};
+///
+/// let descriptors = vec![PackageDescriptor::new("left-pad", "1.3.0", PackageType::Npm)];
+///
+/// let label = "main".parse().unwrap();
+/// let mut request = SubmitPackageRequestRef::new(Default::default(), &label);
+/// request.packages.extend(descriptors.iter().map(Into::into));
+/// let json = serde_json::to_string(&request).unwrap();
+/// # assert!(json.contains("left-pad"));
+/// ```
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SubmitPackageRequestRef<'a> {
+    #[serde(borrow)]
+    pub packages: Vec<PackageDescriptorAndLockfileRef<'a>>,
+    #[serde(alias = "isUser")]
+    pub is_user: bool,
+    pub project: ProjectId,
+    pub label: Cow<'a, Label>,
+    #[serde(
+        borrow,
+        default,
+        deserialize_with = "borrow_cow::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    #[serde(alias = "groupName")]
+    pub group_name: Option<Cow<'a, str>>,
+    #[serde(default, skip_serializing_if = "SubmitOptions::is_default")]
+    pub options: SubmitOptions,
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Cow<'a, Metadata>,
+}
+
+impl<'a> SubmitPackageRequestRef<'a> {
+    /// A request without packages, submitted by a CI.
+    pub fn new(project: ProjectId, label: &'a Label) -> Self {
+        Self {
+            packages: Vec::new(),
+            is_user: false,
+            project,
+            label: Cow::Borrowed(label),
+            group_name: None,
+            options: SubmitOptions::default(),
+            metadata: Cow::Owned(Metadata::default()),
+        }
+    }
+
+    pub fn into_owned(self) -> SubmitPackageRequest {
+        SubmitPackageRequest {
+            packages: self
+                .packages
+                .into_iter()
+                .map(PackageDescriptorAndLockfileRef::into_owned)
+                .collect(),
+            is_user: self.is_user,
+            project: self.project,
+            label: self.label.into_owned(),
+            group_name: self.group_name.map(Cow::into_owned),
+            options: self.options,
+            metadata: self.metadata.into_owned(),
+        }
+    }
+}
+
+impl<'a> From<&'a SubmitPackageRequest> for SubmitPackageRequestRef<'a> {
+    fn from(request: &'a SubmitPackageRequest) -> Self {
+        Self {
+            packages: request.packages.iter().map(Into::into).collect(),
+            is_user: request.is_user,
+            project: request.project,
+            label: Cow::Borrowed(&request.label),
+            group_name: request.group_name.as_deref().map(Cow::Borrowed),
+            options: request.options,
+            metadata: Cow::Borrowed(&request.metadata),
+        }
+    }
+}
@@ -1,19 +1,545 @@
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+use std::hash::{Hash, Hasher};
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+use std::ops::{Deref, DerefMut};
+
+use std::convert::TryFrom;
+use std::fmt;
+
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, Expected, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use uuid::Uuid;
 
+// Ids deserialize from any UUID form, including the hyphenless one some older
+// endpoints return, and always serialize hyphenated
 pub type ProjectId = Uuid;
 pub type JobId = Uuid;
 pub type UserId = Uuid;
+pub type ReportId = Uuid;
 pub type Key = Uuid;
 pub type PackageId = String;
 
 /// Did the processing of the Package or Job complete successfully
-#[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
-)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     Complete,
     Incomplete,
 }
+
+impl Status {
+    const VARIANTS: &'static [(&'static str, Self)] = &[
+        ("complete", Self::Complete),
+        ("incomplete", Self::Incomplete),
+    ];
+
+    /// Whether a job or package may move from this status to `next`.
+    ///
+    /// Processing only moves forward, so a complete job stays complete.
+    pub fn can_transition_to(&self, next: Status) -> bool {
+        matches!((self, next), (Status::Incomplete, Status::Complete))
+    }
+}
+
+impl<'de> Deserialize<'de> for Status {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_variant(deserializer, "Status", Self::VARIANTS)
+    }
+}
+
+/// Deserialize a fieldless enum by matching its wire name, one of `variants`,
+/// against the borrowed string.
+///
+/// This avoids the temporary strings derived implementations may allocate for
+/// enums nested in flattened or untagged types, which large responses hold
+/// many of.
+///
+/// Formats which are not self-describing, such as bincode, write the index of
+/// the variant instead, so `variants` must list every variant in declaration
+/// order. Aliases may appear anywhere after the variant they belong to.
+pub(crate) fn deserialize_variant<'de, D, T>(
+    deserializer: D,
+    name: &'static str,
+    variants: &'static [(&'static str, T)],
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Copy + PartialEq + 'static,
+{
+    struct VariantVisitor<T: 'static>(&'static [(&'static str, T)]);
+
+    impl<T: Copy + PartialEq> VariantVisitor<T> {
+        /// The variant declared at `index`, skipping aliases, which are the
+        /// entries whose variant was already listed.
+        fn nth(&self, index: u64) -> Option<T> {
+            let mut distinct = self.0.iter().enumerate().filter_map(|(i, (_, variant))| {
+                let is_alias = self.0[..i].iter().any(|(_, other)| other == variant);
+                (!is_alias).then_some(*variant)
+            });
+            distinct.nth(usize::try_from(index).ok()?)
+        }
+    }
+
+    impl<T: Copy + PartialEq> Visitor<'_> for VariantVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("one of ")?;
+            for (i, (name, _)) in self.0.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "`{name}`")?;
+            }
+            Ok(())
+        }
+
+        fn visit_u64<E: de::Error>(self, index: u64) -> Result<T, E> {
+            self.nth(index)
+                .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(index), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            match self.0.iter().find(|(name, _)| *name == value) {
+                Some((_, variant)) => Ok(*variant),
+                None => Err(E::custom(format_args!(
+                    "unknown variant `{value}`, expected {}",
+                    &self as &dyn Expected
+                ))),
+            }
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<T, E> {
+            match std::str::from_utf8(value) {
+                Ok(value) => self.visit_str(value),
+                Err(_) => Err(E::invalid_value(Unexpected::Bytes(value), &self)),
+            }
+        }
+    }
+
+    impl<'de, T: Copy + PartialEq> de::DeserializeSeed<'de> for VariantVisitor<T> {
+        type Value = T;
+
+        fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    /// Unit variants arrive as an enum, whose variant is the identifier.
+    struct EnumVisitor<T: 'static>(&'static [(&'static str, T)]);
+
+    impl<'de, T: Copy + PartialEq> Visitor<'de> for EnumVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            VariantVisitor(self.0).expecting(f)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            VariantVisitor(self.0).visit_str(value)
+        }
+
+        fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<T, A::Error> {
+            let (variant, access) = data.variant_seed(VariantVisitor(self.0))?;
+            de::VariantAccess::unit_variant(access)?;
+            Ok(variant)
+        }
+    }
+
+    // The variant names are only a hint, which none of the formats this crate
+    // is used with needs.
+    deserializer.deserialize_enum(name, &[], EnumVisitor(variants))
+}
+
+/// A valid change from one status to another
+///
+/// Deserializing checks the transition like [`new`](Self::new) does.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, JsonSchema)]
+pub struct StatusTransition {
+    from: Status,
+    to: Status,
+}
+
+/// The fields of a [`StatusTransition`], before the transition is checked.
+#[derive(Deserialize)]
+#[serde(rename = "StatusTransition")]
+struct StatusTransitionFields {
+    from: Status,
+    to: Status,
+}
+
+impl<'de> Deserialize<'de> for StatusTransition {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let StatusTransitionFields { from, to } =
+            StatusTransitionFields::deserialize(deserializer)?;
+        Self::new(from, to).ok_or_else(|| {
+            de::Error::custom(format_args!("status cannot change from {from:?} to {to:?}"))
+        })
+    }
+}
+
+impl StatusTransition {
+    /// Create a transition, if it is allowed.
+    pub fn new(from: Status, to: Status) -> Option<Self> {
+        from.can_transition_to(to).then_some(Self { from, to })
+    }
+
+    pub fn from(&self) -> Status {
+        self.from
+    }
+
+    pub fn to(&self) -> Status {
+        self.to
+    }
+}
+
+/// Kind of error returned by the API
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    RateLimited,
+    Internal,
+    Unavailable,
+    /// A code not known to this version of the crate
+    #[serde(other)]
+    Unknown,
+}
+
+impl ErrorCode {
+    /// The code matching an HTTP status, for errors without a body.
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            400 | 422 => Self::BadRequest,
+            401 => Self::Unauthorized,
+            403 => Self::Forbidden,
+            404 => Self::NotFound,
+            409 => Self::Conflict,
+            429 => Self::RateLimited,
+            503 => Self::Unavailable,
+            500..=599 => Self::Internal,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// Whether the same request may succeed when retried later.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::RateLimited | Self::Unavailable)
+    }
+}
+
+/// An error returned by the API
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    /// Human readable description of the error
+    pub description: String,
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}: {}", self.code, self.description)
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+/// Body of a failed API request
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ApiErrorResponse {
+    pub error: ApiError,
+}
+
+/// Body of any API request, which is either the expected response or an
+/// error.
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum ApiResponse<T> {
+    // Errors go first, as some responses consist only of optional fields and
+    // would accept an error body too
+    Err(ApiErrorResponse),
+    Ok(T),
+}
+
+impl<T> ApiResponse<T> {
+    pub fn into_result(self) -> Result<T, ApiError> {
+        match self {
+            ApiResponse::Ok(value) => Ok(value),
+            ApiResponse::Err(response) => Err(response.error),
+        }
+    }
+}
+
+impl<T> From<ApiResponse<T>> for Result<T, ApiError> {
+    fn from(response: ApiResponse<T>) -> Self {
+        response.into_result()
+    }
+}
+
+/// Opaque position in a listing, returned with one page to fetch the next
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(transparent)]
+pub struct PageCursor(pub String);
+
+impl fmt::Display for PageCursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Query parameters of a cursor paginated listing
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PageParams {
+    /// Where to continue from; the first page when unset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<PageCursor>,
+    /// Maximum number of values to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+}
+
+/// One page of a cursor paginated listing
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Paginated<T> {
+    pub values: Vec<T>,
+    /// The cursor of the following page; absent on the last page
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "nextCursor")]
+    pub next_cursor: Option<PageCursor>,
+    /// Number of values in the whole listing, if the server counts them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+}
+
+impl<T> Paginated<T> {
+    pub fn has_more(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+/// A field which can be absent, `null`, or set.
+///
+/// Needed where `null` and a missing field mean different things, such as a
+/// PATCH body where `null` clears a value and a missing field leaves it
+/// unchanged. Fields must be marked `#[serde(default, skip_serializing_if =
+/// "Patch::is_absent")]`:
+///
+/// ```
+/// use phylum_types::types::common::Patch;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct UpdateJob {
+///     #[serde(default, skip_serializing_if = "Patch::is_absent")]
+///     label: Patch<String>,
+/// }
+///
+/// let clear: UpdateJob = serde_json::from_str(r#"{"label": null}"#).unwrap();
+/// assert_eq!(clear.label, Patch::Null);
+/// let keep: UpdateJob = serde_json::from_str("{}").unwrap();
+/// assert_eq!(keep.label, Patch::Absent);
+///
+/// let mut label = Some("main".to_string());
+/// clear.label.apply_to(&mut label);
+/// assert_eq!(label, None);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+pub enum Patch<T> {
+    /// The field was missing; leave the value unchanged
+    #[default]
+    Absent,
+    /// The field was `null`; clear the value
+    Null,
+    Value(T),
+}
+
+impl<T> Patch<T> {
+    pub fn is_absent(&self) -> bool {
+        matches!(self, Patch::Absent)
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Patch::Null)
+    }
+
+    pub fn as_ref(&self) -> Patch<&T> {
+        match self {
+            Patch::Absent => Patch::Absent,
+            Patch::Null => Patch::Null,
+            Patch::Value(value) => Patch::Value(value),
+        }
+    }
+
+    /// The patch as nested options: `None` when absent, `Some(None)` when
+    /// null.
+    pub fn into_option(self) -> Option<Option<T>> {
+        match self {
+            Patch::Absent => None,
+            Patch::Null => Some(None),
+            Patch::Value(value) => Some(Some(value)),
+        }
+    }
+
+    /// Apply the patch to an optional value.
+    pub fn apply_to(self, target: &mut Option<T>) {
+        if let Some(value) = self.into_option() {
+            *target = value;
+        }
+    }
+}
+
+impl<T> From<T> for Patch<T> {
+    fn from(value: T) -> Self {
+        Patch::Value(value)
+    }
+}
+
+impl<T> From<Option<Option<T>>> for Patch<T> {
+    fn from(value: Option<Option<T>>) -> Self {
+        match value {
+            None => Patch::Absent,
+            Some(None) => Patch::Null,
+            Some(Some(value)) => Patch::Value(value),
+        }
+    }
+}
+
+// Absent fields are handled by `#[serde(default)]`, so a present field is
+// either `null` or a value.
+impl<T: Serialize> Serialize for Patch<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Patch::Absent | Patch::Null => serializer.serialize_none(),
+            Patch::Value(value) => serializer.serialize_some(value),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Patch<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::deserialize(deserializer)? {
+            Some(value) => Patch::Value(value),
+            None => Patch::Null,
+        })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Patch<T> {
+    fn schema_name() -> String {
+        Option::<T>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Option::<T>::json_schema(gen)
+    }
+
+    fn is_referenceable() -> bool {
+        Option::<T>::is_referenceable()
+    }
+}
+
+/// Fields of a payload which are not known to this version of the crate.
+///
+/// Captured with `#[serde(flatten)]` so that round-tripping a payload
+/// through these types does not drop data added to the API later. Strict
+/// builds capture them only in types which flatten another, to reject them;
+/// see [`reject_unknown`].
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UnknownFields(pub BTreeMap<String, serde_json::Value>);
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl Deref for UnknownFields {
+    type Target = BTreeMap<String, serde_json::Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl DerefMut for UnknownFields {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Deserializes the fields left over by the flattened parts of a type,
+/// failing if there are any.
+///
+/// `deny_unknown_fields` has no effect on a flattened struct, which only picks
+/// its own fields out of the payload, so the type flattening it captures the
+/// rest and rejects them here.
+#[cfg(deny_unknown_fields)]
+pub(crate) fn reject_unknown<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<UnknownFields, D::Error> {
+    let fields = BTreeMap::<String, de::IgnoredAny>::deserialize(deserializer)?;
+    match fields.keys().next() {
+        Some(field) => Err(de::Error::custom(format_args!("unknown field `{field}`"))),
+        None => Ok(UnknownFields::default()),
+    }
+}
+
+/// The entries of a JSON object with their values serialized, for comparing
+/// and hashing.
+///
+/// JSON values have no ordering or hash of their own, so their serialized form
+/// is used; this keeps the derives of types containing such objects working.
+pub(crate) fn canonical_json(
+    map: &BTreeMap<String, serde_json::Value>,
+) -> impl Iterator<Item = (&String, String)> {
+    map.iter().map(|(key, value)| (key, value.to_string()))
+}
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl PartialOrd for UnknownFields {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl Ord for UnknownFields {
+    fn cmp(&self, other: &Self) -> Ordering {
+        canonical_json(&self.0).cmp(canonical_json(&other.0))
+    }
+}
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl Hash for UnknownFields {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for entry in canonical_json(&self.0) {
+            entry.hash(state);
+        }
+    }
+}
+
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+impl JsonSchema for UnknownFields {
+    fn schema_name() -> String {
+        "UnknownFields".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        BTreeMap::<String, serde_json::Value>::json_schema(gen)
+    }
+}
@@ -0,0 +1,110 @@
+//! This module contains types describing how far Phylum has ingested the
+//! upstream feeds of each registry, as reported by the status API.
+//!
+//! Packages published after a feed's last ingested event are not yet known,
+//! so the lag of a feed bounds how fresh the package data of its ecosystem is.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::package::{Ecosystem, PackageType};
+use super::timestamp::{Duration, Timestamp};
+
+/// Ingestion state of a feed
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedStatus {
+    #[default]
+    Current,
+    /// Ingesting, but behind the registry
+    Lagging,
+    /// Not making progress, such as when the registry is unreachable
+    Stalled,
+    Disabled,
+}
+
+/// Ingestion cursor and lag of a single registry feed
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct FeedState {
+    pub ecosystem: Ecosystem,
+    pub status: FeedStatus,
+    /// Last sequence ingested, in the registry's own format, such as a
+    /// change sequence number or an index commit
+    #[serde(alias = "lastSequence")]
+    pub last_sequence: Option<String>,
+    /// When the last ingested event was published by the registry
+    #[serde(alias = "lastEventAt")]
+    pub last_event_at: Option<Timestamp>,
+    /// When the feed was last polled
+    #[serde(alias = "lastPolledAt")]
+    pub last_polled_at: Option<Timestamp>,
+    /// Seconds between the registry's latest event and the last ingested one
+    #[serde(alias = "lagSeconds")]
+    pub lag_seconds: Option<u64>,
+    /// Events known to be waiting for ingestion
+    #[serde(alias = "pendingEvents")]
+    pub pending_events: Option<u64>,
+}
+
+impl FeedState {
+    pub fn package_type(&self) -> Option<PackageType> {
+        self.ecosystem.package_type()
+    }
+
+    /// Reported lag of the feed, if known.
+    ///
+    /// Lags too large for a [`Duration`] are capped.
+    pub fn lag(&self) -> Option<Duration> {
+        const MAX_SECONDS: u64 = i64::MAX as u64 / 1000;
+        self.lag_seconds
+            .map(|seconds| Duration::seconds(seconds.min(MAX_SECONDS) as i64))
+    }
+
+    /// Age of the last ingested event at `now`.
+    pub fn staleness(&self, now: Timestamp) -> Option<Duration> {
+        self.last_event_at.map(|last_event_at| now - last_event_at)
+    }
+}
+
+/// Response of the feed status endpoint
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct FeedStatusResponse {
+    pub feeds: Vec<FeedState>,
+}
+
+impl FeedStatusResponse {
+    /// The feed of an ecosystem, if reported.
+    pub fn feed(&self, package_type: PackageType) -> Option<&FeedState> {
+        self.feeds
+            .iter()
+            .find(|feed| feed.package_type() == Some(package_type))
+    }
+
+    /// The largest reported lag across all enabled feeds.
+    pub fn max_lag(&self) -> Option<Duration> {
+        self.feeds
+            .iter()
+            .filter(|feed| feed.status != FeedStatus::Disabled)
+            .filter_map(FeedState::lag)
+            .max()
+    }
+}
@@ -0,0 +1,137 @@
+//! This module contains types shared by the package firewall, a proxy in
+//! front of package registries which blocks risky packages, and its clients.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::UserId;
+use super::package::{Issue, PackageRef};
+use super::preferences::{IgnoredIssue, RiskThresholds};
+use super::timestamp::Timestamp;
+
+/// What the firewall does with a request for a package
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum FirewallAction {
+    /// Serve the package from the upstream registry
+    #[default]
+    Allow,
+    /// Refuse to serve the package
+    Deny,
+    /// Hold the package back until it has been analyzed or has aged enough
+    Quarantine,
+}
+
+/// The firewall's verdict for a single package
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct FirewallDecision {
+    pub package: PackageRef,
+    pub action: FirewallAction,
+    /// Issues which led to the package being blocked
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issues: Vec<Issue>,
+    /// Other reasons for the action, such as the package being too new
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reasons: Vec<String>,
+    /// Whether the package was still being analyzed when the decision was
+    /// made
+    #[serde(default)]
+    pub incomplete: bool,
+}
+
+impl FirewallDecision {
+    pub fn is_allowed(&self) -> bool {
+        self.action == FirewallAction::Allow
+    }
+}
+
+/// A package held back by the firewall
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct QuarantinedPackage {
+    pub package: PackageRef,
+    #[serde(alias = "quarantinedAt")]
+    pub quarantined_at: Timestamp,
+    /// When the package will be released automatically, if ever
+    #[serde(alias = "releaseAt")]
+    pub release_at: Option<Timestamp>,
+    pub reason: String,
+}
+
+impl QuarantinedPackage {
+    /// Whether the package has been released by `now`.
+    pub fn is_released_at(&self, now: Timestamp) -> bool {
+        self.release_at.is_some_and(|release_at| release_at <= now)
+    }
+}
+
+/// A single package request handled by the registry proxy
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct FirewallLogEntry {
+    pub timestamp: Timestamp,
+    pub package: PackageRef,
+    pub action: FirewallAction,
+    /// The upstream registry the request was proxied to
+    pub registry: Option<String>,
+    /// Address or name of the client making the request
+    pub client: Option<String>,
+    /// The user whose credentials were used, if any
+    #[serde(alias = "userId")]
+    pub user_id: Option<UserId>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub reasons: Vec<String>,
+}
+
+/// Rules the firewall applies to package requests
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
+#[serde(default)]
+pub struct FirewallPolicy {
+    pub name: String,
+    /// Action for packages which don't fail any check
+    #[serde(alias = "defaultAction")]
+    pub default_action: FirewallAction,
+    /// Action for packages which fail an active threshold
+    #[serde(alias = "failureAction")]
+    pub failure_action: FirewallAction,
+    /// Quarantine packages published less than this many days ago
+    #[serde(alias = "quarantineDays")]
+    pub quarantine_days: Option<u32>,
+    pub thresholds: RiskThresholds,
+    /// Issues which never block a package
+    #[serde(alias = "ignoredIssues")]
+    pub ignored_issues: Vec<IgnoredIssue>,
+}
+
+impl Default for FirewallPolicy {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            default_action: FirewallAction::Allow,
+            failure_action: FirewallAction::Deny,
+            quarantine_days: None,
+            thresholds: RiskThresholds::default(),
+            ignored_issues: Vec::new(),
+        }
+    }
+}
@@ -1,19 +1,36 @@
-use chrono::{DateTime, Utc};
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
+
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::timestamp::Timestamp;
 
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 pub struct CreateGroupRequest {
+    #[serde(alias = "groupName")]
     pub group_name: String,
 }
 
+impl CreateGroupRequest {
+    pub fn new(group_name: impl Into<String>) -> Self {
+        Self {
+            group_name: group_name.into(),
+        }
+    }
+}
+
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 pub struct CreateGroupResponse {
+    #[serde(alias = "groupName")]
     pub group_name: String,
+    #[serde(alias = "ownerEmail")]
     pub owner_email: String,
 }
 
@@ -21,24 +38,37 @@ pub struct CreateGroupResponse {
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 pub struct KickUserFromGroupRequest {
+    #[serde(alias = "userEmail")]
     pub user_email: String,
 }
 
+impl KickUserFromGroupRequest {
+    pub fn new(user_email: impl Into<String>) -> Self {
+        Self {
+            user_email: user_email.into(),
+        }
+    }
+}
+
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 pub struct UserGroup {
-    pub created_at: DateTime<Utc>,
-    pub last_modified: DateTime<Utc>,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    #[serde(alias = "lastModified")]
+    pub last_modified: Timestamp,
+    #[serde(alias = "ownerEmail")]
     pub owner_email: String,
+    #[serde(alias = "groupName")]
     pub group_name: String,
 
     //only present in the relatively uncommon case that the user is an admin
-    #[serde(default)]
+    #[serde(default, alias = "isAdmin")]
     pub is_admin: bool,
 
     //only present in the relatively uncommon case that the user is the owner
-    #[serde(default)]
+    #[serde(default, alias = "isOwner")]
     pub is_owner: bool,
 }
 
@@ -53,8 +83,11 @@ pub struct ListUserGroupsResponse {
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 pub struct GroupMember {
+    #[serde(alias = "userEmail")]
     pub user_email: String,
+    #[serde(alias = "firstName")]
     pub first_name: String,
+    #[serde(alias = "lastName")]
     pub last_name: String,
 }
 
@@ -64,3 +97,223 @@ pub struct GroupMember {
 pub struct ListGroupMembersResponse {
     pub members: Vec<GroupMember>,
 }
+
+/// An action a group member may be allowed to take
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    ViewProjects = 1 << 0,
+    SubmitJobs = 1 << 1,
+    ManageProjects = 1 << 2,
+    ManagePreferences = 1 << 3,
+    ManageMembers = 1 << 4,
+    ManageGroup = 1 << 5,
+}
+
+impl Permission {
+    pub const ALL: [Permission; 6] = [
+        Permission::ViewProjects,
+        Permission::SubmitJobs,
+        Permission::ManageProjects,
+        Permission::ManagePreferences,
+        Permission::ManageMembers,
+        Permission::ManageGroup,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Permission::ViewProjects => "view_projects",
+            Permission::SubmitJobs => "submit_jobs",
+            Permission::ManageProjects => "manage_projects",
+            Permission::ManagePreferences => "manage_preferences",
+            Permission::ManageMembers => "manage_members",
+            Permission::ManageGroup => "manage_group",
+        }
+    }
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Permission {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Permission::ALL
+            .iter()
+            .copied()
+            .find(|permission| permission.name() == s)
+            .ok_or(())
+    }
+}
+
+/// A set of permissions.
+///
+/// Serialized as an array of permission names. Names not known to this
+/// version of the crate are dropped when deserializing.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default)]
+pub struct Permissions(u32);
+
+impl Permissions {
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    pub fn all() -> Self {
+        Permission::ALL.iter().copied().collect()
+    }
+
+    pub fn contains(self, permission: Permission) -> bool {
+        self.0 & permission as u32 != 0
+    }
+
+    pub fn insert(&mut self, permission: Permission) {
+        self.0 |= permission as u32;
+    }
+
+    pub fn remove(&mut self, permission: Permission) {
+        self.0 &= !(permission as u32);
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The permissions in the set, in declaration order.
+    pub fn iter(self) -> impl Iterator<Item = Permission> {
+        Permission::ALL
+            .iter()
+            .copied()
+            .filter(move |permission| self.contains(*permission))
+    }
+}
+
+impl From<Permission> for Permissions {
+    fn from(permission: Permission) -> Self {
+        Self(permission as u32)
+    }
+}
+
+impl FromIterator<Permission> for Permissions {
+    fn from_iter<I: IntoIterator<Item = Permission>>(iter: I) -> Self {
+        let mut permissions = Self::empty();
+        for permission in iter {
+            permissions.insert(permission);
+        }
+        permissions
+    }
+}
+
+impl BitOr for Permissions {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+impl BitOr<Permission> for Permissions {
+    type Output = Self;
+
+    fn bitor(self, permission: Permission) -> Self {
+        self | Self::from(permission)
+    }
+}
+
+impl BitOr for Permission {
+    type Output = Permissions;
+
+    fn bitor(self, other: Self) -> Permissions {
+        Permissions::from(self) | other
+    }
+}
+
+impl BitOrAssign<Permission> for Permissions {
+    fn bitor_assign(&mut self, permission: Permission) {
+        self.insert(permission);
+    }
+}
+
+impl Serialize for Permissions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de> Deserialize<'de> for Permissions {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let names = Vec::<String>::deserialize(deserializer)?;
+        Ok(names.iter().filter_map(|name| name.parse().ok()).collect())
+    }
+}
+
+impl JsonSchema for Permissions {
+    fn schema_name() -> String {
+        "Permissions".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Vec::<Permission>::json_schema(gen)
+    }
+}
+
+/// The role of a user within a group
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    Owner,
+    Admin,
+    Member,
+    /// Read-only access
+    Observer,
+}
+
+impl Role {
+    /// The permissions a role grants unless the group customizes them.
+    pub fn default_permissions(self) -> Permissions {
+        match self {
+            Role::Owner => Permissions::all(),
+            Role::Admin => {
+                let mut permissions = Permissions::all();
+                permissions.remove(Permission::ManageGroup);
+                permissions
+            }
+            Role::Member => Permission::ViewProjects | Permission::SubmitJobs,
+            Role::Observer => Permission::ViewProjects.into(),
+        }
+    }
+}
+
+/// The role of a user in a group, and what it allows them to do
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct RoleAssignment {
+    #[serde(alias = "userEmail")]
+    pub user_email: String,
+    #[serde(alias = "groupName")]
+    pub group_name: String,
+    pub role: Role,
+    /// The permissions in effect, including any customization by the group
+    pub permissions: Permissions,
+}
+
+impl RoleAssignment {
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.permissions.contains(permission)
+    }
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ListRoleAssignmentsResponse {
+    pub assignments: Vec<RoleAssignment>,
+}
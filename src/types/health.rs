@@ -0,0 +1,85 @@
+//! This module contains types for the service health and version endpoints.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::timestamp::Timestamp;
+
+/// State of the service or one of its components
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    #[default]
+    Healthy,
+    /// Working, but slower or with reduced functionality
+    Degraded,
+    Unhealthy,
+}
+
+/// Health of a single dependency of the service, such as a database
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    /// Response time of the last check, in milliseconds
+    #[serde(alias = "latencyMs")]
+    pub latency_ms: Option<u64>,
+    /// Why the component is not healthy
+    pub msg: Option<String>,
+}
+
+/// Response of the health endpoint
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    #[serde(default)]
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthResponse {
+    pub fn is_healthy(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+
+    /// Components which are degraded or unhealthy.
+    pub fn failing_components(&self) -> impl Iterator<Item = &ComponentHealth> {
+        self.components
+            .iter()
+            .filter(|component| component.status != HealthStatus::Healthy)
+    }
+}
+
+/// Response of the version endpoint
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct VersionResponse {
+    /// Version of the API, such as `v0`
+    #[serde(alias = "apiVersion")]
+    pub api_version: String,
+    /// Commit the service was built from
+    #[serde(alias = "gitSha")]
+    pub git_sha: String,
+    #[serde(alias = "buildDate")]
+    pub build_date: Timestamp,
+}
@@ -1,28 +1,92 @@
 //! This module contains types involved with handling phylum processing jobs.
+// Job submissions still carry the deprecated v1 package descriptors, and
+// the `JsonSchema` derive does not pass an item-level allow on to its impl
+#![allow(deprecated)]
 
+use std::collections::{BTreeMap, BTreeSet};
+use std::convert::Infallible;
+use std::fmt;
+
+use indexmap::IndexMap;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
 
+use super::aggregate::{AggregatedIssue, IssueAggregator};
 use super::common::*;
-use crate::types::package::{PackageDescriptorAndLockfile, PackageStatus, PackageStatusExtended};
+use super::label::Label;
+use super::lenient;
+use super::lockfile::{LockfilePath, LockfileRef};
+use super::metadata::Metadata;
+use super::timestamp::{self, Timestamp};
+use crate::types::package::{
+    DependencyKind, Ecosystem, IssueStatus, PackageDescriptorAndLockfile, PackageStatus,
+    PackageStatusExtended, PackageType,
+};
 
 /// Metadata about a job
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct JobDescriptor {
+    #[serde(alias = "jobId")]
     pub job_id: JobId,
-    pub project: String,
-    pub label: String,
+    pub project: ProjectId,
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
+    #[serde(alias = "numDependencies")]
     pub num_dependencies: u32,
     pub packages: Vec<PackageDescriptorAndLockfile>,
     pub pass: bool,
     pub msg: String,
-    pub date: String,
-    #[serde(default)]
-    pub ecosystems: Vec<String>,
+    /// When the job was submitted; see [`lenient::timestamp`] for the
+    /// accepted forms
+    #[serde(deserialize_with = "lenient::timestamp")]
+    pub date: Timestamp,
     #[serde(default)]
+    pub ecosystems: Vec<Ecosystem>,
+    #[serde(default, alias = "numIncomplete")]
     pub num_incomplete: u32,
 }
 
+impl JobDescriptor {
+    /// The package types of the job's ecosystems, skipping those not known to
+    /// this version of the crate.
+    pub fn package_types(&self) -> impl Iterator<Item = PackageType> + '_ {
+        self.ecosystems.iter().filter_map(Ecosystem::package_type)
+    }
+
+    /// The packages depended upon in the given way.
+    pub fn packages_of_kind(
+        &self,
+        kind: DependencyKind,
+    ) -> impl Iterator<Item = &PackageDescriptorAndLockfile> {
+        self.packages
+            .iter()
+            .filter(move |package| package.kind == kind)
+    }
+
+    /// A one line summary, such as
+    /// `<project id>@main (job <id>) [pass, 12 dependencies, 2 incomplete]`.
+    pub fn summary(&self) -> String {
+        let outcome = if self.pass { "pass" } else { "fail" };
+        let mut summary = format!("{self} [{outcome}, {} dependencies", self.num_dependencies);
+        if self.num_incomplete > 0 {
+            summary.push_str(&format!(", {} incomplete", self.num_incomplete));
+        }
+        summary.push(']');
+        summary
+    }
+}
+
+impl fmt::Display for JobDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.label {
+            Some(label) => write!(f, "{}@{label} (job {})", self.project, self.job_id),
+            None => write!(f, "{} (job {})", self.project, self.job_id),
+        }
+    }
+}
+
 /// Submit Package for analysis
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
@@ -31,80 +95,953 @@ pub struct SubmitPackageRequest {
     /// The subpackage dependencies of this package
     pub packages: Vec<PackageDescriptorAndLockfile>,
     /// Was this submitted by a user interactively and not a CI?
+    #[serde(alias = "isUser")]
     pub is_user: bool,
     /// The id of the project this top level package should be associated with
     pub project: ProjectId,
     /// A label for this package. Often it's the branch.
-    pub label: String,
+    pub label: Label,
     /// The group that owns the project, if applicable
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "groupName")]
     pub group_name: Option<String>,
+    /// Options controlling how the job is processed
+    #[serde(default, skip_serializing_if = "SubmitOptions::is_default")]
+    pub options: SubmitOptions,
+    /// Data attached by integrators
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+}
+
+impl SubmitPackageRequest {
+    /// A request without any packages, submitted by CI.
+    pub fn new(project: ProjectId, label: Label) -> Self {
+        Self {
+            packages: Vec::new(),
+            is_user: false,
+            project,
+            label,
+            group_name: None,
+            options: SubmitOptions::default(),
+            metadata: Metadata::default(),
+        }
+    }
+
+    pub fn with_packages(mut self, packages: Vec<PackageDescriptorAndLockfile>) -> Self {
+        self.packages = packages;
+        self
+    }
+
+    pub fn with_is_user(mut self, is_user: bool) -> Self {
+        self.is_user = is_user;
+        self
+    }
+
+    pub fn with_group_name(mut self, group_name: impl Into<String>) -> Self {
+        self.group_name = Some(group_name.into());
+        self
+    }
+
+    pub fn with_options(mut self, options: SubmitOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// The packages depended upon in the given way.
+    pub fn packages_of_kind(
+        &self,
+        kind: DependencyKind,
+    ) -> impl Iterator<Item = &PackageDescriptorAndLockfile> {
+        self.packages
+            .iter()
+            .filter(move |package| package.kind == kind)
+    }
+
+    /// Drop packages depended upon in any of the given ways, such as
+    /// development dependencies.
+    pub fn exclude_kinds(&mut self, kinds: &[DependencyKind]) {
+        self.packages
+            .retain(|package| !kinds.contains(&package.kind));
+    }
+}
+
+/// Scheduling priority of a submitted job
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum JobPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+/// Options for a job submission
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(default)]
+pub struct SubmitOptions {
+    /// Priority of the analysis relative to other jobs
+    pub priority: JobPriority,
+    /// Wait for the analysis to complete before responding
+    pub synchronous: bool,
+    /// Evaluate the project's policy against the job results
+    #[serde(alias = "evaluatePolicy")]
+    pub evaluate_policy: bool,
+}
+
+impl SubmitOptions {
+    /// Whether these are the options used when none are specified.
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
 }
 
 /// Initial response after package has been submitted
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct SubmitPackageResponse {
     /// The id of the job processing the package
+    #[serde(alias = "jobId")]
     pub job_id: JobId,
 }
 
+/// Submit packages for analysis in several projects or labels at once
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct BatchSubmitRequest {
+    pub submissions: Vec<SubmitPackageRequest>,
+}
+
+/// How large a batch the API accepts.
+///
+/// The limits depend on the deployment, so callers supply them.
+#[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
+pub struct BatchLimits {
+    /// Most submissions accepted in one batch
+    pub max_submissions: usize,
+    /// Most packages accepted across all submissions of one batch
+    pub max_packages: usize,
+}
+
+impl BatchLimits {
+    pub fn new(max_submissions: usize, max_packages: usize) -> Self {
+        Self {
+            max_submissions,
+            max_packages,
+        }
+    }
+}
+
+impl BatchSubmitRequest {
+    pub fn new(submissions: Vec<SubmitPackageRequest>) -> Self {
+        Self { submissions }
+    }
+
+    pub fn total_packages(&self) -> usize {
+        self.submissions
+            .iter()
+            .map(|submission| submission.packages.len())
+            .sum()
+    }
+
+    /// Check that the batch is within `limits`.
+    pub fn validate_size(&self, limits: &BatchLimits) -> Result<(), BatchSizeError> {
+        if self.submissions.is_empty() {
+            return Err(BatchSizeError::Empty);
+        }
+        if self.submissions.len() > limits.max_submissions {
+            return Err(BatchSizeError::TooManySubmissions {
+                count: self.submissions.len(),
+                max: limits.max_submissions,
+            });
+        }
+        let packages = self.total_packages();
+        if packages > limits.max_packages {
+            return Err(BatchSizeError::TooManyPackages {
+                count: packages,
+                max: limits.max_packages,
+            });
+        }
+        Ok(())
+    }
+
+    /// Split submissions into batches within `limits`, in order.
+    ///
+    /// A single submission with more than `max_packages` packages still gets
+    /// a batch of its own, which the API will reject.
+    pub fn chunked(submissions: Vec<SubmitPackageRequest>, limits: &BatchLimits) -> Vec<Self> {
+        let mut batches = Vec::new();
+        let mut batch = Self {
+            submissions: Vec::new(),
+        };
+        let mut packages = 0;
+        for submission in submissions {
+            let full = batch.submissions.len() >= limits.max_submissions
+                || packages + submission.packages.len() > limits.max_packages;
+            if full && !batch.submissions.is_empty() {
+                batches.push(std::mem::replace(
+                    &mut batch,
+                    Self {
+                        submissions: Vec::new(),
+                    },
+                ));
+                packages = 0;
+            }
+            packages += submission.packages.len();
+            batch.submissions.push(submission);
+        }
+        if !batch.submissions.is_empty() {
+            batches.push(batch);
+        }
+        batches
+    }
+}
+
+/// Why a batch was not sent
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum BatchSizeError {
+    Empty,
+    /// More submissions than [`BatchLimits::max_submissions`]
+    TooManySubmissions {
+        count: usize,
+        max: usize,
+    },
+    /// More packages in total than [`BatchLimits::max_packages`]
+    TooManyPackages {
+        count: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for BatchSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BatchSizeError::Empty => write!(f, "batch has no submissions"),
+            BatchSizeError::TooManySubmissions { count, max } => write!(
+                f,
+                "batch has {count} submissions, but at most {max} are allowed"
+            ),
+            BatchSizeError::TooManyPackages { count, max } => write!(
+                f,
+                "batch has {count} packages, but at most {max} are allowed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BatchSizeError {}
+
+/// A submission of a batch which was rejected
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct BatchSubmitFailure {
+    /// Position of the submission in the request
+    pub index: usize,
+    pub error: ApiError,
+}
+
+/// Response to a batch of submissions, some of which may have failed
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct BatchSubmitResponse {
+    /// The job of each submission, in request order; `null` for failures
+    #[serde(alias = "jobIds")]
+    pub job_ids: Vec<Option<JobId>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub failures: Vec<BatchSubmitFailure>,
+}
+
+impl BatchSubmitResponse {
+    pub fn is_complete_success(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The outcome of the submission at `index`, if the batch had one.
+    pub fn result(&self, index: usize) -> Option<Result<JobId, &ApiError>> {
+        if let Some(failure) = self.failures.iter().find(|failure| failure.index == index) {
+            return Some(Err(&failure.error));
+        }
+        self.job_ids.get(index).copied().flatten().map(Ok)
+    }
+
+    /// The submissions which were accepted, with their positions.
+    pub fn submitted(&self) -> impl Iterator<Item = (usize, JobId)> + '_ {
+        self.job_ids
+            .iter()
+            .enumerate()
+            .filter_map(|(index, job_id)| job_id.map(|job_id| (index, job_id)))
+    }
+}
+
 /// Represents a response that summarizes the output of all current jobs
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct AllJobsStatusResponse {
     /// A description of the latest jobs
     pub jobs: Vec<JobDescriptor>,
     /// Total jobs run
+    #[serde(alias = "totalJobs")]
     pub total_jobs: u32,
     pub count: u32,
 }
 
-#[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+/// The order of jobs in a listing
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum JobSortKey {
+    #[default]
+    CreatedAt,
+    LastUpdated,
+    Label,
+    ProjectName,
+}
+
+/// The direction of a sort
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Query parameters of the jobs listing, which responds with an
+/// [`AllJobsStatusResponse`].
+///
+/// Every field is optional, and unset fields are left out of the query
+/// string. The fields are flat, so they serialize with
+/// `serde_urlencoded`; ecosystems are a single comma separated value.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct JobListParams {
+    /// Only jobs of this project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<ProjectId>,
+    /// Only jobs with this label
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<Label>,
+    /// Only jobs created at or after this time
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "createdAfter"
+    )]
+    pub created_after: Option<Timestamp>,
+    /// Only jobs created before this time
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "createdBefore"
+    )]
+    pub created_before: Option<Timestamp>,
+    /// Only jobs which passed, or only jobs which failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pass: Option<bool>,
+    /// Only jobs with packages of any of these ecosystems
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        with = "comma_separated"
+    )]
+    #[schemars(with = "String")]
+    pub ecosystems: Vec<PackageType>,
+    /// Maximum number of jobs to return
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+    /// Number of jobs to skip
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<JobSortKey>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<SortOrder>,
+}
+
+impl JobListParams {
+    /// The parameters of the page following one with `count` jobs.
+    pub fn next_page(&self, count: u32) -> Self {
+        Self {
+            offset: Some(self.offset.unwrap_or_default().saturating_add(count)),
+            ..self.clone()
+        }
+    }
+}
+
+/// Ecosystems as a single comma separated value, such as `npm,pypi`.
+mod comma_separated {
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::types::package::PackageType;
+
+    pub fn serialize<S: Serializer>(
+        ecosystems: &[PackageType],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let names: Vec<_> = ecosystems.iter().map(ToString::to_string).collect();
+        serializer.serialize_str(&names.join(","))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<PackageType>, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                name.parse()
+                    .map_err(|()| D::Error::custom(format!("unknown ecosystem: {name}")))
+            })
+            .collect()
+    }
+}
+
+/// A job status response with either basic or extended package statuses.
+///
+/// The variant is chosen by the presence of the extended `type` and
+/// `riskVectors` keys on the packages. A `null` type counts as absent, while
+/// `riskVectors` counts even when `null`. Responses without packages are
+/// considered extended.
+#[derive(PartialEq, Clone, Debug, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum JobStatusResponseVariant {
-    // Serde returns the one that deserializes successfully first, so most complicated goes first
     Extended(JobStatusResponse<PackageStatusExtended>),
     Basic(JobStatusResponse<PackageStatus>),
 }
 
+impl<'de> Deserialize<'de> for JobStatusResponseVariant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let response = JobStatusResponse::<PackageStatusShape>::deserialize(deserializer)?;
+
+        let extended = response
+            .packages
+            .iter()
+            .filter(|package| package.is_extended())
+            .count();
+        if extended == 0 && !response.packages.is_empty() {
+            return Ok(Self::Basic(
+                response.map_packages(|package| package.basic_status),
+            ));
+        }
+        if extended < response.packages.len() {
+            return Err(D::Error::custom(
+                "job mixes basic and extended package statuses",
+            ));
+        }
+
+        response
+            .try_map_packages(PackageStatusShape::into_extended)
+            .map(Self::Extended)
+            .map_err(D::Error::custom)
+    }
+}
+
+/// Either shape of package status, with every extended field optional.
+#[derive(Deserialize)]
+struct PackageStatusShape {
+    #[serde(flatten)]
+    basic_status: PackageStatus,
+    #[serde(default, rename = "type")]
+    package_type: Patch<PackageType>,
+    #[serde(default, rename = "riskVectors", alias = "risk_vectors")]
+    risk_vectors: Patch<IndexMap<String, f64>>,
+    #[serde(default)]
+    dependencies: Patch<IndexMap<String, String>>,
+    #[serde(default)]
+    issues: Patch<Vec<IssueStatus>>,
+    #[cfg(deny_unknown_fields)]
+    #[serde(flatten, deserialize_with = "reject_unknown")]
+    extra: UnknownFields,
+}
+
+impl PackageStatusShape {
+    fn is_extended(&self) -> bool {
+        matches!(self.package_type, Patch::Value(_)) || !self.risk_vectors.is_absent()
+    }
+
+    fn into_extended(self) -> Result<PackageStatusExtended, String> {
+        let package_type = match self.package_type {
+            Patch::Value(package_type) => package_type,
+            _ => return Err(format!("package {} has no type", self.basic_status.name)),
+        };
+
+        Ok(PackageStatusExtended {
+            basic_status: self.basic_status,
+            package_type,
+            risk_vectors: self
+                .risk_vectors
+                .into_option()
+                .flatten()
+                .unwrap_or_default(),
+            dependencies: self
+                .dependencies
+                .into_option()
+                .flatten()
+                .unwrap_or_default(),
+            issues: self.issues.into_option().flatten().unwrap_or_default(),
+            #[cfg(deny_unknown_fields)]
+            extra: self.extra,
+        })
+    }
+}
+
+impl JobStatusResponseVariant {
+    /// The response with basic package statuses, dropping extended data.
+    pub fn into_basic(self) -> JobStatusResponse<PackageStatus> {
+        match self {
+            Self::Extended(response) => response.map_packages(PackageStatus::from),
+            Self::Basic(response) => response,
+        }
+    }
+
+    /// The response with extended package statuses, if it has them.
+    pub fn as_extended(&self) -> Option<&JobStatusResponse<PackageStatusExtended>> {
+        match self {
+            Self::Extended(response) => Some(response),
+            Self::Basic(_) => None,
+        }
+    }
+}
+
+impl From<JobStatusResponseVariant> for JobStatusResponse<PackageStatus> {
+    fn from(variant: JobStatusResponseVariant) -> Self {
+        variant.into_basic()
+    }
+}
+
 /// Data returned when querying the job status endpoint
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct JobStatusResponse<T> {
     /// The id of the job processing the top level package
+    #[serde(alias = "jobId")]
     pub job_id: JobId,
     /// The language ecosystem
     #[serde(default)]
-    pub ecosystems: Vec<String>,
+    pub ecosystems: Vec<Ecosystem>,
     /// The id of the user submitting the job
+    #[serde(alias = "userId")]
     pub user_id: UserId,
     /// The user email
+    #[serde(alias = "userEmail")]
     pub user_email: String,
-    /// The time the job started in epoch seconds
-    pub created_at: i64,
+    /// The time the job started, as epoch seconds on the wire
+    #[serde(with = "timestamp::epoch_seconds", alias = "createdAt")]
+    #[schemars(with = "i64")]
+    pub created_at: Timestamp,
     /// The job status
     pub status: Status,
     pub pass: bool,
+    /// Display text describing the outcome; match on `code` instead
     pub msg: String,
+    /// Machine readable kind of `msg`, when the server provides one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<JobMessageCode>,
+    /// Values referenced by `msg`, such as the failed threshold
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
     #[serde(default)]
     /// Dependencies that have not completed processing
+    #[serde(alias = "numIncomplete")]
     pub num_incomplete: u32,
-    /// The last time the job metadata was updated
-    pub last_updated: u64,
+    /// The last time the job metadata was updated, as epoch seconds on the
+    /// wire
+    #[serde(with = "timestamp::epoch_seconds", alias = "lastUpdated")]
+    #[schemars(with = "u64")]
+    pub last_updated: Timestamp,
     /// The id of the project associated with this job
-    pub project: String,
+    pub project: ProjectId,
     /// The project name
+    #[serde(alias = "projectName")]
     pub project_name: String,
     /// A label associated with this job, most often a branch name
-    pub label: Option<String>,
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
     /// The packages that are a part of this job
     pub packages: Vec<T>,
+    /// Data attached by integrators
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+impl<T> JobStatusResponse<T> {
+    /// The package types of the job's ecosystems, skipping those not known to
+    /// this version of the crate.
+    pub fn package_types(&self) -> impl Iterator<Item = PackageType> + '_ {
+        self.ecosystems.iter().filter_map(Ecosystem::package_type)
+    }
+
+    /// The kind of message, falling back to recognizing `msg` for servers
+    /// which don't send a code.
+    pub fn message_code(&self) -> JobMessageCode {
+        self.code
+            .unwrap_or_else(|| JobMessageCode::from_msg(&self.msg))
+    }
+
+    /// Transform every package, keeping the rest of the response.
+    pub fn map_packages<U>(self, mut f: impl FnMut(T) -> U) -> JobStatusResponse<U> {
+        match self.try_map_packages(|package| Ok::<_, Infallible>(f(package))) {
+            Ok(response) => response,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Transform every package, stopping at the first error.
+    pub fn try_map_packages<U, E>(
+        self,
+        f: impl FnMut(T) -> Result<U, E>,
+    ) -> Result<JobStatusResponse<U>, E> {
+        Ok(JobStatusResponse {
+            job_id: self.job_id,
+            ecosystems: self.ecosystems,
+            user_id: self.user_id,
+            user_email: self.user_email,
+            created_at: self.created_at,
+            status: self.status,
+            pass: self.pass,
+            msg: self.msg,
+            code: self.code,
+            details: self.details,
+            num_incomplete: self.num_incomplete,
+            last_updated: self.last_updated,
+            project: self.project,
+            project_name: self.project_name,
+            label: self.label,
+            packages: self.packages.into_iter().map(f).collect::<Result<_, _>>()?,
+            metadata: self.metadata,
+            #[cfg(feature = "preserve-unknown")]
+            extra: self.extra,
+        })
+    }
+}
+
+/// Request to cancel a job
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(default)]
+pub struct CancelJobRequest {
+    /// Why the job is being canceled, for the audit log
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Cancel the job even if its packages are already being processed
+    pub force: bool,
+}
+
+impl CancelJobRequest {
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    pub fn with_force(mut self, force: bool) -> Self {
+        self.force = force;
+        self
+    }
 }
 
 /// Response from canceling a job
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct CancelJobResponse {
+    /// Display text describing the outcome; match on `code` instead
     pub msg: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<JobMessageCode>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
+    /// The canceled job; not sent by older servers
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "jobId")]
+    pub job_id: Option<JobId>,
+    /// Status of the job before it was canceled; not sent by older servers
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "previousStatus"
+    )]
+    pub previous_status: Option<Status>,
+    /// When the job was canceled; not sent by older servers
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "cancelledAt"
+    )]
+    pub cancelled_at: Option<Timestamp>,
+}
+
+impl CancelJobResponse {
+    /// The kind of message, falling back to recognizing `msg` for servers
+    /// which don't send a code.
+    pub fn message_code(&self) -> JobMessageCode {
+        self.code
+            .unwrap_or_else(|| JobMessageCode::from_msg(&self.msg))
+    }
+
+    /// Whether the job was stopped by this request, rather than having
+    /// already finished.
+    pub fn was_cancelled(&self) -> bool {
+        match self.previous_status {
+            Some(status) => status == Status::Incomplete,
+            None => self.message_code() == JobMessageCode::Canceled,
+        }
+    }
+}
+
+/// Known kinds of job response messages
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum JobMessageCode {
+    /// Every package met the project's thresholds
+    ThresholdsMet,
+    /// A package failed one of the project's thresholds
+    ThresholdFailed,
+    /// Some packages are still being processed
+    Incomplete,
+    /// The job was canceled
+    Canceled,
+    /// The job could not be canceled because it already finished
+    AlreadyComplete,
+    /// A message not known to this version of the crate
+    #[serde(other)]
+    Unknown,
+}
+
+impl JobMessageCode {
+    /// Recognize the text of messages sent by servers which predate codes.
+    pub fn from_msg(msg: &str) -> Self {
+        let msg = msg.to_lowercase();
+        if msg.contains("met threshold") {
+            Self::ThresholdsMet
+        } else if msg.contains("failed due to") {
+            Self::ThresholdFailed
+        } else if msg.contains("incomplete") || msg.contains("processing") {
+            Self::Incomplete
+        } else if msg.contains("already") {
+            Self::AlreadyComplete
+        } else if msg.contains("cancel") {
+            Self::Canceled
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// A status change in the lifetime of a job
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct JobStateHistoryEntry {
+    /// The status the job entered
+    pub status: Status,
+    /// When the job entered this status
+    pub timestamp: Timestamp,
+    /// Who caused the change, such as a user email; `None` for the system
+    pub actor: Option<String>,
+}
+
+/// Response from the job audit endpoint
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct JobStateHistoryResponse {
+    #[serde(alias = "jobId")]
+    pub job_id: JobId,
+    /// Status changes, oldest first
+    pub history: Vec<JobStateHistoryEntry>,
+}
+
+impl JobStateHistoryResponse {
+    /// The transitions between consecutive entries, or `None` if the history
+    /// contains a transition which is not allowed.
+    ///
+    /// Consecutive entries with the same status, such as two actors touching
+    /// an incomplete job, are not transitions and are skipped.
+    pub fn transitions(&self) -> Option<Vec<StatusTransition>> {
+        self.history
+            .windows(2)
+            .filter(|pair| pair[0].status != pair[1].status)
+            .map(|pair| StatusTransition::new(pair[0].status, pair[1].status))
+            .collect()
+    }
+}
+
+/// The packages of a job which were found in one lockfile
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct LockfileGroup {
+    /// The lockfile; `None` for packages not attributed to any lockfile
+    pub lockfile: Option<LockfileRef>,
+    pub packages: Vec<PackageStatusExtended>,
+    /// Issues of the packages, merged across packages
+    pub issues: Vec<AggregatedIssue>,
+}
+
+impl LockfileGroup {
+    fn new(lockfile: Option<LockfileRef>) -> Self {
+        Self {
+            lockfile,
+            packages: Vec::new(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn finish(mut self) -> Self {
+        let mut aggregator = IssueAggregator::default();
+        for package in &self.packages {
+            aggregator.add_package(package);
+        }
+        self.issues = aggregator.finish();
+        self
+    }
+}
+
+/// A job with its packages partitioned by the lockfiles they were found in,
+/// for reporting on each subproject of a monorepo separately.
+///
+/// A package found in several lockfiles is part of each of their groups.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct JobByLockfileView {
+    #[serde(alias = "jobId")]
+    pub job_id: JobId,
+    pub project: ProjectId,
+    #[serde(alias = "projectName")]
+    pub project_name: String,
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
+    /// Groups ordered by lockfile path, followed by the group of packages
+    /// without a lockfile, if there are any
+    pub lockfiles: Vec<LockfileGroup>,
+}
+
+impl JobByLockfileView {
+    pub fn new(job: &JobStatusResponse<PackageStatusExtended>) -> Self {
+        let mut groups = BTreeMap::<LockfilePath, LockfileGroup>::new();
+        let mut unattributed = LockfileGroup::new(None);
+
+        for package in &job.packages {
+            let lockfiles = &package.basic_status.lockfiles;
+            if lockfiles.is_empty() {
+                unattributed.packages.push(package.clone());
+                continue;
+            }
+
+            let mut seen = BTreeSet::new();
+            for lockfile in lockfiles {
+                if !seen.insert(&lockfile.path) {
+                    continue;
+                }
+                let group = groups
+                    .entry(lockfile.path.clone())
+                    .or_insert_with(|| LockfileGroup::new(Some(lockfile.clone())));
+                if let Some(group_lockfile) = &mut group.lockfile {
+                    group_lockfile.format = group_lockfile.format.or(lockfile.format);
+                }
+                group.packages.push(package.clone());
+            }
+        }
+
+        let mut lockfiles: Vec<_> = groups.into_values().map(LockfileGroup::finish).collect();
+        if !unattributed.packages.is_empty() {
+            lockfiles.push(unattributed.finish());
+        }
+
+        Self {
+            job_id: job.job_id,
+            project: job.project,
+            project_name: job.project_name.clone(),
+            label: job.label.clone(),
+            lockfiles,
+        }
+    }
+
+    /// The group of a lockfile, if any of the job's packages were found in it.
+    pub fn lockfile(&self, path: &LockfilePath) -> Option<&LockfileGroup> {
+        self.lockfiles.iter().find(|group| {
+            group
+                .lockfile
+                .as_ref()
+                .is_some_and(|lockfile| &lockfile.path == path)
+        })
+    }
+
+    /// The group of packages not attributed to any lockfile.
+    pub fn unattributed(&self) -> Option<&LockfileGroup> {
+        self.lockfiles.iter().find(|group| group.lockfile.is_none())
+    }
+}
+
+impl From<&JobStatusResponse<PackageStatusExtended>> for JobByLockfileView {
+    fn from(job: &JobStatusResponse<PackageStatusExtended>) -> Self {
+        Self::new(job)
+    }
 }
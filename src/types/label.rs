@@ -0,0 +1,204 @@
+//! Job labels, which are free-form names but restricted by the API.
+//!
+//! A [`Label`] is normalized and validated when created, so requests with an
+//! invalid label fail before being sent:
+//!
+//! ```
+//! use std::convert::TryFrom;
+//!
+//! use phylum_types::types::label::{Label, LabelError};
+//!
+//! let label = Label::try_from("  feature/new   parser ").unwrap();
+//! assert_eq!(label, "feature/new parser");
+//!
+//! assert_eq!(Label::try_from("   "), Err(LabelError::Empty));
+//! ```
+//!
+//! Labels in responses were accepted by the API already, and may predate its
+//! current restrictions. Response types therefore read them with
+//! [`lenient::option_label`](crate::types::lenient::option_label), which
+//! only normalizes whitespace.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Why a label was rejected
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum LabelError {
+    /// Nothing but whitespace
+    Empty,
+    /// More than [`Label::MAX_LEN`] characters after normalization
+    TooLong(usize),
+    /// A control character other than whitespace, such as NUL or ESC
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for LabelError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LabelError::Empty => f.write_str("label is empty"),
+            LabelError::TooLong(len) => write!(
+                f,
+                "label has {len} characters, but at most {} are allowed",
+                Label::MAX_LEN
+            ),
+            LabelError::InvalidCharacter(c) => write!(f, "label contains invalid character {c:?}"),
+        }
+    }
+}
+
+impl std::error::Error for LabelError {}
+
+/// The label of a job, most often a branch name.
+///
+/// Surrounding whitespace is trimmed and inner runs of whitespace are
+/// collapsed into a single space. Serialized as a plain string.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Label(String);
+
+impl Label {
+    /// Maximum number of characters in a label
+    pub const MAX_LEN: usize = 255;
+
+    pub fn new(value: impl Into<String>) -> Result<Self, LabelError> {
+        let value = value.into();
+        if let Some(c) = value.chars().find(|c| c.is_control() && !c.is_whitespace()) {
+            return Err(LabelError::InvalidCharacter(c));
+        }
+
+        let value = if is_normalized(&value) {
+            value
+        } else {
+            value.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+
+        if value.is_empty() {
+            return Err(LabelError::Empty);
+        }
+        let len = value.chars().count();
+        if len > Self::MAX_LEN {
+            return Err(LabelError::TooLong(len));
+        }
+
+        Ok(Self(value))
+    }
+
+    /// A label received from the API, with whitespace normalized but not
+    /// checked against the restrictions on labels sent to it.
+    ///
+    /// Returns `None` for a label of nothing but whitespace.
+    pub fn lenient(value: impl Into<String>) -> Option<Self> {
+        let value = value.into();
+        let value = if is_normalized(&value) {
+            value
+        } else {
+            value.split_whitespace().collect::<Vec<_>>().join(" ")
+        };
+        Some(Self(value)).filter(|label| !label.0.is_empty())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// Whether normalizing `value` would leave it unchanged.
+fn is_normalized(value: &str) -> bool {
+    let mut previous_space = true;
+    for c in value.chars() {
+        if c.is_whitespace() {
+            if c != ' ' || previous_space {
+                return false;
+            }
+            previous_space = true;
+        } else {
+            previous_space = false;
+        }
+    }
+    !previous_space || value.is_empty()
+}
+
+impl TryFrom<String> for Label {
+    type Error = LabelError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<&str> for Label {
+    type Error = LabelError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl FromStr for Label {
+    type Err = LabelError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::new(value)
+    }
+}
+
+impl From<Label> for String {
+    fn from(label: Label) -> Self {
+        label.0
+    }
+}
+
+impl Deref for Label {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Label {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Label {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl PartialEq<str> for Label {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for Label {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl JsonSchema for Label {
+    fn schema_name() -> String {
+        "Label".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = String::json_schema(gen).into_object();
+        schema.string().min_length = Some(1);
+        schema.string().max_length = Some(Self::MAX_LEN as u32);
+        schema.into()
+    }
+}
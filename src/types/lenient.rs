@@ -0,0 +1,175 @@
+//! Serde helpers for values whose type drifts between API versions and
+//! registries, or which the API may send in a form it no longer accepts.
+//!
+//! Counters such as download counts arrive as integers, as floats such as
+//! `2.5e6`, or as strings such as `"12345"`. Fields opt in with
+//! `#[serde(deserialize_with = "...")]` and serialize as plain integers:
+//!
+//! ```
+//! use phylum_types::types::lenient;
+//! use serde::Deserialize;
+//!
+//! #[derive(Deserialize)]
+//! struct Example {
+//!     #[serde(deserialize_with = "lenient::u64")]
+//!     count: u64,
+//! }
+//!
+//! for json in [r#"{"count": 12345}"#, r#"{"count": 12345.0}"#, r#"{"count": "12345"}"#] {
+//!     let example: Example = serde_json::from_str(json).unwrap();
+//!     assert_eq!(example.count, 12345);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+
+use super::label::Label;
+use super::timestamp::{self, Timestamp};
+
+/// An unsigned integer, from an integer, a float or a string holding either.
+///
+/// Floats are rounded to the nearest integer.
+pub fn u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    deserializer.deserialize_any(U64Visitor)
+}
+
+/// Like [`u64()`], also accepting `null`.
+pub fn option_u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    deserializer.deserialize_option(OptionVisitor)
+}
+
+struct U64Visitor;
+
+impl<'de> Visitor<'de> for U64Visitor {
+    type Value = u64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative number, or a string holding one")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<u64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<u64, E> {
+        u64::try_from(value).map_err(|_| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<u64, E> {
+        let rounded = value.round();
+        // `u64::MAX as f64` rounds up to 2^64, which is out of range
+        if rounded >= 0. && rounded < u64::MAX as f64 {
+            Ok(rounded as u64)
+        } else {
+            Err(E::invalid_value(Unexpected::Float(value), &self))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<u64, E> {
+        let trimmed = value.trim();
+        if let Ok(value) = trimmed.parse() {
+            return Ok(value);
+        }
+        match trimmed.parse() {
+            Ok(float) => self.visit_f64(float),
+            Err(_) => Err(E::invalid_value(Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+struct OptionVisitor;
+
+impl<'de> Visitor<'de> for OptionVisitor {
+    type Value = Option<u64>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a non-negative number, a string holding one, or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        u64(deserializer).map(Some)
+    }
+}
+
+/// An optional job label, normalized with [`Label::lenient`] instead of being
+/// validated, so labels which the API no longer accepts still parse.
+///
+/// Empty labels become `None`.
+pub fn option_label<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Label>, D::Error> {
+    Ok(Option::<String>::deserialize(deserializer)?.and_then(Label::lenient))
+}
+
+/// A timestamp in RFC 3339 or a close variant of it, such as
+/// `2022-04-15 05:20:00 UTC`, in RFC 2822, or as integer seconds since the
+/// Unix epoch.
+///
+/// Serialize with the default RFC 3339 encoding. Formats which are not
+/// self-describing, such as bincode, only accept that encoding.
+pub fn timestamp<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(TimestampVisitor)
+    } else {
+        Timestamp::deserialize(deserializer)
+    }
+}
+
+struct TimestampVisitor;
+
+impl<'de> Visitor<'de> for TimestampVisitor {
+    type Value = Timestamp;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a timestamp, such as `2022-06-01T12:00:00Z`")
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Timestamp, E> {
+        i64::try_from(value)
+            .ok()
+            .and_then(timestamp::from_epoch_seconds)
+            .ok_or_else(|| E::invalid_value(Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Timestamp, E> {
+        timestamp::from_epoch_seconds(value)
+            .ok_or_else(|| E::invalid_value(Unexpected::Signed(value), &self))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Timestamp, E> {
+        parse_timestamp(value.trim()).ok_or_else(|| E::invalid_value(Unexpected::Str(value), &self))
+    }
+}
+
+fn parse_timestamp(value: &str) -> Option<Timestamp> {
+    if let Ok(timestamp) = value.parse() {
+        return Some(timestamp);
+    }
+    if let Ok(seconds) = value.parse() {
+        return timestamp::from_epoch_seconds(seconds);
+    }
+    if let Some(timestamp) = timestamp::from_rfc2822(value) {
+        return Some(timestamp);
+    }
+
+    // A space instead of the `T`, and a zone name or no zone instead of an
+    // offset
+    let value = value
+        .strip_suffix(" UTC")
+        .or_else(|| value.strip_suffix(" GMT"))
+        .unwrap_or(value);
+    let (date, time) = value.split_once([' ', 'T'])?;
+    let has_offset = time.ends_with('Z') || time.contains(['+', '-']);
+    let zone = if has_offset { "" } else { "Z" };
+    format!("{date}T{time}{zone}").parse().ok()
+}
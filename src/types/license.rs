@@ -0,0 +1,143 @@
+//! This module contains the parsed form of SPDX license expressions, such as
+//! `MIT OR Apache-2.0`, and their classification for license risk.
+//!
+//! ```
+//! use phylum_types::types::license::{LicenseCategory, LicenseExpression};
+//!
+//! let license = LicenseExpression::parse("GPL-3.0-only OR MIT").unwrap();
+//! assert_eq!(license.category(), LicenseCategory::Permissive);
+//!
+//! let license = LicenseExpression::parse("GPL-3.0-only AND MIT").unwrap();
+//! assert_eq!(license.category(), LicenseCategory::Copyleft);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use spdx::{Expression, LicenseItem, LicenseReq, ParseMode};
+
+pub use spdx::ParseError;
+
+/// How restrictive the terms of a license are
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum LicenseCategory {
+    /// Can be used under an open source license without copyleft terms
+    Permissive,
+    /// Requires accepting a copyleft license, such as the GPL
+    Copyleft,
+    /// Requires accepting a license which is not an open source license, or
+    /// which is not on the SPDX license list
+    Unknown,
+}
+
+/// A parsed SPDX license expression.
+///
+/// Registry metadata is often not quite valid SPDX, so parsing is lenient:
+/// lowercase operators, `/` as `OR`, and common misspellings of license names
+/// are accepted. Serialized as the original string.
+#[derive(Clone, Debug)]
+pub struct LicenseExpression(Expression);
+
+impl LicenseExpression {
+    pub fn parse(expression: &str) -> Result<Self, ParseError> {
+        Expression::parse_mode(expression, ParseMode::LAX).map(Self)
+    }
+
+    /// The expression as it was parsed.
+    pub fn as_str(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    /// Identifiers of all licenses in the expression, such as `MIT`.
+    pub fn licenses(&self) -> Vec<String> {
+        self.0
+            .requirements()
+            .map(|requirement| requirement.req.license.to_string())
+            .collect()
+    }
+
+    /// The least restrictive way to satisfy the expression.
+    pub fn category(&self) -> LicenseCategory {
+        if self
+            .0
+            .evaluate(|req| category(req) == LicenseCategory::Permissive)
+        {
+            LicenseCategory::Permissive
+        } else if self
+            .0
+            .evaluate(|req| category(req) != LicenseCategory::Unknown)
+        {
+            LicenseCategory::Copyleft
+        } else {
+            LicenseCategory::Unknown
+        }
+    }
+
+    /// The underlying parsed expression.
+    pub fn expression(&self) -> &Expression {
+        &self.0
+    }
+}
+
+/// The category of a single license.
+fn category(req: &LicenseReq) -> LicenseCategory {
+    match &req.license {
+        LicenseItem::Spdx { id, .. } if !id.is_osi_approved() && !id.is_fsf_free_libre() => {
+            LicenseCategory::Unknown
+        }
+        LicenseItem::Spdx { id, .. } if id.is_copyleft() => LicenseCategory::Copyleft,
+        LicenseItem::Spdx { .. } => LicenseCategory::Permissive,
+        LicenseItem::Other { .. } => LicenseCategory::Unknown,
+    }
+}
+
+impl PartialEq for LicenseExpression {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for LicenseExpression {}
+
+impl FromStr for LicenseExpression {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for LicenseExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for LicenseExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LicenseExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let expression = String::deserialize(deserializer)?;
+        Self::parse(&expression).map_err(D::Error::custom)
+    }
+}
+
+impl JsonSchema for LicenseExpression {
+    fn schema_name() -> String {
+        "LicenseExpression".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
@@ -0,0 +1,356 @@
+//! This module contains types describing parsed lockfiles submitted for
+//! analysis.
+// Parsed lockfiles still list the deprecated v1 package descriptors, and the
+// `JsonSchema` derive does not pass an item-level allow on to its impl
+#![allow(deprecated)]
+
+use std::fmt;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::package::{
+    DependencyKind, PackageDescriptor, PackageDescriptorAndLockfile, PackageType,
+    PackageUrlAndLockfile,
+};
+
+/// The format of a lockfile or manifest.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub enum LockfileFormat {
+    /// npm `package-lock.json` or `npm-shrinkwrap.json`
+    #[serde(rename = "npm", alias = "package-lock")]
+    PackageLock,
+    /// `yarn.lock`
+    #[serde(rename = "yarn")]
+    Yarn,
+    /// `pnpm-lock.yaml`
+    #[serde(rename = "pnpm")]
+    Pnpm,
+    /// `requirements.txt`
+    #[serde(rename = "pip")]
+    Pip,
+    /// `Pipfile.lock`
+    #[serde(rename = "pipenv", alias = "pipfile")]
+    Pipfile,
+    /// `poetry.lock`
+    #[serde(rename = "poetry")]
+    Poetry,
+    /// `Gemfile.lock`
+    #[serde(rename = "gem", alias = "gemfile")]
+    Gemfile,
+    /// `*.csproj` and `packages.lock.json`
+    #[serde(rename = "nuget", alias = "csproj")]
+    Csproj,
+    /// Maven effective POM
+    #[serde(rename = "maven")]
+    Maven,
+    /// `gradle.lockfile`
+    #[serde(rename = "gradle")]
+    Gradle,
+    /// `go.sum`
+    #[serde(rename = "go", alias = "go.sum")]
+    GoSum,
+    /// `Cargo.lock`
+    #[serde(rename = "cargo")]
+    Cargo,
+    /// `composer.lock`
+    #[serde(rename = "composer")]
+    Composer,
+}
+
+impl LockfileFormat {
+    /// The name used for this format on the wire.
+    pub fn name(&self) -> &'static str {
+        match self {
+            LockfileFormat::PackageLock => "npm",
+            LockfileFormat::Yarn => "yarn",
+            LockfileFormat::Pnpm => "pnpm",
+            LockfileFormat::Pip => "pip",
+            LockfileFormat::Pipfile => "pipenv",
+            LockfileFormat::Poetry => "poetry",
+            LockfileFormat::Gemfile => "gem",
+            LockfileFormat::Csproj => "nuget",
+            LockfileFormat::Maven => "maven",
+            LockfileFormat::Gradle => "gradle",
+            LockfileFormat::GoSum => "go",
+            LockfileFormat::Cargo => "cargo",
+            LockfileFormat::Composer => "composer",
+        }
+    }
+
+    /// The ecosystem of the packages contained in this format.
+    pub fn package_type(&self) -> PackageType {
+        match self {
+            LockfileFormat::PackageLock | LockfileFormat::Yarn | LockfileFormat::Pnpm => {
+                PackageType::Npm
+            }
+            LockfileFormat::Pip | LockfileFormat::Pipfile | LockfileFormat::Poetry => {
+                PackageType::PyPi
+            }
+            LockfileFormat::Gemfile => PackageType::RubyGems,
+            LockfileFormat::Csproj => PackageType::Nuget,
+            LockfileFormat::Maven | LockfileFormat::Gradle => PackageType::Maven,
+            LockfileFormat::GoSum => PackageType::Golang,
+            LockfileFormat::Cargo => PackageType::Cargo,
+            LockfileFormat::Composer => PackageType::Composer,
+        }
+    }
+}
+
+impl FromStr for LockfileFormat {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "npm" | "package-lock" => Ok(Self::PackageLock),
+            "yarn" => Ok(Self::Yarn),
+            "pnpm" => Ok(Self::Pnpm),
+            "pip" => Ok(Self::Pip),
+            "pipenv" | "pipfile" => Ok(Self::Pipfile),
+            "poetry" => Ok(Self::Poetry),
+            "gem" | "gemfile" => Ok(Self::Gemfile),
+            "nuget" | "csproj" => Ok(Self::Csproj),
+            "maven" => Ok(Self::Maven),
+            "gradle" => Ok(Self::Gradle),
+            "go" | "go.sum" => Ok(Self::GoSum),
+            "cargo" => Ok(Self::Cargo),
+            "composer" => Ok(Self::Composer),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LockfileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A lockfile and the packages parsed from it
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ParsedLockfile {
+    /// Path to the lockfile, as given by the submitter
+    pub path: LockfilePath,
+    /// The lockfile format
+    pub format: LockfileFormat,
+    /// Packages found in the lockfile
+    pub packages: Vec<PackageDescriptor>,
+}
+
+impl ParsedLockfile {
+    /// The packages of this lockfile, each tagged with the lockfile path.
+    pub fn analysis_packages(&self) -> impl Iterator<Item = AnalysisPackageDescriptor> + '_ {
+        self.packages.iter().map(move |package| {
+            AnalysisPackageDescriptor::PackageDescriptor(PackageDescriptorAndLockfile {
+                package_descriptor: package.clone(),
+                lockfile: Some(self.path.clone()),
+                kind: DependencyKind::Runtime,
+            })
+        })
+    }
+}
+
+/// A lockfile a package was found in
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct LockfileRef {
+    /// Path to the lockfile, as given by the submitter
+    pub path: LockfilePath,
+    /// The lockfile format, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<LockfileFormat>,
+}
+
+impl From<LockfilePath> for LockfileRef {
+    fn from(path: LockfilePath) -> Self {
+        Self { path, format: None }
+    }
+}
+
+impl From<&ParsedLockfile> for LockfileRef {
+    fn from(lockfile: &ParsedLockfile) -> Self {
+        Self {
+            path: lockfile.path.clone(),
+            format: Some(lockfile.format),
+        }
+    }
+}
+
+/// A package submitted for analysis, described either by its name, version
+/// and ecosystem, or by a PURL.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(untagged)]
+pub enum AnalysisPackageDescriptor {
+    PackageDescriptor(PackageDescriptorAndLockfile),
+    Purl(PackageUrlAndLockfile),
+}
+
+impl From<PackageDescriptorAndLockfile> for AnalysisPackageDescriptor {
+    fn from(value: PackageDescriptorAndLockfile) -> Self {
+        Self::PackageDescriptor(value)
+    }
+}
+
+impl From<PackageUrlAndLockfile> for AnalysisPackageDescriptor {
+    fn from(value: PackageUrlAndLockfile) -> Self {
+        Self::Purl(value)
+    }
+}
+
+/// Path to a lockfile, with a single normalized form across platforms.
+///
+/// Backslashes become forward slashes, and empty, `.` and resolvable `..`
+/// components are removed, so the same lockfile submitted from different CI
+/// runners compares equal. Serialized as the normalized string.
+///
+/// ```
+/// use phylum_types::types::lockfile::LockfilePath;
+///
+/// let path = LockfilePath::new(r".\frontend\\..\web\package-lock.json");
+/// assert_eq!(path.as_str(), "web/package-lock.json");
+///
+/// let absolute = LockfilePath::new("/builds/shop/web/package-lock.json");
+/// let root = LockfilePath::new("/builds/shop/");
+/// assert_eq!(absolute.relative_to(&root), Some(path));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct LockfilePath(String);
+
+impl LockfilePath {
+    pub fn new(path: impl Into<String>) -> Self {
+        let path = path.into();
+        if is_normalized(&path) {
+            Self(path)
+        } else {
+            Self(normalize(&path))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Whether the path starts at a filesystem root or Windows drive.
+    pub fn is_absolute(&self) -> bool {
+        self.0.starts_with('/') || drive_prefix(&self.0).is_some()
+    }
+
+    /// The last component of the path, such as `Cargo.lock`.
+    pub fn file_name(&self) -> &str {
+        self.0.rsplit('/').next().unwrap_or_default()
+    }
+
+    /// The path relative to a project `root`.
+    ///
+    /// Relative paths are assumed to be relative to the root already.
+    /// Returns `None` for absolute paths outside of the root.
+    pub fn relative_to(&self, root: &LockfilePath) -> Option<LockfilePath> {
+        if !self.is_absolute() {
+            return Some(self.clone());
+        }
+
+        let root = root.0.trim_end_matches('/');
+        let relative = self.0.strip_prefix(root)?.strip_prefix('/')?;
+        Some(Self(relative.into()))
+    }
+}
+
+/// The `C:` of a path starting with a Windows drive.
+fn drive_prefix(path: &str) -> Option<&str> {
+    let bytes = path.as_bytes();
+    match bytes {
+        [letter, b':', ..] if letter.is_ascii_alphabetic() => Some(&path[..2]),
+        _ => None,
+    }
+}
+
+/// Whether normalizing `path` would leave it unchanged.
+fn is_normalized(path: &str) -> bool {
+    !path.contains('\\')
+        && !path.contains("//")
+        && (path.len() <= 1 || !path.ends_with('/'))
+        && path
+            .split('/')
+            .all(|component| component != "." && component != "..")
+}
+
+fn normalize(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let (prefix, rest) = match drive_prefix(&path) {
+        Some(drive) => (drive, &path[drive.len()..]),
+        None => ("", path.as_str()),
+    };
+    let absolute = rest.starts_with('/');
+
+    let mut components: Vec<&str> = Vec::new();
+    for component in rest.split('/') {
+        match component {
+            "" | "." => (),
+            ".." => match components.last() {
+                Some(&last) if last != ".." => {
+                    components.pop();
+                }
+                // Nothing to go up from at a root
+                _ if absolute => (),
+                _ => components.push(".."),
+            },
+            component => components.push(component),
+        }
+    }
+
+    let mut normalized = String::from(prefix);
+    if absolute {
+        normalized.push('/');
+    }
+    normalized.push_str(&components.join("/"));
+    normalized
+}
+
+impl From<String> for LockfilePath {
+    fn from(path: String) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<&str> for LockfilePath {
+    fn from(path: &str) -> Self {
+        Self::new(path)
+    }
+}
+
+impl From<LockfilePath> for String {
+    fn from(path: LockfilePath) -> Self {
+        path.0
+    }
+}
+
+impl AsRef<str> for LockfilePath {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for LockfilePath {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl JsonSchema for LockfilePath {
+    fn schema_name() -> String {
+        "LockfilePath".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
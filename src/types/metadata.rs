@@ -0,0 +1,236 @@
+//! This module contains free-form metadata which integrators attach to
+//! projects, jobs and ignored issues, such as correlation ids or data of
+//! their own tools.
+//!
+//! ```
+//! use phylum_types::types::metadata::Metadata;
+//!
+//! let mut metadata = Metadata::default();
+//! metadata.insert("ci.build", &1234).unwrap();
+//! metadata.insert("ci.url", "https://ci.example.com/builds/1234").unwrap();
+//!
+//! assert_eq!(metadata.get::<u32>("ci.build").unwrap(), Some(1234));
+//! assert_eq!(metadata.get_str("ci.url"), Some("https://ci.example.com/builds/1234"));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::common::canonical_json;
+
+/// Why metadata was rejected
+#[derive(Debug)]
+pub enum MetadataError {
+    /// More than [`Metadata::MAX_KEYS`] keys
+    TooManyKeys(usize),
+    /// A key which is empty, longer than [`Metadata::MAX_KEY_LEN`], or has
+    /// characters other than ASCII letters, digits, `-`, `_`, `.` and `:`
+    InvalidKey(String),
+    /// More than [`Metadata::MAX_SIZE`] bytes when serialized as JSON
+    TooLarge(usize),
+    /// A value which could not be converted to or from JSON
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for MetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MetadataError::TooManyKeys(count) => write!(
+                f,
+                "metadata has {count} keys, but at most {} are allowed",
+                Metadata::MAX_KEYS
+            ),
+            MetadataError::InvalidKey(key) => write!(f, "invalid metadata key {key:?}"),
+            MetadataError::TooLarge(size) => write!(
+                f,
+                "metadata is {size} bytes, but at most {} are allowed",
+                Metadata::MAX_SIZE
+            ),
+            MetadataError::Json(err) => write!(f, "invalid metadata value: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for MetadataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            MetadataError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for MetadataError {
+    fn from(err: serde_json::Error) -> Self {
+        MetadataError::Json(err)
+    }
+}
+
+/// A map of string keys to JSON values, limited in size so it can be stored
+/// alongside the entity it is attached to.
+///
+/// Serialized as a JSON object. The limits are enforced when building
+/// metadata with [`insert`](Self::insert) or `TryFrom`, and checked by
+/// [`check_limits`](Self::check_limits) when validating requests, but not
+/// when deserializing, so responses are not rejected if the API's limits
+/// differ from this crate's.
+#[derive(PartialEq, Eq, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Metadata(BTreeMap<String, Value>);
+
+impl Metadata {
+    pub const MAX_KEYS: usize = 64;
+    pub const MAX_KEY_LEN: usize = 128;
+    /// Maximum size of the whole map, serialized as JSON
+    pub const MAX_SIZE: usize = 16 * 1024;
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// The value of a key as JSON.
+    pub fn get_value(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// The value of a key if it is a string.
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(Value::as_str)
+    }
+
+    /// The value of a key converted to `T`, or `None` if the key is missing.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, MetadataError> {
+        match self.0.get(key) {
+            Some(value) => Ok(Some(T::deserialize(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Set a key, returning its previous value.
+    ///
+    /// The metadata is left unchanged if the result would exceed the limits.
+    pub fn insert<T: Serialize + ?Sized>(
+        &mut self,
+        key: impl Into<String>,
+        value: &T,
+    ) -> Result<Option<Value>, MetadataError> {
+        let key = key.into();
+        check_key(&key)?;
+        let value = serde_json::to_value(value)?;
+
+        let previous = self.0.insert(key.clone(), value);
+        if let Err(err) = self.check() {
+            match previous {
+                Some(previous) => self.0.insert(key, previous),
+                None => self.0.remove(&key),
+            };
+            return Err(err);
+        }
+        Ok(previous)
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Check the keys, the number of keys and the serialized size.
+    pub fn check_limits(&self) -> Result<(), MetadataError> {
+        for key in self.0.keys() {
+            check_key(key)?;
+        }
+        self.check()
+    }
+
+    /// Check the number of keys and the serialized size.
+    fn check(&self) -> Result<(), MetadataError> {
+        if self.0.len() > Self::MAX_KEYS {
+            return Err(MetadataError::TooManyKeys(self.0.len()));
+        }
+        let size = serde_json::to_vec(&self.0)?.len();
+        if size > Self::MAX_SIZE {
+            return Err(MetadataError::TooLarge(size));
+        }
+        Ok(())
+    }
+}
+
+fn check_key(key: &str) -> Result<(), MetadataError> {
+    let valid = !key.is_empty()
+        && key.len() <= Metadata::MAX_KEY_LEN
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':'));
+    if valid {
+        Ok(())
+    } else {
+        Err(MetadataError::InvalidKey(key.into()))
+    }
+}
+
+impl TryFrom<BTreeMap<String, Value>> for Metadata {
+    type Error = MetadataError;
+
+    fn try_from(map: BTreeMap<String, Value>) -> Result<Self, Self::Error> {
+        let metadata = Self(map);
+        metadata.check_limits()?;
+        Ok(metadata)
+    }
+}
+
+impl From<Metadata> for BTreeMap<String, Value> {
+    fn from(metadata: Metadata) -> Self {
+        metadata.0
+    }
+}
+
+impl PartialOrd for Metadata {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Metadata {
+    fn cmp(&self, other: &Self) -> Ordering {
+        canonical_json(&self.0).cmp(canonical_json(&other.0))
+    }
+}
+
+impl Hash for Metadata {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for entry in canonical_json(&self.0) {
+            entry.hash(state);
+        }
+    }
+}
+
+impl JsonSchema for Metadata {
+    fn schema_name() -> String {
+        "Metadata".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = BTreeMap::<String, Value>::json_schema(gen).into_object();
+        schema.object().max_properties = Some(Self::MAX_KEYS as u32);
+        schema.into()
+    }
+}
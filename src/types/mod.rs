@@ -2,11 +2,40 @@
 //! client. Eventually this will include all such structs from the API side as
 //! well to ease developing thirdparty clients
 
+pub mod aggregate;
+pub mod analysis;
 pub mod auth;
+pub mod borrowed;
 pub mod common;
+pub mod feed;
+pub mod firewall;
 pub mod group;
+pub mod health;
 pub mod job;
+pub mod label;
+pub mod lenient;
+#[cfg(feature = "spdx")]
+pub mod license;
+pub mod lockfile;
+pub mod metadata;
+pub mod notification;
+pub mod organization;
 pub mod package;
+pub mod package_url;
+pub mod policy;
 pub mod preferences;
 pub mod project;
+pub mod remediation;
+pub mod report;
+pub mod schedule;
+pub mod ssvc;
+pub mod text;
+pub mod timestamp;
+pub mod token;
+pub mod trend;
+pub mod user;
 pub mod user_settings;
+pub mod v1;
+pub mod v2;
+pub mod view;
+pub mod webhook;
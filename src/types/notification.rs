@@ -0,0 +1,66 @@
+//! This module contains event payloads sent to notification integrations
+//! when a new advisory affects packages already used by projects.
+//!
+//! Unlike job completion events, these are raised without any new analysis
+//! being submitted.
+
+use std::collections::BTreeSet;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[allow(deprecated)]
+use super::package::{PackageSpecifier, RiskDomain, RiskLevel};
+use super::project::AffectedProject;
+use super::timestamp::Timestamp;
+
+/// Summary of a newly published advisory
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AdvisorySummary {
+    /// Identifier of the advisory, such as a GHSA id
+    pub id: String,
+    /// Other identifiers of the same advisory, such as CVE ids
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    pub title: String,
+    pub severity: RiskLevel,
+    pub domain: RiskDomain,
+    #[serde(alias = "publishedAt")]
+    pub published_at: Timestamp,
+    pub url: Option<String>,
+}
+
+/// Notification that a new advisory affects packages in one or more projects
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AdvisoryImpactNotification {
+    /// Unique id of this notification, for deduplicating deliveries
+    pub id: Uuid,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    pub advisory: AdvisorySummary,
+    #[serde(alias = "affectedProjects")]
+    pub affected_projects: Vec<AffectedProject>,
+}
+
+#[allow(deprecated)]
+impl AdvisoryImpactNotification {
+    pub fn severity(&self) -> RiskLevel {
+        self.advisory.severity
+    }
+
+    /// Every distinct affected package version across all projects.
+    pub fn affected_packages(&self) -> BTreeSet<&PackageSpecifier> {
+        self.affected_projects
+            .iter()
+            .flat_map(|project| &project.jobs)
+            .flat_map(|job| &job.packages)
+            .collect()
+    }
+}
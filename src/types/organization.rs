@@ -0,0 +1,140 @@
+//! This module contains types for organizations, which own groups and manage
+//! their members centrally.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::timestamp::Timestamp;
+
+/// The role of a user within an organization
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum OrgRole {
+    Owner,
+    Admin,
+    Member,
+}
+
+impl OrgRole {
+    /// The name used on the wire, such as `admin`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OrgRole::Owner => "owner",
+            OrgRole::Admin => "admin",
+            OrgRole::Member => "member",
+        }
+    }
+
+    /// Whether the role may invite and remove members.
+    pub fn can_manage_members(&self) -> bool {
+        matches!(self, OrgRole::Owner | OrgRole::Admin)
+    }
+}
+
+impl fmt::Display for OrgRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct Organization {
+    pub name: String,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    /// Names of the groups owned by the organization
+    #[serde(default)]
+    pub groups: Vec<String>,
+    /// The role of the requesting user, when listing their organizations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<OrgRole>,
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ListOrgsResponse {
+    pub organizations: Vec<Organization>,
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct OrgMember {
+    #[serde(alias = "userEmail")]
+    pub user_email: String,
+    #[serde(alias = "firstName")]
+    pub first_name: String,
+    #[serde(alias = "lastName")]
+    pub last_name: String,
+    pub role: OrgRole,
+    /// When the member accepted their invite, if known
+    #[serde(default, alias = "joinedAt")]
+    pub joined_at: Option<Timestamp>,
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ListOrgMembersResponse {
+    pub members: Vec<OrgMember>,
+}
+
+impl ListOrgMembersResponse {
+    /// Members with the given role.
+    pub fn with_role(&self, role: OrgRole) -> impl Iterator<Item = &OrgMember> {
+        self.members
+            .iter()
+            .filter(move |member| member.role == role)
+    }
+}
+
+/// Invite a user to an organization
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct OrgInviteRequest {
+    #[serde(alias = "userEmail")]
+    pub user_email: String,
+    /// The role the user will have once they accept
+    pub role: OrgRole,
+}
+
+impl OrgInviteRequest {
+    pub fn new(user_email: impl Into<String>, role: OrgRole) -> Self {
+        Self {
+            user_email: user_email.into(),
+            role,
+        }
+    }
+}
+
+/// A pending invite to an organization
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct OrgInviteResponse {
+    #[serde(alias = "inviteId")]
+    pub invite_id: Uuid,
+    #[serde(alias = "orgName")]
+    pub org_name: String,
+    #[serde(alias = "userEmail")]
+    pub user_email: String,
+    pub role: OrgRole,
+    #[serde(alias = "expiresAt")]
+    pub expires_at: Timestamp,
+}
+
+impl OrgInviteResponse {
+    /// Whether the invite can no longer be accepted at `now`.
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at <= now
+    }
+}
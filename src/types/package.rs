@@ -1,20 +1,39 @@
 //! Module containing data types reprsenting on-the-wire data for packages
+// The deprecated v1 package types are re-exported here and still appear in
+// `Package::dep_specs`, which the `JsonSchema` derive cannot allow per item
+#![allow(deprecated)]
 
-use std::collections::HashMap;
-use std::convert::TryFrom;
+use std::cmp::{Ordering, Reverse};
+use std::collections::BTreeMap;
+use std::convert::{Infallible, TryFrom};
 use std::fmt;
+use std::iter::Sum;
 use std::str::FromStr;
 
-use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize};
 
-use crate::types::common::Status;
+#[cfg(deny_unknown_fields)]
+use crate::types::common::reject_unknown;
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+use crate::types::common::UnknownFields;
+use crate::types::common::{deserialize_variant, Status};
+use crate::types::lenient;
+#[cfg(feature = "spdx")]
+use crate::types::license::{self, LicenseCategory, LicenseExpression};
+use crate::types::lockfile::{LockfilePath, LockfileRef};
+use crate::types::remediation::RemediationInfo;
+use crate::types::text::Text;
+use crate::types::timestamp::Timestamp;
+pub use crate::types::v1::{
+    HeuristicResult, PackageDescriptor, PackageDescriptorAndLockfile, PackageSpecifier,
+    PackageSpecifierAndLockfile, Vulnerability,
+};
+pub use crate::types::v2::{Ecosystem, PackageRef, PackageRefAndLockfile};
 
 /// Risk domains.
-#[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
-)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, JsonSchema)]
 #[repr(u8)]
 pub enum RiskDomain {
     /// One or more authors is a possible bad actor or other problems
@@ -25,7 +44,6 @@ pub enum RiskDomain {
     EngineeringRisk = 1,
     /// Malicious code such as malware or crypto miners
     #[serde(rename = "malicious_code")]
-    #[serde(alias = "malicious")]
     Malicious = 2,
     /// A code vulnerability such as use-after-free or other code smell
     #[serde(rename = "vulnerability")]
@@ -35,6 +53,23 @@ pub enum RiskDomain {
     LicenseRisk = 4,
 }
 
+impl RiskDomain {
+    const VARIANTS: &'static [(&'static str, Self)] = &[
+        ("author", Self::AuthorRisk),
+        ("engineering", Self::EngineeringRisk),
+        ("malicious_code", Self::Malicious),
+        ("malicious", Self::Malicious),
+        ("vulnerability", Self::Vulnerabilities),
+        ("license", Self::LicenseRisk),
+    ];
+}
+
+impl<'de> Deserialize<'de> for RiskDomain {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_variant(deserializer, "RiskDomain", Self::VARIANTS)
+    }
+}
+
 impl fmt::Display for RiskDomain {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         RiskType::from(*self).fmt(f)
@@ -42,9 +77,7 @@ impl fmt::Display for RiskDomain {
 }
 
 /// Issue severity.
-#[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
-)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub enum RiskLevel {
     /// Informational, no action needs to be taken.
@@ -60,6 +93,22 @@ pub enum RiskLevel {
     Critical,
 }
 
+impl RiskLevel {
+    const VARIANTS: &'static [(&'static str, Self)] = &[
+        ("info", Self::Info),
+        ("low", Self::Low),
+        ("medium", Self::Medium),
+        ("high", Self::High),
+        ("critical", Self::Critical),
+    ];
+}
+
+impl<'de> Deserialize<'de> for RiskLevel {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_variant(deserializer, "RiskLevel", Self::VARIANTS)
+    }
+}
+
 impl RiskLevel {
     pub fn score(&self) -> f32 {
         match self {
@@ -70,6 +119,34 @@ impl RiskLevel {
             RiskLevel::Critical => 0.1,
         }
     }
+
+    /// The worst of some levels, or `None` if there are none.
+    pub fn max_of<I>(levels: I) -> Option<RiskLevel>
+    where
+        I: IntoIterator,
+        I::Item: Into<RiskLevel>,
+    {
+        levels.into_iter().map(Into::into).max()
+    }
+}
+
+impl From<&RiskLevel> for RiskLevel {
+    fn from(level: &RiskLevel) -> Self {
+        *level
+    }
+}
+
+/// Folds to the worst level, or [`RiskLevel::Info`] if there are none.
+impl Sum for RiskLevel {
+    fn sum<I: Iterator<Item = RiskLevel>>(levels: I) -> Self {
+        RiskLevel::max_of(levels).unwrap_or(RiskLevel::Info)
+    }
+}
+
+impl<'a> Sum<&'a RiskLevel> for RiskLevel {
+    fn sum<I: Iterator<Item = &'a RiskLevel>>(levels: I) -> Self {
+        levels.copied().sum()
+    }
 }
 
 impl fmt::Display for RiskLevel {
@@ -80,9 +157,7 @@ impl fmt::Display for RiskLevel {
 }
 
 /// The package ecosystem
-#[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
-)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PackageType {
     Npm,
@@ -92,6 +167,28 @@ pub enum PackageType {
     Nuget,
     Cargo,
     Golang,
+    Composer,
+    Conan,
+}
+
+impl PackageType {
+    pub(crate) const VARIANTS: &'static [(&'static str, Self)] = &[
+        ("npm", Self::Npm),
+        ("pypi", Self::PyPi),
+        ("maven", Self::Maven),
+        ("rubygems", Self::RubyGems),
+        ("nuget", Self::Nuget),
+        ("cargo", Self::Cargo),
+        ("golang", Self::Golang),
+        ("composer", Self::Composer),
+        ("conan", Self::Conan),
+    ];
+}
+
+impl<'de> Deserialize<'de> for PackageType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_variant(deserializer, "PackageType", Self::VARIANTS)
+    }
 }
 
 impl PackageType {
@@ -104,6 +201,18 @@ impl PackageType {
             PackageType::Nuget => ".NET",
             PackageType::Cargo => "Rust",
             PackageType::Golang => "Golang",
+            PackageType::Composer => "PHP",
+            PackageType::Conan => "C++",
+        }
+    }
+
+    /// Separator between the namespace and the name of a package, for
+    /// ecosystems which have namespaces.
+    pub fn namespace_separator(&self) -> Option<char> {
+        match self {
+            PackageType::Npm | PackageType::Golang | PackageType::Composer => Some('/'),
+            PackageType::Maven => Some(':'),
+            _ => None,
         }
     }
 }
@@ -120,6 +229,8 @@ impl FromStr for PackageType {
             "nuget" | "dotnet" => Ok(Self::Nuget),
             "cargo" => Ok(Self::Cargo),
             "golang" => Ok(Self::Golang),
+            "php" | "packagist" | "composer" => Ok(Self::Composer),
+            "conan" | "cpp" | "c++" => Ok(Self::Conan),
             _ => Err(()),
         }
     }
@@ -132,9 +243,35 @@ impl fmt::Display for PackageType {
     }
 }
 
-impl From<PackageType> for purl::PackageType {
-    fn from(package_type: PackageType) -> purl::PackageType {
-        match package_type {
+impl PackageType {
+    /// The type of this ecosystem in a package URL.
+    ///
+    /// Unlike the conversion to [`purl::PackageType`], this covers every
+    /// ecosystem, including those the `purl` crate does not know about.
+    pub fn purl_type(&self) -> &'static str {
+        match self {
+            PackageType::Npm => "npm",
+            PackageType::PyPi => "pypi",
+            PackageType::Maven => "maven",
+            PackageType::RubyGems => "gem",
+            PackageType::Nuget => "nuget",
+            PackageType::Cargo => "cargo",
+            PackageType::Golang => "golang",
+            PackageType::Composer => "composer",
+            PackageType::Conan => "conan",
+        }
+    }
+}
+
+/// Fails for ecosystems the `purl` crate does not know about; see
+/// [`PackageType::purl_type`] for a type string covering every ecosystem.
+impl TryFrom<PackageType> for purl::PackageType {
+    type Error = purl::UnsupportedPackageType;
+
+    fn try_from(
+        package_type: PackageType,
+    ) -> Result<purl::PackageType, purl::UnsupportedPackageType> {
+        Ok(match package_type {
             PackageType::Npm => purl::PackageType::Npm,
             PackageType::PyPi => purl::PackageType::PyPI,
             PackageType::Maven => purl::PackageType::Maven,
@@ -142,7 +279,8 @@ impl From<PackageType> for purl::PackageType {
             PackageType::Nuget => purl::PackageType::NuGet,
             PackageType::Cargo => purl::PackageType::Cargo,
             PackageType::Golang => purl::PackageType::Golang,
-        }
+            PackageType::Composer | PackageType::Conan => return Err(purl::UnsupportedPackageType),
+        })
     }
 }
 
@@ -166,71 +304,94 @@ impl TryFrom<purl::PackageType> for PackageType {
 }
 
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct ScoredVersion {
     pub version: String,
+    #[serde(alias = "totalRiskScore")]
     pub total_risk_score: Option<f32>,
 }
 
+/// Risk scores by domain.
 #[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+    PartialEq, PartialOrd, Copy, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
 )]
-pub struct PackageSpecifier {
-    #[serde(alias = "type")]
-    pub registry: String,
-    pub name: String,
-    pub version: String,
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct RiskScores {
+    pub total: f32,
+    pub vulnerability: f32,
+    #[serde(
+        rename = "malicious_code",
+        alias = "malicious",
+        alias = "maliciousCode"
+    )]
+    pub malicious: f32,
+    pub author: f32,
+    pub engineering: f32,
+    pub license: f32,
 }
 
-// TODO Once we unify PackageDescriptor and PackageSpecifier, this goes away
-impl From<&PackageDescriptor> for PackageSpecifier {
-    fn from(descriptor: &PackageDescriptor) -> Self {
-        Self {
-            registry: descriptor.package_type.to_string(),
-            name: descriptor.name.clone(),
-            version: descriptor.version.clone(),
-        }
-    }
-}
+impl RiskScores {
+    /// Combine the domain scores into a total, weighting each domain.
+    ///
+    /// Returns `1.0`, the best possible score, when all weights are zero.
+    pub fn weighted_total(&self, weights: &RiskWeights) -> f32 {
+        let weighted = [
+            (self.vulnerability, weights.vulnerability),
+            (self.malicious, weights.malicious),
+            (self.author, weights.author),
+            (self.engineering, weights.engineering),
+            (self.license, weights.license),
+        ];
 
-impl TryFrom<PackageSpecifier> for PackageDescriptor {
-    type Error = String;
+        let total_weight: f32 = weighted.iter().map(|(_, weight)| weight).sum();
+        if total_weight <= 0. {
+            return 1.;
+        }
 
-    fn try_from(value: PackageSpecifier) -> Result<Self, Self::Error> {
-        let PackageSpecifier {
-            registry,
-            name,
-            version,
-        } = value;
-        let package_type = PackageType::from_str(&registry)
-            .map_err(|()| format!("Failed to convert registry {registry} to package type"))?;
-        Ok(PackageDescriptor {
-            name,
-            version,
-            package_type,
-        })
+        weighted
+            .iter()
+            .map(|(score, weight)| score * weight)
+            .sum::<f32>()
+            / total_weight
     }
 }
 
-/// Risk scores by domain.
-#[derive(
-    PartialEq, PartialOrd, Copy, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
-)]
-pub struct RiskScores {
-    pub total: f32,
+/// Relative importance of each risk domain when computing a total score.
+#[derive(PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct RiskWeights {
     pub vulnerability: f32,
-    #[serde(rename = "malicious_code")]
-    #[serde(alias = "malicious")]
+    #[serde(
+        rename = "malicious_code",
+        alias = "malicious",
+        alias = "maliciousCode"
+    )]
     pub malicious: f32,
     pub author: f32,
     pub engineering: f32,
     pub license: f32,
 }
 
+impl Default for RiskWeights {
+    /// All domains weighted equally.
+    fn default() -> Self {
+        Self {
+            vulnerability: 1.,
+            malicious: 1.,
+            author: 1.,
+            engineering: 1.,
+            license: 1.,
+        }
+    }
+}
+
 /// Change in score over time.
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct ScoreDynamicsPoint {
-    pub date_time: DateTime<Utc>,
+    #[serde(alias = "date_time")]
+    pub date_time: Timestamp,
     pub score: f32,
     pub label: String,
 }
@@ -239,23 +400,718 @@ pub struct ScoreDynamicsPoint {
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct Issue {
     pub tag: Option<String>,
     pub id: Option<String>,
     pub title: String,
+    /// Markdown description of the issue
     pub description: String,
+    /// Structured form of `description`, when the API provides one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<Box<IssueBody>>,
     #[serde(alias = "risk_level")]
     pub severity: RiskLevel,
     #[serde(alias = "risk_domain")]
     pub domain: RiskDomain,
+    /// How far exploitation has progressed, for vulnerabilities
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "exploitMaturity"
+    )]
+    pub exploit_maturity: Option<ExploitMaturity>,
     #[serde(skip)]
     pub rule: Option<String>,
+    /// Locations in the package's code which triggered this issue
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence: Vec<IssueEvidence>,
+    /// Links to further information, such as advisories or write-ups
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    /// Tickets in external systems tracking this issue
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "externalReferences"
+    )]
+    pub external_references: Vec<ExternalReference>,
+    /// How to fix the issue, when the API knows
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "remediationDetails"
+    )]
+    pub remediation_details: Option<Box<RemediationInfo>>,
+    /// The chain of dependencies through which the project depends on the
+    /// affected package, from a top level dependency down to the package
+    #[serde(default, skip_serializing_if = "Vec::is_empty", alias = "introducedBy")]
+    pub introduced_by: Vec<PackageRef>,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+fn severity_key(issue: &Issue) -> (Reverse<RiskLevel>, RiskDomain) {
+    (Reverse(issue.severity), issue.domain)
+}
+
+/// Sort issues for presentation, most severe first and then by domain.
+///
+/// The sort is stable, so issues of the same severity and domain keep their
+/// order.
+pub trait SortBySeverity {
+    fn sort_by_severity(&mut self);
+}
+
+impl SortBySeverity for [Issue] {
+    fn sort_by_severity(&mut self) {
+        self.sort_by_key(severity_key);
+    }
+}
+
+impl SortBySeverity for [IssueStatus] {
+    fn sort_by_severity(&mut self) {
+        self.sort_by_key(|status| severity_key(&status.issue));
+    }
+}
+
+/// How far exploitation of a vulnerability has progressed.
+///
+/// Ordered from least to most mature.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ExploitMaturity {
+    /// No exploit is known
+    Unproven,
+    /// Exploit code demonstrating the vulnerability exists
+    #[serde(alias = "poc")]
+    ProofOfConcept,
+    /// A working exploit is available
+    Functional,
+    /// The vulnerability is exploited in the wild
+    #[serde(alias = "itw", alias = "in_the_wild")]
+    Weaponized,
+}
+
+impl ExploitMaturity {
+    /// Whether an exploit is known to exist.
+    pub fn has_exploit(&self) -> bool {
+        *self > ExploitMaturity::Unproven
+    }
+
+    /// Whether the vulnerability is exploited in the wild.
+    pub fn is_exploited(&self) -> bool {
+        *self == ExploitMaturity::Weaponized
+    }
+}
+
+/// What a reference links to
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferenceKind {
+    /// A security advisory, such as a GHSA or CVE record
+    Advisory,
+    /// The commit or release fixing the issue
+    Fix,
+    /// A proof of concept or exploit
+    Exploit,
+    /// An article or blog post about the issue
+    Writeup,
+    /// The vendor's or maintainer's own statement
+    Vendor,
+    /// Unclassified, including kinds unknown to this version of the crate
+    #[serde(other)]
+    Other,
+}
+
+/// A link to further information about an issue.
+///
+/// Older API versions send plain URLs, which are references of kind
+/// [`ReferenceKind::Other`]. Such references are also serialized as plain
+/// URLs when they have no title, so the wire format is unchanged for them.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub struct Reference {
+    /// The link as received. It is not parsed into a `url::Url`, so that a
+    /// malformed link from an upstream advisory does not make the whole issue
+    /// fail to deserialize.
+    pub url: String,
+    pub kind: ReferenceKind,
+    /// The title of the linked page, such as `GHSA-35jh-r3h4-6jhm`
+    pub title: Option<String>,
+}
+
+impl Reference {
+    pub fn new(url: impl Into<String>, kind: ReferenceKind) -> Self {
+        Self {
+            url: url.into(),
+            kind,
+            title: None,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+impl From<String> for Reference {
+    fn from(url: String) -> Self {
+        Self::new(url, ReferenceKind::Other)
+    }
+}
+
+impl From<&str> for Reference {
+    fn from(url: &str) -> Self {
+        Self::new(url, ReferenceKind::Other)
+    }
+}
+
+/// The object form of a [`Reference`].
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "Reference")]
+struct ReferenceObject<T> {
+    url: T,
+    #[serde(default = "ReferenceKind::other")]
+    kind: ReferenceKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<T>,
+}
+
+impl ReferenceKind {
+    fn other() -> Self {
+        ReferenceKind::Other
+    }
+}
+
+impl Serialize for Reference {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match (self.kind, &self.title) {
+            (ReferenceKind::Other, None) => serializer.serialize_str(&self.url),
+            (kind, title) => ReferenceObject {
+                url: &self.url,
+                kind,
+                title: title.as_ref(),
+            }
+            .serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Reference {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ReferenceVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ReferenceVisitor {
+            type Value = Reference;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a URL or an object with a URL and kind")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, url: &str) -> Result<Reference, E> {
+                Ok(url.into())
+            }
+
+            fn visit_string<E: serde::de::Error>(self, url: String) -> Result<Reference, E> {
+                Ok(url.into())
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                map: A,
+            ) -> Result<Reference, A::Error> {
+                let deserializer = serde::de::value::MapAccessDeserializer::new(map);
+                let object = ReferenceObject::<String>::deserialize(deserializer)?;
+                Ok(Reference {
+                    url: object.url,
+                    kind: object.kind,
+                    title: object.title,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ReferenceVisitor)
+    }
+}
+
+impl JsonSchema for Reference {
+    fn schema_name() -> String {
+        "Reference".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+                any_of: Some(vec![
+                    gen.subschema_for::<String>(),
+                    gen.subschema_for::<ReferenceObject<String>>(),
+                ]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+/// The content of an issue description, split into its parts so they don't
+/// have to be parsed out of the markdown.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+pub struct IssueBody {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sections: Vec<DescriptionSection>,
+    /// Links cited by the description
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    /// Packages and versions the issue applies to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub affected: Vec<AffectedArtifact>,
+}
+
+impl IssueBody {
+    /// The first section of a kind, if any.
+    pub fn section(&self, kind: SectionKind) -> Option<&DescriptionSection> {
+        self.sections.iter().find(|section| section.kind == kind)
+    }
+
+    /// The sections as markdown, each headed by its heading if it has one.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for section in &self.sections {
+            if !markdown.is_empty() {
+                markdown.push_str("\n\n");
+            }
+            if let Some(heading) = &section.heading {
+                markdown.push_str(&format!("## {heading}\n\n"));
+            }
+            markdown.push_str(section.markdown.trim_end());
+        }
+        markdown
+    }
+}
+
+/// What a part of an issue description is about
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionKind {
+    Summary,
+    Details,
+    Impact,
+    Remediation,
+    /// Any other section, including kinds unknown to this version of the
+    /// crate
+    #[serde(other)]
+    Other,
+}
+
+/// A part of an issue description
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct DescriptionSection {
+    pub kind: SectionKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub heading: Option<String>,
+    pub markdown: String,
+}
+
+/// A package affected by an issue
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct AffectedArtifact {
+    pub name: String,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "packageType"
+    )]
+    pub package_type: Option<PackageType>,
+    /// Affected version ranges in the ecosystem's syntax, such as
+    /// `>=4.0.0 <4.17.21`
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "affectedRanges"
+    )]
+    pub affected_ranges: Vec<String>,
+    /// Versions in which the issue is fixed
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        alias = "fixedVersions"
+    )]
+    pub fixed_versions: Vec<String>,
+}
+
+/// A location in a package's code supporting an issue.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct IssueEvidence {
+    /// Path of the file within the package
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lines: Option<LineRange>,
+    /// The offending code
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+    /// Identifier of the rule which matched
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "ruleId")]
+    pub rule_id: Option<String>,
+}
+
+/// An inclusive range of 1-based line numbers.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct LineRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl LineRange {
+    pub fn line(line: u32) -> Self {
+        Self {
+            start: line,
+            end: line,
+        }
+    }
+
+    pub fn contains(&self, line: u32) -> bool {
+        (self.start..=self.end).contains(&line)
+    }
+}
+
+/// Synchronization state between an issue and its external ticket.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SyncState {
+    /// The ticket is being created or updated
+    Pending,
+    /// The ticket reflects the current state of the issue
+    Synced,
+    /// The issue changed since the ticket was last updated
+    OutOfSync,
+    /// The last attempt to update the ticket failed
+    Failed,
+}
+
+/// A ticket mirroring an issue in an external system, such as Jira or
+/// ServiceNow.
+///
+/// The pair of `system` and `id` identifies the ticket, so integrations can
+/// check it before creating a duplicate.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ExternalReference {
+    /// Name of the external system, such as `jira`
+    pub system: String,
+    /// Identifier of the ticket within the system, such as `SEC-123`
+    pub id: String,
+    pub url: Option<String>,
+    #[serde(alias = "syncState")]
+    pub sync_state: SyncState,
+    #[serde(alias = "lastSyncedAt")]
+    pub last_synced_at: Option<Timestamp>,
+}
+
+impl Issue {
+    /// An issue with an empty description and no optional details.
+    pub fn new(title: impl Into<String>, severity: RiskLevel, domain: RiskDomain) -> Self {
+        Self {
+            tag: None,
+            id: None,
+            title: title.into(),
+            description: String::new(),
+            body: None,
+            severity,
+            domain,
+            exploit_maturity: None,
+            rule: None,
+            evidence: Vec::new(),
+            references: Vec::new(),
+            external_references: Vec::new(),
+            remediation_details: None,
+            introduced_by: Vec::new(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        }
+    }
+
+    pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
+        self.tag = Some(tag.into());
+        self
+    }
+
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn with_body(mut self, body: IssueBody) -> Self {
+        self.body = Some(Box::new(body));
+        self
+    }
+
+    pub fn with_exploit_maturity(mut self, exploit_maturity: ExploitMaturity) -> Self {
+        self.exploit_maturity = Some(exploit_maturity);
+        self
+    }
+
+    pub fn with_evidence(mut self, evidence: Vec<IssueEvidence>) -> Self {
+        self.evidence = evidence;
+        self
+    }
+
+    pub fn with_references(mut self, references: Vec<Reference>) -> Self {
+        self.references = references;
+        self
+    }
+
+    pub fn with_external_references(mut self, external_references: Vec<ExternalReference>) -> Self {
+        self.external_references = external_references;
+        self
+    }
+
+    pub fn with_remediation_details(mut self, remediation_details: RemediationInfo) -> Self {
+        self.remediation_details = Some(Box::new(remediation_details));
+        self
+    }
+
+    pub fn with_introduced_by(mut self, introduced_by: Vec<PackageRef>) -> Self {
+        self.introduced_by = introduced_by;
+        self
+    }
+
+    /// Compare by severity, most severe first, and then by domain.
+    ///
+    /// The derived `Ord` compares every field in declaration order instead,
+    /// which suits sets and maps but not presentation.
+    pub fn cmp_severity(&self, other: &Issue) -> Ordering {
+        severity_key(self).cmp(&severity_key(other))
+    }
+
+    /// The references of a kind.
+    pub fn references_of(&self, kind: ReferenceKind) -> impl Iterator<Item = &Reference> {
+        self.references
+            .iter()
+            .filter(move |reference| reference.kind == kind)
+    }
+
+    /// The ticket tracking this issue in the given system, if any.
+    pub fn external_reference(&self, system: &str) -> Option<&ExternalReference> {
+        self.external_references
+            .iter()
+            .find(|reference| reference.system == system)
+    }
+
+    /// The tag, parsed into its family.
+    pub fn parsed_tag(&self) -> Option<IssueTag> {
+        self.tag.as_deref().map(IssueTag::parse)
+    }
+
+    /// The names along `introduced_by`, such as `express → qs`, if known.
+    pub fn introduced_via(&self) -> Option<String> {
+        if self.introduced_by.is_empty() {
+            return None;
+        }
+        let names: Vec<_> = self
+            .introduced_by
+            .iter()
+            .map(|package| package.name.as_str())
+            .collect();
+        Some(names.join(" → "))
+    }
+}
+
+/// Prefixes of vulnerability ids from advisory databases other than CVE and
+/// GitHub.
+const VULNERABILITY_PREFIXES: &[&str] = &["GO-", "GSD-", "OSV-", "PYSEC-", "RUSTSEC-", "SNYK-"];
+
+/// The prefix of license tags.
+const LICENSE_PREFIX: &str = "license:";
+
+/// An issue tag, classified by the family it belongs to.
+///
+/// Serialized as the plain tag, so tags outside the known families round-trip
+/// unchanged.
+///
+/// ```
+/// use phylum_types::types::package::IssueTag;
+///
+/// assert!(IssueTag::parse("HM0012").is_heuristic());
+/// assert!(IssueTag::parse("CVE-2022-24999").is_vulnerability());
+/// assert_eq!(IssueTag::parse("license:GPL-3.0-only").license(), Some("GPL-3.0-only"));
+/// assert_eq!(IssueTag::parse("custom"), IssueTag::Unknown("custom".into()));
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug)]
+pub enum IssueTag {
+    /// A heuristic code, such as `HM0012`
+    Heuristic(String),
+    /// A vulnerability id, such as `CVE-2022-24999` or `GHSA-29mw-wpgm-hmr9`
+    Vulnerability(String),
+    /// A license issue, such as `license:GPL-3.0-only`, holding the SPDX id
+    License(String),
+    /// Any other tag
+    Unknown(String),
+}
+
+impl IssueTag {
+    pub fn parse(tag: &str) -> Self {
+        if is_heuristic_code(tag) {
+            Self::Heuristic(tag.into())
+        } else if is_vulnerability_id(tag) {
+            Self::Vulnerability(tag.into())
+        } else {
+            match tag.strip_prefix(LICENSE_PREFIX) {
+                Some(license) if !license.is_empty() => Self::License(license.into()),
+                _ => Self::Unknown(tag.into()),
+            }
+        }
+    }
+
+    pub fn is_heuristic(&self) -> bool {
+        matches!(self, Self::Heuristic(_))
+    }
+
+    pub fn is_vulnerability(&self) -> bool {
+        matches!(self, Self::Vulnerability(_))
+    }
+
+    pub fn is_license(&self) -> bool {
+        matches!(self, Self::License(_))
+    }
+
+    /// The letter naming the family of a heuristic, such as `M` for `HM0012`.
+    pub fn heuristic_family(&self) -> Option<char> {
+        match self {
+            Self::Heuristic(code) => code.chars().nth(1),
+            _ => None,
+        }
+    }
+
+    /// The SPDX id of a license tag.
+    pub fn license(&self) -> Option<&str> {
+        match self {
+            Self::License(license) => Some(license),
+            _ => None,
+        }
+    }
+}
+
+/// `H`, an uppercase letter and four digits.
+fn is_heuristic_code(tag: &str) -> bool {
+    let bytes = tag.as_bytes();
+    bytes.len() == 6
+        && bytes[0] == b'H'
+        && bytes[1].is_ascii_uppercase()
+        && bytes[2..].iter().all(u8::is_ascii_digit)
+}
+
+fn is_vulnerability_id(tag: &str) -> bool {
+    if let Some(id) = tag.strip_prefix("CVE-") {
+        let mut parts = id.splitn(2, '-');
+        let year = parts.next().unwrap_or_default();
+        let number = parts.next().unwrap_or_default();
+        return year.len() == 4
+            && number.len() >= 4
+            && year
+                .bytes()
+                .chain(number.bytes())
+                .all(|b| b.is_ascii_digit());
+    }
+    if let Some(id) = tag.strip_prefix("GHSA-") {
+        let groups: Vec<_> = id.split('-').collect();
+        return groups.len() == 3
+            && groups
+                .iter()
+                .all(|group| group.len() == 4 && group.bytes().all(|b| b.is_ascii_alphanumeric()));
+    }
+    VULNERABILITY_PREFIXES
+        .iter()
+        .any(|prefix| tag.len() > prefix.len() && tag.starts_with(prefix))
+}
+
+impl FromStr for IssueTag {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
+impl fmt::Display for IssueTag {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::License(license) => write!(f, "{LICENSE_PREFIX}{license}"),
+            Self::Heuristic(tag) | Self::Vulnerability(tag) | Self::Unknown(tag) => {
+                f.write_str(tag)
+            }
+        }
+    }
+}
+
+impl Serialize for IssueTag {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for IssueTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|tag| Self::parse(&tag))
+    }
+}
+
+impl JsonSchema for IssueTag {
+    fn schema_name() -> String {
+        "IssueTag".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// The severity, domain and title, followed by the id or tag if there is one,
+/// such as `[high VLN] Command injection in lodash (CVE-2021-23337)`.
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} {}] {}", self.severity, self.domain, self.title)?;
+        match self.id.as_ref().or(self.tag.as_ref()) {
+            Some(id) => write!(f, " ({id})"),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Issue description.
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct IssuesListItem {
+    #[serde(alias = "risk_type")]
     pub risk_type: RiskType,
     pub score: f32,
     pub impact: RiskLevel,
@@ -312,30 +1168,141 @@ impl fmt::Display for RiskType {
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
 #[serde(rename_all = "camelCase")]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct Author {
     pub name: String,
+    #[serde(alias = "avatar_url")]
     pub avatar_url: String,
     pub email: String,
+    #[serde(alias = "profile_url")]
     pub profile_url: String,
 }
 
 /// Responsiveness of developers
 #[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct DeveloperResponsiveness {
-    pub open_issue_count: Option<usize>,
-    pub total_issue_count: Option<usize>,
-    pub open_issue_avg_duration: Option<u32>,
-    pub open_pull_request_count: Option<usize>,
-    pub total_pull_request_count: Option<usize>,
-    pub open_pull_request_avg_duration: Option<u32>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "openIssueCount"
+    )]
+    pub open_issue_count: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "totalIssueCount"
+    )]
+    pub total_issue_count: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "openIssueAvgDuration"
+    )]
+    pub open_issue_avg_duration: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "openPullRequestCount"
+    )]
+    pub open_pull_request_count: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "totalPullRequestCount"
+    )]
+    pub total_pull_request_count: Option<u64>,
+    #[serde(
+        default,
+        deserialize_with = "lenient::option_u64",
+        alias = "openPullRequestAvgDuration"
+    )]
+    pub open_pull_request_avg_duration: Option<u64>,
+}
+
+impl DeveloperResponsiveness {
+    /// The share of issues which have been closed, from 0 to 1.
+    ///
+    /// `None` when either count is missing or there are no issues.
+    pub fn issue_close_rate(&self) -> Option<f64> {
+        resolved_rate(self.open_issue_count, self.total_issue_count)
+    }
+
+    /// The share of pull requests which are no longer open, from 0 to 1.
+    ///
+    /// Closed pull requests are not counted separately from merged ones, so
+    /// they count as merged. `None` when either count is missing or there are
+    /// no pull requests.
+    pub fn pr_merge_rate(&self) -> Option<f64> {
+        resolved_rate(self.open_pull_request_count, self.total_pull_request_count)
+    }
+
+    /// The mean of the known issue close and pull request merge rates.
+    pub fn resolution_rate(&self) -> Option<f64> {
+        let rates: Vec<f64> = self
+            .issue_close_rate()
+            .into_iter()
+            .chain(self.pr_merge_rate())
+            .collect();
+        if rates.is_empty() {
+            return None;
+        }
+        Some(rates.iter().sum::<f64>() / rates.len() as f64)
+    }
+
+    /// How concerning the responsiveness is, graded by
+    /// [`Self::resolution_rate`]:
+    ///
+    /// | Resolved | Level    |
+    /// |----------|----------|
+    /// | ≥ 80%    | Info     |
+    /// | ≥ 60%    | Low      |
+    /// | ≥ 40%    | Medium   |
+    /// | ≥ 20%    | High     |
+    /// | < 20%    | Critical |
+    ///
+    /// Packages without issue or pull request counts grade as `Info`, as
+    /// there is nothing to go on.
+    pub fn responsiveness_grade(&self) -> RiskLevel {
+        match self.resolution_rate() {
+            None => RiskLevel::Info,
+            Some(rate) if rate >= 0.8 => RiskLevel::Info,
+            Some(rate) if rate >= 0.6 => RiskLevel::Low,
+            Some(rate) if rate >= 0.4 => RiskLevel::Medium,
+            Some(rate) if rate >= 0.2 => RiskLevel::High,
+            Some(_) => RiskLevel::Critical,
+        }
+    }
+}
+
+/// The share of `total` which is not `open`; counts of open items larger
+/// than the total are clamped.
+fn resolved_rate(open: Option<u64>, total: Option<u64>) -> Option<f64> {
+    match (open, total) {
+        (Some(open), Some(total)) if total > 0 => {
+            Some(total.saturating_sub(open) as f64 / total as f64)
+        }
+        _ => None,
+    }
 }
 
 #[derive(Deserialize, Serialize, PartialEq, Debug)]
 #[serde(tag = "status", content = "data")]
 pub enum PackageSubmitResponse {
-    AlreadyProcessed(Package),
+    AlreadyProcessed(Box<Package>),
     AlreadySubmitted,
     New,
 }
@@ -343,131 +1310,287 @@ pub enum PackageSubmitResponse {
 #[derive(PartialEq, PartialOrd, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct Package {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub purl: Option<String>,
     pub id: String,
     pub name: String,
     pub version: String,
-    pub registry: String,
+    pub registry: Text,
+    #[serde(alias = "published_date")]
     pub published_date: Option<String>,
+    #[serde(alias = "latest_version")]
     pub latest_version: Option<String>,
     pub versions: Vec<ScoredVersion>,
     pub description: Option<String>,
-    pub license: Option<String>,
+    pub license: Option<Text>,
+    #[serde(alias = "dep_specs")]
     pub dep_specs: Vec<PackageSpecifier>,
     pub dependencies: Option<Vec<Package>>,
-    pub download_count: u32,
-    pub risk_scores: RiskScores,
+    #[serde(deserialize_with = "lenient::u64", alias = "download_count")]
+    pub download_count: u64,
+    /// Scores of the package; absent until it has been analyzed, as opposed
+    /// to scores of zero
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "risk_scores"
+    )]
+    pub risk_scores: Option<RiskScores>,
+    #[serde(alias = "total_risk_score_dynamics")]
     pub total_risk_score_dynamics: Option<Vec<ScoreDynamicsPoint>>,
+    #[serde(alias = "issues_details")]
     pub issues_details: Vec<Issue>,
     pub issues: Vec<IssuesListItem>,
     pub authors: Vec<Author>,
+    #[serde(alias = "developer_responsiveness")]
     pub developer_responsiveness: Option<DeveloperResponsiveness>,
-    pub complete: bool,
+    /// Whether analysis of the package has finished; absent when unknown
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complete: Option<bool>,
+    #[serde(alias = "release_data")]
     pub release_data: Option<PackageReleaseData>,
+    #[serde(alias = "repo_url")]
     pub repo_url: Option<String>,
+    #[serde(alias = "maintainers_recently_changed")]
     pub maintainers_recently_changed: Option<bool>,
+    #[serde(alias = "is_abandonware")]
     pub is_abandonware: Option<bool>,
+    #[serde(alias = "trust_signals")]
+    pub trust_signals: Vec<TrustSignal>,
+    pub stewardship: Option<Stewardship>,
+    #[serde(alias = "disclosure_policy")]
+    pub disclosure_policy: Option<DisclosurePolicy>,
+    /// Which version of the analysis engine produced this result
+    pub provenance: Option<AnalysisProvenance>,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
-#[derive(PartialEq, PartialOrd, Clone, Debug, Default, Eq, Serialize, Deserialize, JsonSchema)]
-#[serde(rename_all = "camelCase")]
-#[serde(default)]
-pub struct PackageReleaseData {
-    pub first_release_date: String,
-    pub last_release_date: String,
+impl Package {
+    /// Whether analysis of the package is known to have finished.
+    pub fn is_complete(&self) -> bool {
+        self.complete.unwrap_or(false)
+    }
+
+    /// The license as an SPDX expression, if the package has one.
+    #[cfg(feature = "spdx")]
+    pub fn parsed_license(&self) -> Option<Result<LicenseExpression, license::ParseError>> {
+        self.license
+            .as_ref()
+            .map(|license| LicenseExpression::parse(license))
+    }
+
+    /// The category of the license; unknown if missing or not valid SPDX.
+    #[cfg(feature = "spdx")]
+    pub fn license_category(&self) -> LicenseCategory {
+        match self.parsed_license() {
+            Some(Ok(license)) => license.category(),
+            _ => LicenseCategory::Unknown,
+        }
+    }
+
+    /// A one line summary, such as `npm/left-pad@1.3.0 [score 0.42, 3 issues]`.
+    pub fn summary(&self) -> String {
+        let score = self.risk_scores.as_ref().map(|scores| scores.total.into());
+        let issues = count(self.issues.len(), "issue", "issues");
+        format!("{self} [{}]", summarize(score, Some(issues)))
+    }
 }
 
-// v--- TODO: OLD PACKAGE RESPONSES ---v //
+impl fmt::Display for Package {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}@{}", self.registry, self.name, self.version)
+    }
+}
 
-/// The results of an individual heuristic run
-#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
-pub struct HeuristicResult {
-    /// The risk domain
-    pub domain: RiskDomain,
-    /// The score
-    pub score: f64,
-    /// The risk level bucket it falls into
-    pub risk_level: RiskLevel,
+/// The bracketed part of package summaries.
+fn summarize(score: Option<f64>, count: Option<String>) -> String {
+    let score = match score {
+        Some(score) => format!("score {score:.2}"),
+        None => "unscored".into(),
+    };
+    match count {
+        Some(count) => format!("{score}, {count}"),
+        None => score,
+    }
 }
 
-/// A vulnerability
-#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
-pub struct Vulnerability {
-    /// If this vulnerability falls into one or more known CVEs
-    pub cve: Vec<String>,
-    /// Severity of the vulnerability
-    #[serde(rename = "severity")]
-    pub base_severity: f32,
-    /// What risk level bucket it falls into
-    pub risk_level: RiskLevel,
-    /// Title of the vulnerability
-    pub title: String,
-    /// A more in depth description
-    pub description: String,
-    /// Remediation information if known
-    pub remediation: String,
+/// A count followed by the singular or plural noun, such as `1 issue`.
+fn count(count: usize, singular: &str, plural: &str) -> String {
+    match count {
+        1 => format!("1 {singular}"),
+        count => format!("{count} {plural}"),
+    }
 }
 
-/// Describes a package in the system
+/// Positive signals about the trustworthiness of a package.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum TrustSignal {
+    /// Published by an account verified by the registry
+    VerifiedPublisher,
+    /// The release can be rebuilt from source with identical output
+    ReproducibleBuild,
+    /// Releases are cryptographically signed or have build provenance
+    SignedReleases,
+    /// All maintainers are required to use two-factor authentication
+    TwoFactorMaintainers,
+}
+
+/// How decisions about a project are made.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum GovernanceModel {
+    /// A single maintainer makes all decisions
+    SingleMaintainer,
+    /// A group of maintainers without formal structure
+    Community,
+    /// A company controls the project
+    Corporate,
+    /// A foundation, such as the Apache or Linux foundations, oversees the
+    /// project
+    Foundation,
+}
+
+/// A way to financially support a package.
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
-pub struct PackageDescriptor {
-    pub name: String,
-    pub version: String,
-    #[serde(rename = "type")]
-    #[serde(alias = "registry")]
-    pub package_type: PackageType,
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct FundingLink {
+    /// The funding platform, such as `github` or `open_collective`
+    pub platform: String,
+    pub url: String,
 }
 
-/// `PackageDescriptorAndLockfile` represents a parsed package
-/// (`package_descriptor`) and the optional path to its lockfile (`lockfile`).
+/// Who looks after a package and how it is supported.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct Stewardship {
+    /// Organization sponsoring development
+    #[serde(alias = "sponsoring_organization")]
+    pub sponsoring_organization: Option<String>,
+    pub funding: Vec<FundingLink>,
+    pub governance: Option<GovernanceModel>,
+    /// Whether the repository publishes a security policy
+    #[serde(alias = "has_security_policy")]
+    pub has_security_policy: Option<bool>,
+}
+
+/// How vulnerabilities in a package should be reported, and how they have
+/// been handled in the past.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct DisclosurePolicy {
+    /// Whether the repository contains a `SECURITY.md` file
+    #[serde(alias = "security_md_present")]
+    pub security_md_present: bool,
+    /// Address or URL for reporting vulnerabilities privately
+    pub contact: Option<String>,
+    /// Days the maintainers ask reporters to wait before public disclosure
+    #[serde(alias = "embargo_days")]
+    pub embargo_days: Option<u32>,
+    /// Median time between a vulnerability report and a fix, in days
+    #[serde(alias = "median_response_days")]
+    pub median_response_days: Option<u32>,
+}
+
+/// The analysis engine which produced a package result.
+///
+/// Comparing the provenance of two results tells whether a change in scores
+/// may come from the engine rather than from the package itself.
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
-pub struct PackageDescriptorAndLockfile {
-    #[serde(flatten)]
-    pub package_descriptor: PackageDescriptor,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub lockfile: Option<String>,
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AnalysisProvenance {
+    /// Semantic version of the analysis pipeline
+    #[serde(alias = "pipeline_version")]
+    pub pipeline_version: String,
+    /// Identifier of the set of heuristics which ran
+    #[serde(alias = "heuristic_set")]
+    pub heuristic_set: Option<String>,
+    /// Versions of the models used, by model name
+    #[serde(default, alias = "model_versions")]
+    pub model_versions: BTreeMap<String, String>,
+    #[serde(alias = "analyzed_at")]
+    pub analyzed_at: Timestamp,
 }
 
-impl From<&PackageDescriptor> for PackageDescriptorAndLockfile {
-    fn from(value: &PackageDescriptor) -> Self {
-        PackageDescriptorAndLockfile {
-            package_descriptor: value.clone(),
-            lockfile: None,
-        }
+impl AnalysisProvenance {
+    /// Whether `other` was produced by a different engine, ignoring when
+    /// the analyses ran.
+    pub fn engine_differs(&self, other: &AnalysisProvenance) -> bool {
+        self.pipeline_version != other.pipeline_version
+            || self.heuristic_set != other.heuristic_set
+            || self.model_versions != other.model_versions
     }
 }
 
-impl From<PackageDescriptor> for PackageDescriptorAndLockfile {
-    fn from(package_descriptor: PackageDescriptor) -> Self {
-        Self {
-            package_descriptor,
-            lockfile: None,
-        }
-    }
+#[derive(PartialEq, PartialOrd, Clone, Debug, Default, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct PackageReleaseData {
+    #[serde(alias = "first_release_date")]
+    pub first_release_date: String,
+    #[serde(alias = "last_release_date")]
+    pub last_release_date: String,
 }
 
-/// `PackageSpecifierAndLockfile` represents a parsed package
-/// (`package_specifier`) and the optional path to its lockfile (`lockfile`).
+/// How a package is depended upon
 #[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
 )]
-pub struct PackageSpecifierAndLockfile {
-    pub package_specifier: PackageSpecifier,
-    pub lockfile: Option<String>,
+#[serde(rename_all = "snake_case")]
+pub enum DependencyKind {
+    /// Needed when the project runs
+    #[default]
+    #[serde(alias = "production", alias = "prod")]
+    Runtime,
+    /// Needed only for developing the project, such as test frameworks
+    #[serde(alias = "development")]
+    Dev,
+    /// Installed if available, but not required
+    Optional,
+    /// Expected to be provided by the project depending on this one
+    Peer,
+    /// Needed only for building the project
+    Build,
 }
 
-impl From<&PackageSpecifier> for PackageSpecifierAndLockfile {
-    fn from(value: &PackageSpecifier) -> Self {
-        PackageSpecifierAndLockfile {
-            package_specifier: value.clone(),
-            lockfile: None,
-        }
+impl DependencyKind {
+    pub fn is_runtime(&self) -> bool {
+        *self == DependencyKind::Runtime
     }
 }
 
@@ -478,12 +1601,13 @@ impl From<&PackageSpecifier> for PackageSpecifierAndLockfile {
 )]
 pub struct PackageUrlAndLockfile {
     pub purl: String,
-    pub lockfile: Option<String>,
+    pub lockfile: Option<LockfilePath>,
 }
 
 /// Basic core package meta data
 // TODO Clearer name
 #[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct PackageStatus {
     /// A PURL referencing this package.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -496,21 +1620,34 @@ pub struct PackageStatus {
     // TODO Better name, such as processing_status?
     pub status: Status,
     /// Last updates, as epoch seconds
+    #[serde(alias = "lastUpdated")]
     pub last_updated: u64,
     /// Package license
-    pub license: Option<String>,
+    pub license: Option<Text>,
     /// The overall quality score of the package
+    #[serde(alias = "packageScore")]
     pub package_score: Option<f64>,
     /// Number of dependencies
     // TODO Break out by type? dev / optional / core?
+    #[serde(alias = "numDependencies")]
     pub num_dependencies: u32,
     /// Number of vulnerabilities found in this package and all transitive
     /// dependencies
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "numVulnerabilities")]
     pub num_vulnerabilities: Option<u32>,
+    /// The lockfiles of the job this package was found in, if reported
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lockfiles: Vec<LockfileRef>,
+    /// Fields not known to this version of the crate
+    #[cfg(feature = "preserve-unknown")]
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Package metadata with extended info info
+///
+/// Maps keep the order of their entries as received, and serialize in that
+/// order, so output is deterministic.
 // TODO Clearer name
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PackageStatusExtended {
@@ -522,16 +1659,67 @@ pub struct PackageStatusExtended {
     pub package_type: PackageType,
     // TODO This might a leftover of the api work going as we eliminate / merge some services, some
     // of which had inconsistent naming styles
-    #[serde(rename = "riskVectors")]
-    pub risk_vectors: HashMap<String, f64>,
+    #[serde(rename = "riskVectors", alias = "risk_vectors")]
+    pub risk_vectors: IndexMap<String, f64>,
     /// Dependencies of this package
-    pub dependencies: HashMap<String, String>,
+    pub dependencies: IndexMap<String, String>,
     /// Any issues found that may need action, but aren't in and of themselves
     /// vulnerabilities
     pub issues: Vec<IssueStatus>,
+    /// Fields known to neither this nor the basic status, which are rejected
+    #[cfg(deny_unknown_fields)]
+    #[serde(flatten, deserialize_with = "reject_unknown")]
+    pub extra: UnknownFields,
+}
+
+impl PackageStatus {
+    /// A one line summary, such as
+    /// `left-pad@1.3.0 [score 0.42, 3 vulnerabilities]`.
+    ///
+    /// Basic statuses don't list issues, so the vulnerability count is given
+    /// instead, when known.
+    pub fn summary(&self) -> String {
+        let vulnerabilities = self
+            .num_vulnerabilities
+            .map(|n| count(n as usize, "vulnerability", "vulnerabilities"));
+        format!(
+            "{self} [{}]",
+            summarize(self.package_score, vulnerabilities)
+        )
+    }
+}
+
+impl fmt::Display for PackageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
+    }
+}
+
+impl PackageStatusExtended {
+    /// A one line summary, such as `npm/left-pad@1.3.0 [score 0.42, 3 issues]`.
+    pub fn summary(&self) -> String {
+        let score = self.basic_status.package_score;
+        let issues = count(self.issues.len(), "issue", "issues");
+        format!("{self} [{}]", summarize(score, Some(issues)))
+    }
+}
+
+impl fmt::Display for PackageStatusExtended {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.package_type, self.basic_status)
+    }
+}
+
+impl From<PackageStatusExtended> for PackageStatus {
+    fn from(status: PackageStatusExtended) -> Self {
+        status.basic_status
+    }
 }
 
 /// A dependency issue with its job status.
+///
+/// The fields of the issue, such as `introduced_by`, are on the status
+/// itself on the wire.
 #[derive(PartialEq, Clone, Debug, Deserialize, Eq, JsonSchema, Serialize)]
 pub struct IssueStatus {
     /// The issue.
@@ -540,4 +1728,8 @@ pub struct IssueStatus {
     /// The reason why the issue is ignored (if applicable).
     #[serde(default)]
     pub ignored: Option<String>,
+    /// Fields known to neither this nor the issue, which are rejected
+    #[cfg(deny_unknown_fields)]
+    #[serde(flatten, deserialize_with = "reject_unknown")]
+    pub extra: UnknownFields,
 }
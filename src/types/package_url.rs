@@ -0,0 +1,205 @@
+//! Parsing of the package URLs carried by package responses, and checking
+//! them against the other fields of the package.
+//!
+//! ```
+//! use phylum_types::types::package::Package;
+//! use phylum_types::types::package_url::PackageUrl;
+//!
+//! let purl: PackageUrl = "pkg:npm/%40types/node@18.0.0".parse().unwrap();
+//! let package = Package::with_purl(purl);
+//! assert_eq!(package.name, "@types/node");
+//! assert_eq!(package.registry, "npm");
+//! assert!(package.validate_purl().is_ok());
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::types::package::{Package, PackageStatus, PackageStatusExtended, PackageType};
+
+/// A parsed package URL of any type, including those unknown to the `purl`
+/// crate
+pub type PackageUrl = purl::GenericPurl<String>;
+
+/// Reasons a package URL is unusable.
+#[derive(Debug)]
+pub enum PurlError {
+    Parse(purl::ParseError),
+    /// The package URL disagrees with another field of the package
+    Mismatch {
+        field: &'static str,
+        purl: String,
+        package: String,
+    },
+}
+
+impl fmt::Display for PurlError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PurlError::Parse(error) => write!(f, "invalid package URL: {error}"),
+            PurlError::Mismatch {
+                field,
+                purl,
+                package,
+            } => write!(
+                f,
+                "package URL {field} {purl} does not match package {field} {package}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PurlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PurlError::Parse(error) => Some(error),
+            PurlError::Mismatch { .. } => None,
+        }
+    }
+}
+
+impl From<purl::ParseError> for PurlError {
+    fn from(error: purl::ParseError) -> Self {
+        PurlError::Parse(error)
+    }
+}
+
+fn parse(purl: Option<&str>) -> Option<Result<PackageUrl, PurlError>> {
+    purl.map(|purl| PackageUrl::from_str(purl).map_err(PurlError::from))
+}
+
+/// The package type of a package URL type, such as `RubyGems` for `gem`.
+fn package_type(purl: &PackageUrl) -> Option<PackageType> {
+    purl.package_type().parse().ok()
+}
+
+/// The name of the package, including its namespace as written in the
+/// ecosystem, such as `org.apache.commons:commons-lang3`.
+fn combined_name(purl: &PackageUrl) -> String {
+    let separator = package_type(purl)
+        .and_then(|package_type| package_type.namespace_separator())
+        .unwrap_or('/');
+    match purl.namespace() {
+        Some(namespace) => format!("{namespace}{separator}{}", purl.name()),
+        None => purl.name().to_owned(),
+    }
+}
+
+/// Names as compared between the package URL and the package; Python names
+/// are normalized to lowercase with dashes in package URLs.
+fn comparable_name(package_type: Option<PackageType>, name: &str) -> String {
+    match package_type {
+        Some(PackageType::PyPi) => name.to_lowercase().replace('_', "-"),
+        _ => name.to_owned(),
+    }
+}
+
+fn check(field: &'static str, purl: &str, package: &str) -> Result<(), PurlError> {
+    if purl == package {
+        return Ok(());
+    }
+    Err(PurlError::Mismatch {
+        field,
+        purl: purl.into(),
+        package: package.into(),
+    })
+}
+
+/// Check the name and version of `purl`, and its type if `registry` is given.
+fn validate(
+    purl: &PackageUrl,
+    registry: Option<&str>,
+    name: &str,
+    version: &str,
+) -> Result<(), PurlError> {
+    let purl_type = package_type(purl);
+    if let Some(registry) = registry {
+        let registry_type = registry.parse::<PackageType>().ok();
+        match (purl_type, registry_type) {
+            (Some(purl_type), Some(registry_type)) => {
+                check("type", &purl_type.to_string(), &registry_type.to_string())?
+            }
+            _ => check("type", purl.package_type(), registry)?,
+        }
+    }
+
+    check(
+        "name",
+        &comparable_name(purl_type, &combined_name(purl)),
+        &comparable_name(purl_type, name),
+    )?;
+
+    match purl.version() {
+        Some(purl_version) => check("version", purl_version, version),
+        None => Ok(()),
+    }
+}
+
+impl Package {
+    /// A package with the name, version and registry of a package URL, and
+    /// no analysis.
+    pub fn with_purl(purl: PackageUrl) -> Self {
+        let registry = match package_type(&purl) {
+            Some(package_type) => package_type.to_string(),
+            None => purl.package_type().clone(),
+        };
+        let name = combined_name(&purl);
+        let version = purl.version().unwrap_or_default().to_owned();
+
+        Self {
+            id: format!("{registry}:{name}:{version}"),
+            purl: Some(purl.to_string()),
+            name,
+            version,
+            registry: registry.as_str().into(),
+            ..Default::default()
+        }
+    }
+
+    /// The package URL, if the package has one.
+    pub fn parsed_purl(&self) -> Option<Result<PackageUrl, PurlError>> {
+        parse(self.purl.as_deref())
+    }
+
+    /// Check that the package URL, if any, agrees with the name, version and
+    /// registry of the package.
+    pub fn validate_purl(&self) -> Result<(), PurlError> {
+        match self.parsed_purl() {
+            Some(purl) => validate(&purl?, Some(&*self.registry), &self.name, &self.version),
+            None => Ok(()),
+        }
+    }
+}
+
+impl PackageStatus {
+    /// The package URL, if the status has one.
+    pub fn parsed_purl(&self) -> Option<Result<PackageUrl, PurlError>> {
+        parse(self.purl.as_deref())
+    }
+
+    /// Check that the package URL, if any, agrees with the name and version
+    /// of the package.
+    pub fn validate_purl(&self) -> Result<(), PurlError> {
+        match self.parsed_purl() {
+            Some(purl) => validate(&purl?, None, &self.name, &self.version),
+            None => Ok(()),
+        }
+    }
+}
+
+impl PackageStatusExtended {
+    /// Check that the package URL, if any, agrees with the name, version and
+    /// type of the package.
+    pub fn validate_purl(&self) -> Result<(), PurlError> {
+        let status = &self.basic_status;
+        match status.parsed_purl() {
+            Some(purl) => validate(
+                &purl?,
+                Some(&self.package_type.to_string()),
+                &status.name,
+                &status.version,
+            ),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,163 @@
+//! This module contains types for policies inherited from the organization
+//! through groups down to projects.
+//!
+//! Each level defines a [`PolicyLayer`] holding only the rules it sets.
+//! [`resolve_effective_policy`] merges the layers, with narrower levels
+//! overriding broader ones unless a broader level locked the rule, and
+//! records where each effective rule came from.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::ProjectId;
+use super::package::RiskType;
+use super::preferences::{IgnoredIssue, RiskThresholds};
+use super::user_settings::Threshold;
+
+/// The level a policy layer is defined at
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "level", content = "id", rename_all = "snake_case")]
+pub enum PolicyScope {
+    #[default]
+    Organization,
+    /// A group, by name
+    Group(String),
+    Project(ProjectId),
+}
+
+impl PolicyScope {
+    /// How specific the scope is; narrower scopes have higher ranks.
+    pub fn rank(&self) -> u8 {
+        match self {
+            PolicyScope::Organization => 0,
+            PolicyScope::Group(_) => 1,
+            PolicyScope::Project(_) => 2,
+        }
+    }
+}
+
+/// The rules set at one level; anything not set is inherited
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct PolicyLayer {
+    pub scope: PolicyScope,
+    pub thresholds: BTreeMap<RiskType, Threshold>,
+    /// Issues to ignore, in addition to those ignored by broader levels
+    #[serde(alias = "ignored_issues")]
+    pub ignored_issues: Vec<IgnoredIssue>,
+    /// Thresholds which narrower levels may not override
+    pub locked: BTreeSet<RiskType>,
+}
+
+/// A threshold in effect, with the level it came from
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveThreshold {
+    pub threshold: Threshold,
+    pub source: PolicyScope,
+    /// Whether `source` locked the threshold
+    pub locked: bool,
+    /// Other levels which set this threshold, but were overridden or
+    /// prevented by a lock
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub superseded: Vec<PolicyScope>,
+}
+
+/// An ignored issue in effect, with the level which ignored it
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveIgnoredIssue {
+    pub issue: IgnoredIssue,
+    pub source: PolicyScope,
+}
+
+/// The policy applying to a project after inheritance
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectivePolicy {
+    pub thresholds: BTreeMap<RiskType, EffectiveThreshold>,
+    #[serde(alias = "ignored_issues")]
+    pub ignored_issues: Vec<EffectiveIgnoredIssue>,
+}
+
+impl EffectivePolicy {
+    pub fn threshold(&self, risk_type: RiskType) -> Option<&EffectiveThreshold> {
+        self.thresholds.get(&risk_type)
+    }
+
+    /// The effective thresholds, with defaults for those no level set.
+    pub fn risk_thresholds(&self) -> RiskThresholds {
+        let get = |risk_type| {
+            self.threshold(risk_type)
+                .map(|effective| effective.threshold.clone())
+                .unwrap_or_default()
+        };
+        RiskThresholds {
+            total: get(RiskType::TotalRisk),
+            author: get(RiskType::AuthorsRisk),
+            engineering: get(RiskType::EngineeringRisk),
+            license: get(RiskType::LicenseRisk),
+            malicious: get(RiskType::MaliciousRisk),
+            vulnerability: get(RiskType::Vulnerabilities),
+        }
+    }
+}
+
+/// Merge policy layers into the policy in effect.
+///
+/// Layers may be given in any order; they are applied from the broadest to
+/// the narrowest scope. Ignored issues accumulate across levels, keeping the
+/// broadest level which ignored each.
+pub fn resolve_effective_policy(layers: &[PolicyLayer]) -> EffectivePolicy {
+    let mut layers: Vec<&PolicyLayer> = layers.iter().collect();
+    layers.sort_by_key(|layer| layer.scope.rank());
+
+    let mut policy = EffectivePolicy::default();
+    for layer in layers {
+        for (risk_type, threshold) in &layer.thresholds {
+            let locked = layer.locked.contains(risk_type);
+            match policy.thresholds.get_mut(risk_type) {
+                Some(effective) if effective.locked => {
+                    effective.superseded.push(layer.scope.clone());
+                }
+                Some(effective) => {
+                    let previous = std::mem::replace(&mut effective.source, layer.scope.clone());
+                    effective.superseded.push(previous);
+                    effective.threshold = threshold.clone();
+                    effective.locked = locked;
+                }
+                None => {
+                    policy.thresholds.insert(
+                        *risk_type,
+                        EffectiveThreshold {
+                            threshold: threshold.clone(),
+                            source: layer.scope.clone(),
+                            locked,
+                            superseded: Vec::new(),
+                        },
+                    );
+                }
+            }
+        }
+
+        for issue in &layer.ignored_issues {
+            let known = policy
+                .ignored_issues
+                .iter()
+                .any(|ignored| ignored.issue.id == issue.id && ignored.issue.tag == issue.tag);
+            if !known {
+                policy.ignored_issues.push(EffectiveIgnoredIssue {
+                    issue: issue.clone(),
+                    source: layer.scope.clone(),
+                });
+            }
+        }
+    }
+
+    policy
+}
@@ -2,16 +2,21 @@ use schemars::JsonSchema;
 use serde::{self, Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::types::common::Patch;
+use crate::types::metadata::Metadata;
+use crate::types::package::{RiskScores, RiskWeights};
 use crate::types::user_settings::Threshold;
 
 #[derive(Debug, Default, Serialize, Deserialize, JsonSchema, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CorePreferences {
     /// The default label to use when none is supplied.
+    #[serde(alias = "default_label")]
     pub default_label: Option<String>,
     /// The risk thresholds to apply.
     pub thresholds: RiskThresholds,
     /// Project specific ignored issues.
+    #[serde(alias = "ignored_issues")]
     pub ignored_issues: Option<Vec<IgnoredIssue>>,
 }
 
@@ -20,20 +25,85 @@ pub struct CorePreferences {
 #[serde(rename_all = "camelCase")]
 pub struct ProjectPreferences {
     /// The id of the project these preferences apply to.
+    #[serde(alias = "project_id")]
     pub project_id: Uuid,
     /// The preference settings
     pub preferences: CorePreferences,
 }
 
+/// Partial update of the preferences of a project
+///
+/// Fields which are absent are left unchanged, and `null` clears a field.
+#[derive(PartialEq, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PreferencesPatch {
+    #[serde(
+        default,
+        skip_serializing_if = "Patch::is_absent",
+        alias = "default_label"
+    )]
+    pub default_label: Patch<String>,
+    /// The new thresholds; `null` restores the defaults
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    pub thresholds: Patch<RiskThresholds>,
+    /// The new ignored issues, replacing all existing ones
+    #[serde(
+        default,
+        skip_serializing_if = "Patch::is_absent",
+        alias = "ignored_issues"
+    )]
+    pub ignored_issues: Patch<Vec<IgnoredIssue>>,
+}
+
+impl PreferencesPatch {
+    /// Whether the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.default_label.is_absent()
+            && self.thresholds.is_absent()
+            && self.ignored_issues.is_absent()
+    }
+
+    /// Set the default label, or clear it with `None`.
+    pub fn with_default_label(mut self, default_label: Option<String>) -> Self {
+        self.default_label = Some(default_label).into();
+        self
+    }
+
+    /// Replace the thresholds, or restore the defaults with `None`.
+    pub fn with_thresholds(mut self, thresholds: Option<RiskThresholds>) -> Self {
+        self.thresholds = Some(thresholds).into();
+        self
+    }
+
+    /// Replace the ignored issues, or clear them with `None`.
+    pub fn with_ignored_issues(mut self, ignored_issues: Option<Vec<IgnoredIssue>>) -> Self {
+        self.ignored_issues = Some(ignored_issues).into();
+        self
+    }
+
+    /// Apply the patch to a set of preferences.
+    pub fn apply_to(self, preferences: &mut CorePreferences) {
+        self.default_label.apply_to(&mut preferences.default_label);
+        if let Some(thresholds) = self.thresholds.into_option() {
+            preferences.thresholds = thresholds.unwrap_or_default();
+        }
+        self.ignored_issues
+            .apply_to(&mut preferences.ignored_issues);
+    }
+}
+
 /// Capture the project threshold settings.
-#[derive(Serialize, Deserialize, Clone, Debug, Default, JsonSchema)]
+#[derive(Serialize, Deserialize, PartialEq, Clone, Debug, Default, JsonSchema)]
 pub struct RiskThresholds {
     pub total: Threshold,
     pub author: Threshold,
     pub engineering: Threshold,
     pub license: Threshold,
-    #[serde(alias = "malicious")]
-    #[serde(rename = "maliciousCode")]
+    #[serde(
+        alias = "malicious",
+        rename = "maliciousCode",
+        alias = "malicious_code"
+    )]
     pub malicious: Threshold,
     pub vulnerability: Threshold,
 }
@@ -44,4 +114,41 @@ pub struct IgnoredIssue {
     pub id: String,
     pub tag: String,
     pub reason: String,
+    /// Data attached by integrators
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+}
+
+/// Scoring configuration, such as an organization's, which determines the
+/// total score of a package from its domain scores.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ScoreConfig {
+    /// Weights of each domain in the total score.
+    pub weights: RiskWeights,
+    /// Thresholds to apply to the scores.
+    pub thresholds: RiskThresholds,
+}
+
+impl ScoreConfig {
+    /// The total score of a package according to this configuration.
+    pub fn total(&self, scores: &RiskScores) -> f32 {
+        scores.weighted_total(&self.weights)
+    }
+
+    /// Whether any active threshold fails for the given scores.
+    pub fn fails(&self, scores: &RiskScores) -> bool {
+        let checks = [
+            (&self.thresholds.total, self.total(scores)),
+            (&self.thresholds.author, scores.author),
+            (&self.thresholds.engineering, scores.engineering),
+            (&self.thresholds.license, scores.license),
+            (&self.thresholds.malicious, scores.malicious),
+            (&self.thresholds.vulnerability, scores.vulnerability),
+        ];
+        checks
+            .iter()
+            .any(|(threshold, score)| threshold.active && *score < threshold.threshold)
+    }
 }
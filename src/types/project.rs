@@ -1,54 +1,229 @@
 //! This module contains types for working with project data
-use chrono::{DateTime, Utc};
+// Affected project lookups still use the deprecated v1 package specifiers,
+// and the `JsonSchema` derive does not pass an item-level allow on to its impl
+#![allow(deprecated)]
+
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use super::common::ProjectId;
-use super::package::PackageType;
+use super::common::{JobId, Patch, ProjectId};
+use super::label::Label;
+use super::lenient;
+use super::metadata::Metadata;
+use super::package::{PackageSpecifier, PackageType};
+use super::timestamp::Timestamp;
 
 /// Summary response for a project
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct ProjectSummaryResponse {
     /// The project name
     pub name: String,
     /// The project id
     pub id: ProjectId,
     /// When the project was updated
-    pub updated_at: DateTime<Utc>,
+    #[serde(alias = "updatedAt")]
+    pub updated_at: Timestamp,
     /// When the project was created
-    pub created_at: DateTime<Utc>,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
     /// The ecosystems of the project; determined by its latest job
     #[serde(default)]
     pub ecosystems: Vec<PackageType>,
     /// The project's group's name, if this is a group project
+    #[serde(alias = "groupName")]
     pub group_name: Option<String>,
     /// The project's repository location
+    #[serde(alias = "repositoryUrl")]
     pub repository_url: Option<String>,
+    /// Data attached by integrators
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
 }
 
 /// Request to create a project
 #[derive(
-    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
 )]
 pub struct CreateProjectRequest {
     pub name: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "groupName")]
     pub group_name: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "repositoryUrl")]
     pub repository_url: Option<String>,
+    /// Data attached by integrators
+    #[serde(default, skip_serializing_if = "Metadata::is_empty")]
+    pub metadata: Metadata,
+}
+
+impl CreateProjectRequest {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_group_name(mut self, group_name: impl Into<String>) -> Self {
+        self.group_name = Some(group_name.into());
+        self
+    }
+
+    pub fn with_repository_url(mut self, repository_url: impl Into<String>) -> Self {
+        self.repository_url = Some(repository_url.into());
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = metadata;
+        self
+    }
 }
 
 pub type UpdateProjectRequest = CreateProjectRequest;
 
+/// Partial update of a project
+///
+/// Fields which are absent are left unchanged, and `null` clears a field.
+#[derive(PartialEq, Eq, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ProjectPatch {
+    /// The new project name; it cannot be cleared
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    pub name: Patch<String>,
+    #[serde(default, skip_serializing_if = "Patch::is_absent", alias = "groupName")]
+    pub group_name: Patch<String>,
+    #[serde(
+        default,
+        skip_serializing_if = "Patch::is_absent",
+        alias = "repositoryUrl"
+    )]
+    pub repository_url: Patch<String>,
+    /// Data attached by integrators; `null` removes all of it
+    #[serde(default, skip_serializing_if = "Patch::is_absent")]
+    pub metadata: Patch<Metadata>,
+}
+
+impl ProjectPatch {
+    /// Whether the patch changes nothing.
+    pub fn is_empty(&self) -> bool {
+        self.name.is_absent()
+            && self.group_name.is_absent()
+            && self.repository_url.is_absent()
+            && self.metadata.is_absent()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Patch::Value(name.into());
+        self
+    }
+
+    /// Set the group name, or clear it with `None`.
+    pub fn with_group_name(mut self, group_name: Option<String>) -> Self {
+        self.group_name = Some(group_name).into();
+        self
+    }
+
+    /// Set the repository location, or clear it with `None`.
+    pub fn with_repository_url(mut self, repository_url: Option<String>) -> Self {
+        self.repository_url = Some(repository_url).into();
+        self
+    }
+
+    /// Replace the metadata, or remove all of it with `None`.
+    pub fn with_metadata(mut self, metadata: Option<Metadata>) -> Self {
+        self.metadata = Some(metadata).into();
+        self
+    }
+
+    /// Apply the patch to a project.
+    ///
+    /// A `null` name is left unchanged, as the server rejects it.
+    pub fn apply_to(self, project: &mut ProjectSummaryResponse) {
+        if let Patch::Value(name) = self.name {
+            project.name = name;
+        }
+        self.group_name.apply_to(&mut project.group_name);
+        self.repository_url.apply_to(&mut project.repository_url);
+        if let Some(metadata) = self.metadata.into_option() {
+            project.metadata = metadata.unwrap_or_default();
+        }
+    }
+}
+
 /// Response of a create project request
 #[derive(
     PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
 )]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
 pub struct CreateProjectResponse {
     /// The id of the newly created project
     pub id: ProjectId,
 }
 
 pub type UpdateProjectResponse = CreateProjectResponse;
+
+/// Request to find the projects affected by an advisory or package version
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AffectedProjectsRequest {
+    /// Look up by advisory identifier, such as a CVE or GHSA id
+    Advisory(String),
+    /// Look up by a specific package version
+    Package(PackageSpecifier),
+}
+
+/// A job in which an affected package was found
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AffectedJob {
+    /// The id of the job
+    #[serde(alias = "jobId")]
+    pub job_id: JobId,
+    /// The label associated with the job, most often a branch name
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
+    /// When the job was created
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    /// The affected packages found in this job
+    pub packages: Vec<PackageSpecifier>,
+    /// Dependency paths from a top level dependency down to an affected
+    /// package
+    #[serde(default, alias = "usagePaths")]
+    pub usage_paths: Vec<Vec<PackageSpecifier>>,
+}
+
+/// A project containing one or more affected packages
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AffectedProject {
+    /// The project id
+    #[serde(alias = "projectId")]
+    pub project_id: ProjectId,
+    /// The project name
+    #[serde(alias = "projectName")]
+    pub project_name: String,
+    /// The project's group's name, if this is a group project
+    #[serde(alias = "groupName")]
+    pub group_name: Option<String>,
+    /// The jobs of this project in which affected packages were found
+    pub jobs: Vec<AffectedJob>,
+}
+
+/// Response of an affected projects lookup
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct AffectedProjectsResponse {
+    /// The projects affected
+    pub projects: Vec<AffectedProject>,
+}
@@ -0,0 +1,296 @@
+//! This module contains types describing remediation suggestions for issues
+//! found during analysis.
+// Upgrade steps still name packages with the deprecated v1 specifiers, and
+// the `JsonSchema` derive does not pass an item-level allow on to its impl
+#![allow(deprecated)]
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{JobId, ProjectId};
+use super::package::{PackageSpecifier, PackageType};
+use super::timestamp::Timestamp;
+
+/// Machine readable remediation of a vulnerability or issue, for tools
+/// which apply fixes automatically
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(default)]
+pub struct RemediationInfo {
+    /// Versions in which the problem is fixed
+    #[serde(skip_serializing_if = "Vec::is_empty", alias = "fixedVersions")]
+    pub fixed_versions: Vec<String>,
+    /// Links to patches fixing the problem, unparsed like
+    /// [`Reference::url`](super::package::Reference::url)
+    #[serde(skip_serializing_if = "Vec::is_empty", alias = "patchUrls")]
+    pub patch_urls: Vec<String>,
+    /// How to avoid the problem without upgrading
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workaround: Option<String>,
+    /// Versions to upgrade through, in order, ending with the suggested
+    /// version
+    #[serde(skip_serializing_if = "Vec::is_empty", alias = "upgradePath")]
+    pub upgrade_path: Vec<String>,
+}
+
+impl RemediationInfo {
+    /// The version to upgrade to: the end of the upgrade path, or else the
+    /// first fixed version.
+    pub fn suggested_version(&self) -> Option<&str> {
+        self.upgrade_path
+            .last()
+            .or_else(|| self.fixed_versions.first())
+            .map(String::as_str)
+    }
+
+    /// Whether the problem can be fixed by upgrading.
+    pub fn has_fix(&self) -> bool {
+        self.suggested_version().is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Estimated likelihood that an upgrade breaks the dependent project
+#[derive(
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub enum BreakingChangeRisk {
+    /// Not enough information to estimate.
+    #[default]
+    Unknown,
+    /// Patch level change, or otherwise believed to be compatible.
+    Low,
+    /// Minor version change, or behavior changes noted in the changelog.
+    Medium,
+    /// Major version change or known API removals.
+    High,
+}
+
+/// A single package upgrade as part of a remediation plan
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeStep {
+    /// The package as currently depended upon
+    pub package: PackageSpecifier,
+    /// The version to upgrade to
+    #[serde(alias = "target_version")]
+    pub target_version: String,
+    /// Ids of the issues expected to be resolved by this step
+    #[serde(default, alias = "resolved_issues")]
+    pub resolved_issues: Vec<String>,
+    /// Ids of the issues expected to be introduced by this step
+    #[serde(default, alias = "introduced_issues")]
+    pub introduced_issues: Vec<String>,
+    /// Estimate of how likely the upgrade is to break the project
+    #[serde(default, alias = "breaking_change_risk")]
+    pub breaking_change_risk: BreakingChangeRisk,
+    /// Concrete file edits performing this upgrade
+    #[serde(default)]
+    pub edits: Vec<ManifestEdit>,
+}
+
+/// Suggested upgrades for remediating the issues of a job
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RemediationPlan {
+    /// The upgrades, in the order they should be applied
+    pub steps: Vec<UpgradeStep>,
+    /// Ids of the issues which no step is able to resolve
+    #[serde(default, alias = "unresolved_issues")]
+    pub unresolved_issues: Vec<String>,
+}
+
+impl RemediationPlan {
+    /// Ids of all issues resolved by the plan.
+    pub fn resolved_issues(&self) -> impl Iterator<Item = &str> {
+        self.steps
+            .iter()
+            .flat_map(|step| step.resolved_issues.iter().map(String::as_str))
+    }
+
+    /// The highest breaking change risk of any step.
+    pub fn breaking_change_risk(&self) -> BreakingChangeRisk {
+        self.steps
+            .iter()
+            .map(|step| step.breaking_change_risk)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+/// A change to a single dependency declaration in a manifest file
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestEdit {
+    /// Path of the manifest file, relative to the project root
+    pub path: String,
+    /// The ecosystem of the manifest
+    #[serde(alias = "package_type")]
+    pub package_type: PackageType,
+    /// The key the dependency is declared under, such as `lodash` in a
+    /// `package.json` or `org.slf4j:slf4j-api` in a `pom.xml`
+    pub dependency: String,
+    /// The current version constraint, if the dependency is already declared
+    #[serde(alias = "old_constraint")]
+    pub old_constraint: Option<String>,
+    /// The version constraint to write
+    #[serde(alias = "new_constraint")]
+    pub new_constraint: String,
+    /// Command to run afterwards so the lockfile matches the manifest
+    #[serde(default, alias = "lockfile_command")]
+    pub lockfile_command: Option<LockfileRegeneration>,
+}
+
+impl ManifestEdit {
+    /// The lockfile regeneration command, falling back to the ecosystem's
+    /// usual command when none was supplied.
+    pub fn regeneration_command(&self) -> Option<LockfileRegeneration> {
+        self.lockfile_command
+            .clone()
+            .or_else(|| LockfileRegeneration::default_for(self.package_type, &self.dependency))
+    }
+}
+
+/// A command which regenerates a lockfile after its manifest was edited
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct LockfileRegeneration {
+    /// Path of the lockfile, relative to the project root
+    pub lockfile: Option<String>,
+    /// Program and arguments to run from the manifest's directory
+    pub command: Vec<String>,
+}
+
+impl LockfileRegeneration {
+    /// The usual command to update a single dependency in the lockfile of
+    /// an ecosystem.
+    pub fn default_for(package_type: PackageType, dependency: &str) -> Option<Self> {
+        let (lockfile, command): (_, &[&str]) = match package_type {
+            PackageType::Npm => ("package-lock.json", &["npm", "install"]),
+            PackageType::PyPi => ("poetry.lock", &["poetry", "lock", "--no-update"]),
+            PackageType::RubyGems => ("Gemfile.lock", &["bundle", "update", "--conservative"]),
+            PackageType::Nuget => ("packages.lock.json", &["dotnet", "restore"]),
+            PackageType::Cargo => ("Cargo.lock", &["cargo", "update", "-p"]),
+            PackageType::Golang => ("go.sum", &["go", "mod", "tidy"]),
+            PackageType::Composer => ("composer.lock", &["composer", "update"]),
+            PackageType::Conan => ("conan.lock", &["conan", "lock", "create", "."]),
+            PackageType::Maven => return None,
+        };
+
+        let mut command: Vec<String> = command.iter().map(|arg| arg.to_string()).collect();
+        if matches!(
+            package_type,
+            PackageType::RubyGems | PackageType::Cargo | PackageType::Composer
+        ) {
+            command.push(dependency.into());
+        }
+
+        Some(Self {
+            lockfile: Some(lockfile.into()),
+            command,
+        })
+    }
+}
+
+/// Source code hosting provider on which a fix pull request was opened
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum PullRequestProvider {
+    GitHub,
+    GitLab,
+    Bitbucket,
+    #[serde(rename = "azure_devops")]
+    AzureDevOps,
+    /// Any other provider, by name
+    Other(String),
+}
+
+/// State of a fix pull request
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum PullRequestState {
+    Draft,
+    Open,
+    Merged,
+    Closed,
+}
+
+impl PullRequestState {
+    /// Whether the fix may still land.
+    pub fn is_in_flight(&self) -> bool {
+        matches!(self, PullRequestState::Draft | PullRequestState::Open)
+    }
+}
+
+/// A pull request opened to remediate issues found by Phylum
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FixPullRequest {
+    /// The hosting provider
+    pub provider: PullRequestProvider,
+    /// Link to the pull request
+    pub url: String,
+    /// The project being remediated
+    pub project: ProjectId,
+    /// The job whose findings the pull request addresses, if known
+    #[serde(alias = "job_id")]
+    pub job_id: Option<JobId>,
+    /// Ids of the issues addressed
+    pub issues: Vec<String>,
+    /// Current state of the pull request
+    pub state: PullRequestState,
+    /// Name of the integration which opened the pull request
+    #[serde(alias = "created_by")]
+    pub created_by: Option<String>,
+    #[serde(alias = "created_at")]
+    pub created_at: Timestamp,
+    #[serde(alias = "updated_at")]
+    pub updated_at: Timestamp,
+}
+
+/// Request to record or update a fix pull request
+pub type UpsertFixPullRequest = FixPullRequest;
+
+/// Fix pull requests known for a project
+#[derive(PartialEq, Eq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ListFixPullRequestsResponse {
+    #[serde(alias = "pullRequests")]
+    pub pull_requests: Vec<FixPullRequest>,
+}
+
+impl ListFixPullRequestsResponse {
+    /// Whether a fix for the given issue is already open.
+    pub fn has_fix_in_flight(&self, issue_id: &str) -> bool {
+        self.pull_requests
+            .iter()
+            .any(|pr| pr.state.is_in_flight() && pr.issues.iter().any(|issue| issue == issue_id))
+    }
+}
@@ -0,0 +1,203 @@
+//! This module contains types for generating shareable reports of the
+//! analysis of a job, project or group.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::common::{JobId, ProjectId, ReportId};
+use super::timestamp::Timestamp;
+
+/// File format of a report
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFormat {
+    Html,
+    Pdf,
+    Json,
+    /// SARIF 2.1.0, for code scanning tools
+    Sarif,
+}
+
+impl ReportFormat {
+    /// The name used on the wire, such as `html`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Pdf => "pdf",
+            ReportFormat::Json => "json",
+            ReportFormat::Sarif => "sarif",
+        }
+    }
+
+    /// File name extension of reports in this format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "html",
+            ReportFormat::Pdf => "pdf",
+            ReportFormat::Json => "json",
+            ReportFormat::Sarif => "sarif.json",
+        }
+    }
+
+    /// Media type of reports in this format.
+    pub fn media_type(&self) -> &'static str {
+        match self {
+            ReportFormat::Html => "text/html",
+            ReportFormat::Pdf => "application/pdf",
+            ReportFormat::Json => "application/json",
+            ReportFormat::Sarif => "application/sarif+json",
+        }
+    }
+}
+
+impl fmt::Display for ReportFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// What a report covers
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReportScope {
+    /// A single job
+    Job {
+        #[serde(alias = "jobId")]
+        job_id: JobId,
+    },
+    /// The latest job of a project, optionally of one label only
+    Project {
+        #[serde(alias = "projectId")]
+        project_id: ProjectId,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        label: Option<String>,
+    },
+    /// The latest jobs of every project of a group
+    Group {
+        #[serde(alias = "groupName")]
+        group_name: String,
+    },
+}
+
+/// Request to generate a report
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ReportRequest {
+    pub format: ReportFormat,
+    pub scope: ReportScope,
+    /// Title shown in the report; the server picks one if absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Whether ignored issues are listed
+    #[serde(default, alias = "includeIgnored")]
+    pub include_ignored: bool,
+}
+
+impl ReportRequest {
+    pub fn new(format: ReportFormat, scope: ReportScope) -> Self {
+        Self {
+            format,
+            scope,
+            title: None,
+            include_ignored: false,
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_include_ignored(mut self, include_ignored: bool) -> Self {
+        self.include_ignored = include_ignored;
+        self
+    }
+}
+
+/// State of the generation of a report
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportState {
+    Pending,
+    Generating,
+    /// The report can be downloaded
+    Ready,
+    Failed,
+    /// The report was ready, but has since been deleted
+    Expired,
+}
+
+impl ReportState {
+    /// Whether the state will not change any more.
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            ReportState::Ready | ReportState::Failed | ReportState::Expired
+        )
+    }
+}
+
+/// Status of a report, as returned when it is requested and while it is
+/// being generated
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ReportStatus {
+    pub id: ReportId,
+    pub state: ReportState,
+    pub format: ReportFormat,
+    pub scope: ReportScope,
+    #[serde(alias = "requestedAt")]
+    pub requested_at: Timestamp,
+    #[serde(default, alias = "completedAt")]
+    pub completed_at: Option<Timestamp>,
+    /// Why generation failed
+    #[serde(default)]
+    pub msg: Option<String>,
+}
+
+impl ReportStatus {
+    pub fn is_ready(&self) -> bool {
+        self.state == ReportState::Ready
+    }
+}
+
+/// Where to download a generated report from
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ReportDownloadInfo {
+    /// Signed link to the report, usable without authentication until it
+    /// expires. Kept as a string because normalizing it, as `url::Url` does,
+    /// could invalidate the signature.
+    pub url: String,
+    #[serde(alias = "expiresAt")]
+    pub expires_at: Timestamp,
+    pub format: ReportFormat,
+    /// Suggested file name, such as `my-project.pdf`
+    #[serde(default, alias = "fileName")]
+    pub file_name: Option<String>,
+    #[serde(default, alias = "sizeBytes")]
+    pub size_bytes: Option<u64>,
+    /// Hex encoded SHA-256 digest of the report
+    #[serde(default)]
+    pub sha256: Option<String>,
+}
+
+impl ReportDownloadInfo {
+    /// Whether the link can no longer be used at `now`.
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at <= now
+    }
+}
@@ -0,0 +1,109 @@
+//! This module contains types for the periodic re-analysis of projects, so
+//! that new advisories are caught without pushing new code.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::{JobId, ProjectId};
+use super::timestamp::{Duration, Timestamp};
+
+/// When a scheduled analysis runs
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Schedule {
+    /// A cron expression in UTC, such as `0 6 * * 1`
+    Cron { expression: String },
+    /// A fixed interval between runs
+    Interval { hours: u32 },
+}
+
+/// Re-analysis settings of a project
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ReanalysisConfig {
+    #[serde(alias = "projectId")]
+    pub project_id: ProjectId,
+    pub enabled: bool,
+    /// Regular runs, if any
+    pub schedule: Option<Schedule>,
+    /// Run when a new advisory affects one of the project's dependencies
+    #[serde(default, alias = "onNewAdvisory")]
+    pub on_new_advisory: bool,
+    /// Run when the latest analysis is older than this many days
+    #[serde(alias = "staleAfterDays")]
+    pub stale_after_days: Option<u32>,
+    /// Label of the jobs created by scheduled runs
+    pub label: Option<String>,
+}
+
+impl ReanalysisConfig {
+    /// Whether an analysis completed at `analyzed_at` is stale by `now`.
+    pub fn is_stale(&self, analyzed_at: Timestamp, now: Timestamp) -> bool {
+        self.stale_after_days
+            .is_some_and(|days| now - analyzed_at >= Duration::days(days.into()))
+    }
+}
+
+/// Why a scheduled run was started
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReanalysisTrigger {
+    Schedule,
+    NewAdvisory { advisory_id: String },
+    Stale,
+    Manual,
+}
+
+/// State of a scheduled run
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ScheduledRunStatus {
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    /// Nothing changed since the last analysis, so no job was created
+    Skipped,
+}
+
+/// A single re-analysis run
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ScheduledRun {
+    pub id: Uuid,
+    #[serde(alias = "projectId")]
+    pub project_id: ProjectId,
+    pub trigger: ReanalysisTrigger,
+    pub status: ScheduledRunStatus,
+    #[serde(alias = "scheduledAt")]
+    pub scheduled_at: Timestamp,
+    #[serde(alias = "startedAt")]
+    pub started_at: Option<Timestamp>,
+    #[serde(alias = "completedAt")]
+    pub completed_at: Option<Timestamp>,
+    /// The job analyzing the project, once created
+    #[serde(alias = "jobId")]
+    pub job_id: Option<JobId>,
+    /// Why the run failed or was skipped
+    pub msg: Option<String>,
+}
+
+/// Response of the scheduled runs endpoint for a project
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct ListScheduledRunsResponse {
+    pub runs: Vec<ScheduledRun>,
+}
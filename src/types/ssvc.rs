@@ -0,0 +1,226 @@
+//! This module contains inputs and outcomes of Stakeholder-Specific
+//! Vulnerability Categorization (SSVC), for organizations prioritizing
+//! vulnerabilities with it.
+//!
+//! Decisions follow the tree of the [CISA SSVC Guide], November 2022.
+//! Exploitation and technical impact can be derived from an issue, while
+//! automatability and mission impact depend on how the affected project is
+//! deployed:
+//!
+//! ```
+//! use phylum_types::types::ssvc::{Automatable, MissionImpact, SsvcDecision, SsvcInputs};
+//! # use phylum_types::types::package::Issue;
+//! # let issue: Issue = serde_json::from_value(serde_json::json!({
+//! #     "tag": null, "id": "CVE-2021-44228", "title": "log4shell", "description": "",
+//! #     "severity": "critical", "domain": "vulnerability", "exploit_maturity": "weaponized",
+//! # })).unwrap();
+//!
+//! let inputs = SsvcInputs::for_issue(&issue, Automatable::Yes, MissionImpact::High);
+//! assert_eq!(inputs.decide(), SsvcDecision::Act);
+//! ```
+//!
+//! [CISA SSVC Guide]: https://www.cisa.gov/sites/default/files/publications/cisa-ssvc-guide%20508c.pdf
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::package::{ExploitMaturity, Issue, RiskLevel};
+use super::timestamp::Timestamp;
+
+/// The current state of exploitation of a vulnerability
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Exploitation {
+    /// No evidence of exploitation or public exploit code
+    None,
+    /// Exploit code or a proof of concept is public
+    Poc,
+    /// Exploited in the wild
+    Active,
+}
+
+impl From<ExploitMaturity> for Exploitation {
+    fn from(maturity: ExploitMaturity) -> Self {
+        match maturity {
+            ExploitMaturity::Unproven => Exploitation::None,
+            ExploitMaturity::ProofOfConcept | ExploitMaturity::Functional => Exploitation::Poc,
+            ExploitMaturity::Weaponized => Exploitation::Active,
+        }
+    }
+}
+
+/// Whether an attacker can reliably automate the steps of exploitation
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Automatable {
+    No,
+    Yes,
+}
+
+/// How much control over the vulnerable software exploitation gives
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TechnicalImpact {
+    /// Limited control, or disclosure of some information
+    Partial,
+    /// Total control of the software, or disclosure of all its information
+    Total,
+}
+
+impl TechnicalImpact {
+    /// The technical impact implied by an issue's severity, for issues which
+    /// have not been assessed.
+    pub fn from_severity(severity: RiskLevel) -> Self {
+        match severity {
+            RiskLevel::Critical => TechnicalImpact::Total,
+            RiskLevel::High | RiskLevel::Medium | RiskLevel::Low | RiskLevel::Info => {
+                TechnicalImpact::Partial
+            }
+        }
+    }
+}
+
+/// Impact of exploitation on the organization's mission and on public
+/// well-being
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionImpact {
+    Low,
+    Medium,
+    High,
+}
+
+/// The outcome of an SSVC decision, from least to most urgent
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SsvcDecision {
+    /// Remediate within standard update timelines
+    Track,
+    /// Track closely, in case the situation changes
+    #[serde(rename = "track*", alias = "track_star")]
+    TrackStar,
+    /// Remediate sooner than standard update timelines
+    Attend,
+    /// Remediate as soon as possible
+    Act,
+}
+
+impl SsvcDecision {
+    /// The name used by SSVC, such as `Track*`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SsvcDecision::Track => "Track",
+            SsvcDecision::TrackStar => "Track*",
+            SsvcDecision::Attend => "Attend",
+            SsvcDecision::Act => "Act",
+        }
+    }
+}
+
+impl fmt::Display for SsvcDecision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The decision points of a vulnerability
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SsvcInputs {
+    pub exploitation: Exploitation,
+    pub automatable: Automatable,
+    #[serde(alias = "technicalImpact")]
+    pub technical_impact: TechnicalImpact,
+    #[serde(alias = "missionImpact")]
+    pub mission_impact: MissionImpact,
+}
+
+impl SsvcInputs {
+    /// The inputs for an issue in a deployment where exploitation is or is
+    /// not automatable and has the given mission impact.
+    ///
+    /// Issues without an exploit maturity are treated as unexploited, and
+    /// technical impact is derived from the severity.
+    pub fn for_issue(
+        issue: &Issue,
+        automatable: Automatable,
+        mission_impact: MissionImpact,
+    ) -> Self {
+        Self {
+            exploitation: issue
+                .exploit_maturity
+                .map_or(Exploitation::None, Exploitation::from),
+            automatable,
+            technical_impact: TechnicalImpact::from_severity(issue.severity),
+            mission_impact,
+        }
+    }
+
+    /// Look the inputs up in the decision tree of the CISA SSVC Guide,
+    /// November 2022.
+    pub fn decide(&self) -> SsvcDecision {
+        use SsvcDecision::{Act, Attend, Track, TrackStar};
+
+        // Indexed by exploitation, automatable, technical impact and mission
+        // impact, in the order of the guide's tree
+        const TREE: [[[[SsvcDecision; 3]; 2]; 2]; 3] = [
+            // None
+            [
+                [[Track, Track, Track], [Track, Track, TrackStar]],
+                [[Track, Track, Attend], [Track, Track, Attend]],
+            ],
+            // Public PoC
+            [
+                [[Track, Track, TrackStar], [Track, TrackStar, Attend]],
+                [[Track, Track, Attend], [Track, TrackStar, Attend]],
+            ],
+            // Active
+            [
+                [[Track, Track, Attend], [Track, Attend, Act]],
+                [[Attend, Attend, Act], [Attend, Act, Act]],
+            ],
+        ];
+
+        TREE[self.exploitation as usize][self.automatable as usize][self.technical_impact as usize]
+            [self.mission_impact as usize]
+    }
+}
+
+/// A recorded SSVC decision for an issue
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SsvcAssessment {
+    /// The id of the issue, such as a CVE id
+    #[serde(alias = "issueId")]
+    pub issue_id: Option<String>,
+    pub inputs: SsvcInputs,
+    pub decision: SsvcDecision,
+    #[serde(alias = "decidedAt")]
+    pub decided_at: Option<Timestamp>,
+}
+
+impl SsvcAssessment {
+    /// The decision for the inputs, without recording when it was made.
+    pub fn new(issue_id: Option<String>, inputs: SsvcInputs) -> Self {
+        Self {
+            issue_id,
+            inputs,
+            decision: inputs.decide(),
+            decided_at: None,
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Text fields which repeat across large payloads, such as registries and
+//! licenses.
+//!
+//! [`Text`] is a plain `String` by default. With the `compact` feature it is
+//! an [`InternedStr`] instead, which shares one allocation between equal
+//! strings deserialized on the same thread.
+
+#[cfg(not(feature = "compact"))]
+pub type Text = String;
+
+#[cfg(feature = "compact")]
+pub type Text = InternedStr;
+
+#[cfg(feature = "compact")]
+pub use interned::{clear_interner, InternedStr};
+
+#[cfg(feature = "compact")]
+mod interned {
+    use std::borrow::Borrow;
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::ops::Deref;
+    use std::sync::Arc;
+
+    use schemars::JsonSchema;
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    thread_local! {
+        static POOL: RefCell<HashSet<Arc<str>>> = RefCell::new(HashSet::new());
+    }
+
+    /// Drop the strings interned on this thread.
+    ///
+    /// Existing values are unaffected, but are no longer shared with values
+    /// deserialized afterwards.
+    pub fn clear_interner() {
+        POOL.with(|pool| pool.borrow_mut().clear());
+    }
+
+    /// A cheaply cloned, immutable string which is shared with equal strings
+    /// when deserialized.
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Default)]
+    pub struct InternedStr(Arc<str>);
+
+    impl InternedStr {
+        /// Get the shared copy of `value`, adding it to this thread's pool.
+        pub fn intern(value: &str) -> Self {
+            POOL.with(|pool| {
+                let mut pool = pool.borrow_mut();
+                match pool.get(value) {
+                    Some(shared) => Self(shared.clone()),
+                    None => {
+                        let shared: Arc<str> = value.into();
+                        pool.insert(shared.clone());
+                        Self(shared)
+                    }
+                }
+            })
+        }
+
+        pub fn as_str(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Deref for InternedStr {
+        type Target = str;
+
+        fn deref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl AsRef<str> for InternedStr {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl Borrow<str> for InternedStr {
+        fn borrow(&self) -> &str {
+            &self.0
+        }
+    }
+
+    impl fmt::Debug for InternedStr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt::Debug::fmt(&*self.0, f)
+        }
+    }
+
+    impl fmt::Display for InternedStr {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt::Display::fmt(&*self.0, f)
+        }
+    }
+
+    impl From<&str> for InternedStr {
+        fn from(value: &str) -> Self {
+            Self::intern(value)
+        }
+    }
+
+    impl From<String> for InternedStr {
+        fn from(value: String) -> Self {
+            Self::intern(&value)
+        }
+    }
+
+    impl From<InternedStr> for String {
+        fn from(value: InternedStr) -> Self {
+            value.0.as_ref().into()
+        }
+    }
+
+    impl PartialEq<str> for InternedStr {
+        fn eq(&self, other: &str) -> bool {
+            &*self.0 == other
+        }
+    }
+
+    impl PartialEq<&str> for InternedStr {
+        fn eq(&self, other: &&str) -> bool {
+            &*self.0 == *other
+        }
+    }
+
+    impl PartialEq<String> for InternedStr {
+        fn eq(&self, other: &String) -> bool {
+            &*self.0 == other
+        }
+    }
+
+    impl Serialize for InternedStr {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&self.0)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for InternedStr {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct InternedStrVisitor;
+
+            impl Visitor<'_> for InternedStrVisitor {
+                type Value = InternedStr;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("a string")
+                }
+
+                fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                    Ok(InternedStr::intern(value))
+                }
+            }
+
+            deserializer.deserialize_str(InternedStrVisitor)
+        }
+    }
+
+    impl JsonSchema for InternedStr {
+        fn schema_name() -> String {
+            String::schema_name()
+        }
+
+        fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+            String::json_schema(gen)
+        }
+    }
+}
@@ -0,0 +1,345 @@
+//! Serde helpers for the different timestamp encodings used by the API.
+//!
+//! Fields hold a [`Timestamp`] in Rust and pick their wire encoding with
+//! `#[serde(with = "...")]`:
+//!
+//! ```
+//! use phylum_types::types::timestamp::{self, Timestamp};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Example {
+//!     #[serde(with = "timestamp::epoch_seconds")]
+//!     created_at: Timestamp,
+//! }
+//! ```
+//!
+//! [`Timestamp`] is a chrono `DateTime<Utc>` whenever the `chrono` feature
+//! is enabled, as it is by default. Enabling `time` as well never changes
+//! the type; it only adds the `to_offset_date_time` and
+//! `from_offset_date_time` conversions. Consumers avoiding chrono choose the
+//! `time` backend explicitly by disabling default features and enabling
+//! `time`, in which case [`Timestamp`] wraps a `time::OffsetDateTime` in UTC.
+//! The wire encodings are the same with either backend, and the conversion
+//! functions in this module work with both.
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+compile_error!("either the `chrono` or the `time` feature is required");
+
+#[cfg(feature = "chrono")]
+pub use chrono_backend::*;
+#[cfg(not(feature = "chrono"))]
+pub use time_backend::*;
+
+#[cfg(feature = "chrono")]
+mod chrono_backend {
+    use chrono::{DateTime, TimeZone, Utc};
+
+    /// A point in time, as used by all API types.
+    pub type Timestamp = DateTime<Utc>;
+
+    /// The difference between two timestamps.
+    pub type Duration = chrono::Duration;
+
+    /// The timestamp `seconds` after the Unix epoch, if in range.
+    pub fn from_epoch_seconds(seconds: i64) -> Option<Timestamp> {
+        Utc.timestamp_opt(seconds, 0).single()
+    }
+
+    /// The timestamp `millis` milliseconds after the Unix epoch, if in range.
+    pub fn from_epoch_millis(millis: i64) -> Option<Timestamp> {
+        Utc.timestamp_millis_opt(millis).single()
+    }
+
+    /// `value` plus `duration`, if in range.
+    pub fn checked_add(value: &Timestamp, duration: Duration) -> Option<Timestamp> {
+        value.checked_add_signed(duration)
+    }
+
+    /// `value` minus `duration`, if in range.
+    pub fn checked_sub(value: &Timestamp, duration: Duration) -> Option<Timestamp> {
+        value.checked_sub_signed(duration)
+    }
+
+    /// Parse an RFC 2822 date, such as `Fri, 15 Apr 2022 05:20:00 +0000`.
+    pub fn from_rfc2822(value: &str) -> Option<Timestamp> {
+        DateTime::parse_from_rfc2822(value)
+            .ok()
+            .map(|value| value.with_timezone(&Utc))
+    }
+
+    /// Whole seconds since the Unix epoch.
+    pub fn to_epoch_seconds(value: &Timestamp) -> i64 {
+        value.timestamp()
+    }
+
+    /// Whole milliseconds since the Unix epoch.
+    pub fn to_epoch_millis(value: &Timestamp) -> i64 {
+        value.timestamp_millis()
+    }
+
+    /// The RFC 3339 form used on the wire, such as `2022-06-01T12:00:00Z`.
+    pub fn to_rfc3339(value: &Timestamp) -> String {
+        // The `Debug` output is what serde uses, and unlike `to_rfc3339` does
+        // not need chrono's `alloc` feature
+        format!("{value:?}")
+    }
+
+    /// The same point in time as a `time::OffsetDateTime`, if in its range.
+    #[cfg(feature = "time")]
+    pub fn to_offset_date_time(value: &Timestamp) -> Option<time::OffsetDateTime> {
+        let nanos = time::Duration::nanoseconds(value.timestamp_subsec_nanos().into());
+        time::OffsetDateTime::from_unix_timestamp(value.timestamp())
+            .ok()?
+            .checked_add(nanos)
+    }
+
+    /// The timestamp of a `time::OffsetDateTime`, if in range.
+    #[cfg(feature = "time")]
+    pub fn from_offset_date_time(value: time::OffsetDateTime) -> Option<Timestamp> {
+        Utc.timestamp_opt(value.unix_timestamp(), value.nanosecond())
+            .single()
+    }
+}
+
+#[cfg(not(feature = "chrono"))]
+mod time_backend {
+    use std::convert::TryFrom;
+    use std::fmt;
+    use std::ops::{Add, Deref, Sub};
+    use std::str::FromStr;
+
+    use schemars::JsonSchema;
+    use serde::de::{self, Visitor};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use time::format_description::well_known::{Rfc2822, Rfc3339};
+    use time::{OffsetDateTime, UtcOffset};
+
+    /// The difference between two timestamps.
+    pub type Duration = time::Duration;
+
+    /// A point in time in UTC, as used by all API types.
+    ///
+    /// A thin wrapper rather than an alias, as `OffsetDateTime` has no
+    /// `JsonSchema` implementation.
+    #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+    pub struct Timestamp(OffsetDateTime);
+
+    impl Timestamp {
+        /// The same point in time, converted to UTC.
+        pub fn new(value: OffsetDateTime) -> Self {
+            Self(value.to_offset(UtcOffset::UTC))
+        }
+
+        pub fn into_inner(self) -> OffsetDateTime {
+            self.0
+        }
+    }
+
+    impl Default for Timestamp {
+        fn default() -> Self {
+            Self(OffsetDateTime::UNIX_EPOCH)
+        }
+    }
+
+    impl Deref for Timestamp {
+        type Target = OffsetDateTime;
+
+        fn deref(&self) -> &OffsetDateTime {
+            &self.0
+        }
+    }
+
+    impl From<OffsetDateTime> for Timestamp {
+        fn from(value: OffsetDateTime) -> Self {
+            Self::new(value)
+        }
+    }
+
+    impl From<Timestamp> for OffsetDateTime {
+        fn from(value: Timestamp) -> Self {
+            value.0
+        }
+    }
+
+    impl Sub for Timestamp {
+        type Output = Duration;
+
+        fn sub(self, other: Timestamp) -> Duration {
+            self.0 - other.0
+        }
+    }
+
+    impl Add<Duration> for Timestamp {
+        type Output = Timestamp;
+
+        fn add(self, duration: Duration) -> Timestamp {
+            Self(self.0 + duration)
+        }
+    }
+
+    impl Sub<Duration> for Timestamp {
+        type Output = Timestamp;
+
+        fn sub(self, duration: Duration) -> Timestamp {
+            Self(self.0 - duration)
+        }
+    }
+
+    impl fmt::Display for Timestamp {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            let formatted = self.0.format(&Rfc3339).map_err(|_| fmt::Error)?;
+            f.write_str(&formatted)
+        }
+    }
+
+    impl fmt::Debug for Timestamp {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            fmt::Display::fmt(self, f)
+        }
+    }
+
+    impl Serialize for Timestamp {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl FromStr for Timestamp {
+        type Err = time::error::Parse;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            OffsetDateTime::parse(s, &Rfc3339).map(Self::new)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Timestamp {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct TimestampVisitor;
+
+            impl Visitor<'_> for TimestampVisitor {
+                type Value = Timestamp;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("an RFC 3339 timestamp")
+                }
+
+                fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                    value.parse().map_err(E::custom)
+                }
+            }
+
+            deserializer.deserialize_str(TimestampVisitor)
+        }
+    }
+
+    impl JsonSchema for Timestamp {
+        fn schema_name() -> String {
+            "DateTime".into()
+        }
+
+        fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+            let mut schema = String::json_schema(gen).into_object();
+            schema.format = Some("date-time".into());
+            schema.into()
+        }
+    }
+
+    /// The timestamp `seconds` after the Unix epoch, if in range.
+    pub fn from_epoch_seconds(seconds: i64) -> Option<Timestamp> {
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .ok()
+            .map(Timestamp)
+    }
+
+    /// The timestamp `millis` milliseconds after the Unix epoch, if in range.
+    pub fn from_epoch_millis(millis: i64) -> Option<Timestamp> {
+        OffsetDateTime::from_unix_timestamp_nanos(i128::from(millis) * 1_000_000)
+            .ok()
+            .map(Timestamp)
+    }
+
+    /// `value` plus `duration`, if in range.
+    pub fn checked_add(value: &Timestamp, duration: Duration) -> Option<Timestamp> {
+        value.0.checked_add(duration).map(Timestamp)
+    }
+
+    /// `value` minus `duration`, if in range.
+    pub fn checked_sub(value: &Timestamp, duration: Duration) -> Option<Timestamp> {
+        value.0.checked_sub(duration).map(Timestamp)
+    }
+
+    /// Parse an RFC 2822 date, such as `Fri, 15 Apr 2022 05:20:00 +0000`.
+    pub fn from_rfc2822(value: &str) -> Option<Timestamp> {
+        OffsetDateTime::parse(value, &Rfc2822)
+            .ok()
+            .map(Timestamp::new)
+    }
+
+    /// Whole seconds since the Unix epoch.
+    pub fn to_epoch_seconds(value: &Timestamp) -> i64 {
+        value.0.unix_timestamp()
+    }
+
+    /// Whole milliseconds since the Unix epoch.
+    pub fn to_epoch_millis(value: &Timestamp) -> i64 {
+        i64::try_from(value.0.unix_timestamp_nanos().div_euclid(1_000_000)).unwrap_or(i64::MAX)
+    }
+
+    /// The RFC 3339 form used on the wire, such as `2022-06-01T12:00:00Z`.
+    pub fn to_rfc3339(value: &Timestamp) -> String {
+        value.to_string()
+    }
+}
+
+/// Timestamps encoded as integer seconds since the Unix epoch.
+pub mod epoch_seconds {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Timestamp;
+
+    pub fn serialize<S: Serializer>(value: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(super::to_epoch_seconds(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let seconds = i64::deserialize(deserializer)?;
+        super::from_epoch_seconds(seconds)
+            .ok_or_else(|| D::Error::custom(format!("timestamp out of range: {seconds}")))
+    }
+}
+
+/// Timestamps encoded as integer milliseconds since the Unix epoch.
+pub mod epoch_millis {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Timestamp;
+
+    pub fn serialize<S: Serializer>(value: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(super::to_epoch_millis(value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        let millis = i64::deserialize(deserializer)?;
+        super::from_epoch_millis(millis)
+            .ok_or_else(|| D::Error::custom(format!("timestamp out of range: {millis}")))
+    }
+}
+
+/// Timestamps encoded as RFC 3339 strings, such as
+/// `2022-06-01T12:00:00Z`.
+pub mod rfc3339 {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Timestamp;
+
+    pub fn serialize<S: Serializer>(value: &Timestamp, serializer: S) -> Result<S::Ok, S::Error> {
+        value.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Timestamp, D::Error> {
+        Timestamp::deserialize(deserializer)
+    }
+}
@@ -0,0 +1,107 @@
+//! This module contains types for managing long-lived API tokens, which
+//! authenticate CI systems and other non-interactive clients.
+//!
+//! The tokens themselves, and the requests creating them, are the ones in
+//! [`user`](super::user); the aliases here are kept for compatibility.
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::timestamp::Timestamp;
+use super::user::{CreateTokenRequest, CreateTokenResponse, UserToken};
+
+/// What a token is allowed to do
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub enum TokenScope {
+    #[serde(rename = "projects:read")]
+    ReadProjects,
+    #[serde(rename = "projects:write")]
+    WriteProjects,
+    #[serde(rename = "jobs:submit")]
+    SubmitJobs,
+    #[serde(rename = "packages:read")]
+    ReadPackages,
+    #[serde(rename = "groups:manage")]
+    ManageGroups,
+    /// Everything the owner of the token may do
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl TokenScope {
+    /// The name used on the wire, such as `projects:read`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TokenScope::ReadProjects => "projects:read",
+            TokenScope::WriteProjects => "projects:write",
+            TokenScope::SubmitJobs => "jobs:submit",
+            TokenScope::ReadPackages => "packages:read",
+            TokenScope::ManageGroups => "groups:manage",
+            TokenScope::Admin => "admin",
+        }
+    }
+
+    /// Whether a token with this scope may also do what `other` allows.
+    pub fn implies(&self, other: TokenScope) -> bool {
+        match self {
+            TokenScope::Admin => true,
+            TokenScope::WriteProjects => {
+                matches!(other, TokenScope::WriteProjects | TokenScope::ReadProjects)
+            }
+            scope => *scope == other,
+        }
+    }
+}
+
+impl fmt::Display for TokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Create an API token
+#[deprecated(note = "use `user::CreateTokenRequest`")]
+pub type CreateApiTokenRequest = CreateTokenRequest;
+
+/// An API token, without its secret value
+#[deprecated(note = "use `user::UserToken`")]
+pub type ApiToken = UserToken;
+
+/// Response to creating an API token; the only time its secret is returned
+#[deprecated(note = "use `user::CreateTokenResponse`")]
+pub type CreateApiTokenResponse = CreateTokenResponse;
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ListApiTokensResponse {
+    pub tokens: Vec<UserToken>,
+}
+
+impl ListApiTokensResponse {
+    /// Tokens still usable at `now`.
+    pub fn active_at(&self, now: Timestamp) -> impl Iterator<Item = &UserToken> {
+        self.tokens
+            .iter()
+            .filter(move |token| !token.is_expired_at(now))
+    }
+}
+
+/// Revoke an API token, so it can no longer be used
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct RevokeTokenRequest {
+    pub id: Uuid,
+}
+
+impl RevokeTokenRequest {
+    pub fn new(id: Uuid) -> Self {
+        Self { id }
+    }
+}
@@ -0,0 +1,220 @@
+//! This module contains trend analysis of score dynamics, summarizing how the
+//! score of a package changed over time.
+//!
+//! ```
+//! use phylum_types::types::package::ScoreDynamicsPoint;
+//! use phylum_types::types::trend::{ScoreDynamics, TrendDirection};
+//!
+//! let point = |date: &str, score| ScoreDynamicsPoint {
+//!     date_time: date.parse().unwrap(),
+//!     score,
+//!     label: "main".into(),
+//! };
+//! let points = vec![
+//!     point("2022-01-01T00:00:00Z", 0.5),
+//!     point("2022-02-01T00:00:00Z", 0.6),
+//!     point("2022-03-01T00:00:00Z", 0.8),
+//! ];
+//!
+//! assert_eq!(points.trend().unwrap().direction, TrendDirection::Improving);
+//! assert_eq!(points.worst_point().unwrap().score, 0.5);
+//! ```
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::package::ScoreDynamicsPoint;
+use super::timestamp::{self, Duration, Timestamp};
+
+/// Slopes smaller than this, in score per day, are considered stable.
+pub const STABLE_SLOPE_PER_DAY: f64 = 0.001;
+
+/// Most points [`ScoreDynamics::resample`] produces.
+pub const MAX_RESAMPLED_POINTS: usize = 10_000;
+
+const MILLIS_PER_DAY: f64 = 86_400_000.;
+
+/// Which way a score is heading
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendDirection {
+    Improving,
+    Stable,
+    Declining,
+}
+
+/// A linear fit of a score over time
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreTrend {
+    pub direction: TrendDirection,
+    /// Change of the fitted score per day
+    #[serde(alias = "slope_per_day")]
+    pub slope_per_day: f64,
+    /// Time of the earliest point
+    pub start: Timestamp,
+    /// Time of the latest point
+    pub end: Timestamp,
+    /// Number of points fitted
+    pub points: usize,
+}
+
+/// The change of a score within a window of time
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreDelta {
+    /// Time of the point compared against
+    pub from: Timestamp,
+    /// Time of the latest point
+    pub to: Timestamp,
+    #[serde(alias = "from_score")]
+    pub from_score: f32,
+    #[serde(alias = "to_score")]
+    pub to_score: f32,
+    /// `to_score - from_score`, positive when the score improved
+    pub change: f32,
+}
+
+/// Analysis of a series of score points.
+///
+/// Points may be in any order; they are ordered by time before analysis.
+pub trait ScoreDynamics {
+    /// The direction of a least-squares fit of the score over time.
+    ///
+    /// `None` without at least two points at different times.
+    fn trend(&self) -> Option<ScoreTrend>;
+
+    /// The change from the score `window` before the latest point to the
+    /// latest score.
+    ///
+    /// Compares against the earliest point when the series is shorter than
+    /// `window`. `None` if there are no points.
+    fn delta_over(&self, window: Duration) -> Option<ScoreDelta>;
+
+    /// The point with the lowest score, the earliest one if tied.
+    fn worst_point(&self) -> Option<&ScoreDynamicsPoint>;
+
+    /// The score every `interval` from the earliest point to the latest one,
+    /// each taken from the latest point at or before that time.
+    ///
+    /// Empty if there are no points, `interval` is not positive, or it is so
+    /// small that there would be more than [`MAX_RESAMPLED_POINTS`].
+    fn resample(&self, interval: Duration) -> Vec<ScoreDynamicsPoint>;
+}
+
+impl ScoreDynamics for [ScoreDynamicsPoint] {
+    fn trend(&self) -> Option<ScoreTrend> {
+        let points = by_time(self);
+        let (first, last) = (points.first()?, points.last()?);
+
+        let origin = timestamp::to_epoch_millis(&first.date_time);
+        let days = |point: &ScoreDynamicsPoint| {
+            (timestamp::to_epoch_millis(&point.date_time) - origin) as f64 / MILLIS_PER_DAY
+        };
+
+        let count = points.len() as f64;
+        let mean_x = points.iter().map(|point| days(point)).sum::<f64>() / count;
+        let mean_y = points
+            .iter()
+            .map(|point| f64::from(point.score))
+            .sum::<f64>()
+            / count;
+        let (mut covariance, mut variance) = (0., 0.);
+        for point in &points {
+            let dx = days(point) - mean_x;
+            covariance += dx * (f64::from(point.score) - mean_y);
+            variance += dx * dx;
+        }
+        if variance == 0. {
+            return None;
+        }
+
+        let slope_per_day = covariance / variance;
+        let direction = if slope_per_day.abs() < STABLE_SLOPE_PER_DAY {
+            TrendDirection::Stable
+        } else if slope_per_day > 0. {
+            TrendDirection::Improving
+        } else {
+            TrendDirection::Declining
+        };
+
+        Some(ScoreTrend {
+            direction,
+            slope_per_day,
+            start: first.date_time,
+            end: last.date_time,
+            points: points.len(),
+        })
+    }
+
+    fn delta_over(&self, window: Duration) -> Option<ScoreDelta> {
+        let points = by_time(self);
+        let latest = *points.last()?;
+        let since = timestamp::checked_sub(&latest.date_time, window);
+        let baseline = points
+            .iter()
+            .rev()
+            .find(|point| since.is_some_and(|since| point.date_time <= since))
+            .or_else(|| points.first())?;
+
+        Some(ScoreDelta {
+            from: baseline.date_time,
+            to: latest.date_time,
+            from_score: baseline.score,
+            to_score: latest.score,
+            change: latest.score - baseline.score,
+        })
+    }
+
+    fn worst_point(&self) -> Option<&ScoreDynamicsPoint> {
+        by_time(self).into_iter().reduce(|worst, point| {
+            if point.score < worst.score {
+                point
+            } else {
+                worst
+            }
+        })
+    }
+
+    fn resample(&self, interval: Duration) -> Vec<ScoreDynamicsPoint> {
+        let points = by_time(self);
+        let (first, last) = match (points.first(), points.last()) {
+            (Some(first), Some(last)) if interval > Duration::seconds(0) => (first, last),
+            _ => return Vec::new(),
+        };
+
+        let times: Vec<_> = std::iter::successors(Some(first.date_time), |time| {
+            timestamp::checked_add(time, interval)
+        })
+        .take_while(|time| *time <= last.date_time)
+        .take(MAX_RESAMPLED_POINTS + 1)
+        .collect();
+        if times.len() > MAX_RESAMPLED_POINTS {
+            return Vec::new();
+        }
+
+        let mut current = 0;
+        times
+            .into_iter()
+            .map(|time| {
+                while current + 1 < points.len() && points[current + 1].date_time <= time {
+                    current += 1;
+                }
+                ScoreDynamicsPoint {
+                    date_time: time,
+                    score: points[current].score,
+                    label: points[current].label.clone(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// The points ordered by time, keeping the order of points at the same time.
+fn by_time(points: &[ScoreDynamicsPoint]) -> Vec<&ScoreDynamicsPoint> {
+    let mut points: Vec<_> = points.iter().collect();
+    points.sort_by_key(|point| point.date_time);
+    points
+}
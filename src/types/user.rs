@@ -0,0 +1,144 @@
+//! This module contains types describing users and their tokens.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::UserId;
+use super::timestamp::Timestamp;
+use super::token::TokenScope;
+
+/// Number of trailing characters of a token left visible when masked.
+const VISIBLE_CHARS: usize = 4;
+
+/// Role of a user within Phylum
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum UserRole {
+    User,
+    Admin,
+}
+
+/// Profile of a user, as returned by the user info endpoints
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct UserProfile {
+    pub id: UserId,
+    pub email: String,
+    #[serde(alias = "firstName")]
+    pub first_name: String,
+    #[serde(alias = "lastName")]
+    pub last_name: String,
+    /// Names of the groups the user is a member of
+    #[serde(default)]
+    pub groups: Vec<String>,
+    #[serde(default)]
+    pub roles: Vec<UserRole>,
+    /// Tokens the user has created
+    #[serde(default)]
+    pub tokens: Vec<UserToken>,
+}
+
+impl UserProfile {
+    pub fn is_admin(&self) -> bool {
+        self.roles.contains(&UserRole::Admin)
+    }
+}
+
+/// Metadata of a user's token; never contains the secret itself
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct UserToken {
+    pub id: Uuid,
+    pub name: String,
+    /// The value with all but its last characters hidden, such as `****a1b2`
+    #[serde(alias = "maskedValue")]
+    pub masked_value: String,
+    /// What the token may do; every scope of its owner if empty
+    #[serde(default)]
+    pub scopes: Vec<TokenScope>,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    /// When the token stops being valid, if ever
+    #[serde(default, alias = "expiresAt")]
+    pub expires_at: Option<Timestamp>,
+    /// When the token last authenticated a request, if ever
+    #[serde(default, alias = "lastUsedAt")]
+    pub last_used_at: Option<Timestamp>,
+}
+
+impl UserToken {
+    /// Hide all but the last few characters of a token value.
+    pub fn mask(value: &str) -> String {
+        let chars = value.chars().count();
+        let visible: String = value
+            .chars()
+            .skip(chars.saturating_sub(VISIBLE_CHARS))
+            .collect();
+        format!("****{visible}")
+    }
+
+    /// Whether the token can no longer be used at `now`.
+    pub fn is_expired_at(&self, now: Timestamp) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+
+    /// Whether the token may do what `scope` allows.
+    ///
+    /// Tokens without scopes have every scope of their owner.
+    pub fn allows(&self, scope: TokenScope) -> bool {
+        self.scopes.is_empty() || self.scopes.iter().any(|own| own.implies(scope))
+    }
+}
+
+/// Request to create a new token for the current user
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CreateTokenRequest {
+    /// A name to tell the token apart from others, such as the CI using it
+    pub name: String,
+    /// When the token should stop being valid; `None` for no expiry
+    #[serde(default, skip_serializing_if = "Option::is_none", alias = "expiresAt")]
+    pub expires_at: Option<Timestamp>,
+    /// What the token may do; every scope of its owner if empty
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<TokenScope>,
+}
+
+impl CreateTokenRequest {
+    /// A request for a token with every scope of its owner, which never
+    /// expires.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_expires_at(mut self, expires_at: Timestamp) -> Self {
+        self.expires_at = Some(expires_at);
+        self
+    }
+
+    pub fn with_scopes(mut self, scopes: Vec<TokenScope>) -> Self {
+        self.scopes = scopes;
+        self
+    }
+}
+
+/// Response of a create token request
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CreateTokenResponse {
+    /// The secret token value; it cannot be retrieved again later
+    pub token: String,
+    #[serde(flatten)]
+    pub metadata: UserToken,
+}
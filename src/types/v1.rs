@@ -0,0 +1,295 @@
+//! Version 1 of the package types.
+//!
+//! These are the shapes of the original API: packages described by a
+//! [`PackageType`] or a free-form registry name, and the heuristic and
+//! vulnerability results of the old package responses. They remain available,
+//! also from [`crate::types::package`], for clients of older endpoints; new
+//! code should use the package references of [`crate::types::v2`], which
+//! convert from each of them.
+
+// The deprecated package types are defined here, along with their
+// conversions to each other
+#![allow(deprecated)]
+
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::lockfile::LockfilePath;
+use crate::types::package::{
+    DependencyKind, ExploitMaturity, PackageType, Reference, RiskDomain, RiskLevel,
+};
+use crate::types::remediation::RemediationInfo;
+
+/// The results of an individual heuristic run
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HeuristicResult {
+    /// The risk domain
+    pub domain: RiskDomain,
+    /// The score
+    pub score: f64,
+    /// The risk level bucket it falls into
+    #[serde(alias = "riskLevel")]
+    pub risk_level: RiskLevel,
+}
+
+/// A vulnerability
+#[derive(PartialEq, PartialOrd, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Vulnerability {
+    /// If this vulnerability falls into one or more known CVEs
+    pub cve: Vec<String>,
+    /// Severity of the vulnerability
+    #[serde(rename = "severity")]
+    pub base_severity: f32,
+    /// What risk level bucket it falls into
+    #[serde(alias = "riskLevel")]
+    pub risk_level: RiskLevel,
+    /// Title of the vulnerability
+    pub title: String,
+    /// A more in depth description
+    pub description: String,
+    /// Remediation information if known
+    pub remediation: String,
+    /// Structured form of `remediation`, when the API provides one
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "remediationDetails"
+    )]
+    pub remediation_details: Option<Box<RemediationInfo>>,
+    /// Links to further information
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<Reference>,
+    /// How far exploitation has progressed, if known
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "exploitMaturity"
+    )]
+    pub exploit_maturity: Option<ExploitMaturity>,
+}
+
+/// Describes a package in the system
+#[deprecated(note = "use v2::PackageRef")]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageDescriptor {
+    pub name: String,
+    pub version: String,
+    #[serde(rename = "type", alias = "registry")]
+    pub package_type: PackageType,
+    /// The Maven group, npm scope or Go module path prefix, if it is not
+    /// already part of `name`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+impl PackageDescriptor {
+    pub fn new(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        package_type: PackageType,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            package_type,
+            namespace: None,
+        }
+    }
+
+    pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Describe a Maven package from its coordinates.
+    pub fn maven(
+        group: impl Into<String>,
+        artifact: impl Into<String>,
+        version: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: artifact.into(),
+            version: version.into(),
+            package_type: PackageType::Maven,
+            namespace: Some(group.into()),
+        }
+    }
+
+    /// Describe a Conan package from its reference, such as
+    /// `zlib/1.2.13@user/channel`.
+    ///
+    /// Conan packages have no namespace; the user and channel, if any, stay
+    /// part of the version. Returns `None` if the reference has no version.
+    pub fn conan(reference: &str) -> Option<Self> {
+        let (name, version) = reference.split_once('/')?;
+        if name.is_empty() || version.is_empty() {
+            return None;
+        }
+        Some(Self::new(name, version, PackageType::Conan))
+    }
+
+    /// Split a full package name into its namespace and local name, as
+    /// appropriate for the ecosystem.
+    pub fn split_name(package_type: PackageType, full_name: &str) -> (Option<&str>, &str) {
+        let split = match package_type {
+            PackageType::Maven => full_name.split_once(':'),
+            PackageType::Npm if full_name.starts_with('@') => full_name.split_once('/'),
+            PackageType::Golang => full_name.rsplit_once('/'),
+            PackageType::Composer => full_name.split_once('/'),
+            _ => None,
+        };
+
+        match split {
+            Some((namespace, name)) => (Some(namespace), name),
+            None => (None, full_name),
+        }
+    }
+
+    /// The namespace of this package, whether it is stored in `namespace` or
+    /// as part of `name`.
+    pub fn namespace(&self) -> Option<&str> {
+        match &self.namespace {
+            Some(namespace) => Some(namespace),
+            None => Self::split_name(self.package_type, &self.name).0,
+        }
+    }
+
+    /// The name of this package without its namespace.
+    pub fn local_name(&self) -> &str {
+        match &self.namespace {
+            Some(_) => &self.name,
+            None => Self::split_name(self.package_type, &self.name).1,
+        }
+    }
+
+    /// The name of this package including its namespace, as used in
+    /// lockfiles and registries.
+    pub fn full_name(&self) -> String {
+        match (&self.namespace, self.package_type.namespace_separator()) {
+            (Some(namespace), Some(separator)) => {
+                format!("{namespace}{separator}{}", self.name)
+            }
+            _ => self.name.clone(),
+        }
+    }
+
+    /// Move any namespace embedded in `name` into `namespace`.
+    pub fn into_structured(self) -> Self {
+        if self.namespace.is_some() {
+            return self;
+        }
+
+        match Self::split_name(self.package_type, &self.name) {
+            (Some(namespace), name) => Self {
+                namespace: Some(namespace.into()),
+                name: name.into(),
+                ..self
+            },
+            (None, _) => self,
+        }
+    }
+}
+
+/// A package named by a free-form registry
+#[deprecated(note = "use v2::PackageRef")]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageSpecifier {
+    #[serde(alias = "type")]
+    pub registry: String,
+    pub name: String,
+    pub version: String,
+}
+
+impl From<&PackageDescriptor> for PackageSpecifier {
+    fn from(descriptor: &PackageDescriptor) -> Self {
+        Self {
+            registry: descriptor.package_type.to_string(),
+            name: descriptor.full_name(),
+            version: descriptor.version.clone(),
+        }
+    }
+}
+
+impl TryFrom<PackageSpecifier> for PackageDescriptor {
+    type Error = String;
+
+    fn try_from(value: PackageSpecifier) -> Result<Self, Self::Error> {
+        let PackageSpecifier {
+            registry,
+            name,
+            version,
+        } = value;
+        let package_type = PackageType::from_str(&registry)
+            .map_err(|()| format!("Failed to convert registry {registry} to package type"))?;
+        Ok(PackageDescriptor {
+            name,
+            version,
+            package_type,
+            namespace: None,
+        })
+    }
+}
+
+/// `PackageDescriptorAndLockfile` represents a parsed package
+/// (`package_descriptor`) and the optional path to its lockfile (`lockfile`).
+#[deprecated(note = "use v2::PackageRefAndLockfile")]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageDescriptorAndLockfile {
+    #[serde(flatten)]
+    pub package_descriptor: PackageDescriptor,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lockfile: Option<LockfilePath>,
+    /// How the package is depended upon; runtime unless stated
+    #[serde(default, skip_serializing_if = "DependencyKind::is_runtime")]
+    pub kind: DependencyKind,
+}
+
+impl From<&PackageDescriptor> for PackageDescriptorAndLockfile {
+    fn from(value: &PackageDescriptor) -> Self {
+        PackageDescriptorAndLockfile {
+            package_descriptor: value.clone(),
+            lockfile: None,
+            kind: DependencyKind::Runtime,
+        }
+    }
+}
+
+impl From<PackageDescriptor> for PackageDescriptorAndLockfile {
+    fn from(package_descriptor: PackageDescriptor) -> Self {
+        Self {
+            package_descriptor,
+            lockfile: None,
+            kind: DependencyKind::Runtime,
+        }
+    }
+}
+
+/// `PackageSpecifierAndLockfile` represents a parsed package
+/// (`package_specifier`) and the optional path to its lockfile (`lockfile`).
+#[deprecated(note = "use v2::PackageRefAndLockfile")]
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageSpecifierAndLockfile {
+    #[serde(alias = "packageSpecifier")]
+    pub package_specifier: PackageSpecifier,
+    pub lockfile: Option<LockfilePath>,
+}
+
+impl From<&PackageSpecifier> for PackageSpecifierAndLockfile {
+    fn from(value: &PackageSpecifier) -> Self {
+        PackageSpecifierAndLockfile {
+            package_specifier: value.clone(),
+            lockfile: None,
+        }
+    }
+}
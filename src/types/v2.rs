@@ -0,0 +1,370 @@
+//! Version 2 of the package types.
+//!
+//! Packages are referenced by an [`Ecosystem`], which keeps registry names
+//! unknown to this crate instead of rejecting them. Every version 1 type
+//! converts into its version 2 replacement without loss, and back where the
+//! ecosystem is known.
+//!
+//! Heuristic results identify the heuristic which produced them and carry its
+//! raw output, as the heuristics service now emits.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::types::lockfile::LockfilePath;
+use crate::types::package::{DependencyKind, PackageType, RiskDomain, RiskLevel};
+#[allow(deprecated)]
+use crate::types::v1::{
+    self, PackageDescriptor, PackageDescriptorAndLockfile, PackageSpecifier,
+    PackageSpecifierAndLockfile,
+};
+
+/// A package ecosystem, as named by a registry.
+///
+/// Names which don't match a known [`PackageType`] exactly are kept verbatim,
+/// so no information is lost when converting from free-form registry names.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum Ecosystem {
+    Known(PackageType),
+    Unknown(String),
+}
+
+impl Ecosystem {
+    /// The ecosystem of a name as written by people or other tools, such as
+    /// ` Python` or `maven-central`.
+    ///
+    /// Names of known ecosystems are matched leniently; any other name is
+    /// kept, without surrounding whitespace.
+    pub fn normalize(name: &str) -> Self {
+        let name = name.trim();
+        match name.parse() {
+            Ok(package_type) => Self::Known(package_type),
+            Err(()) => Self::Unknown(name.into()),
+        }
+    }
+
+    /// This ecosystem with an alias of a known ecosystem replaced by its
+    /// package type.
+    pub fn normalized(&self) -> Self {
+        match self {
+            Ecosystem::Known(package_type) => Self::Known(*package_type),
+            Ecosystem::Unknown(name) => Self::normalize(name),
+        }
+    }
+
+    /// The package type of this ecosystem, if it can be determined.
+    ///
+    /// Unknown names are parsed leniently, so `maven-central` is `Maven`.
+    pub fn package_type(&self) -> Option<PackageType> {
+        match self {
+            Ecosystem::Known(package_type) => Some(*package_type),
+            Ecosystem::Unknown(name) => name.trim().parse().ok(),
+        }
+    }
+}
+
+impl From<PackageType> for Ecosystem {
+    fn from(package_type: PackageType) -> Self {
+        Self::Known(package_type)
+    }
+}
+
+impl From<String> for Ecosystem {
+    fn from(name: String) -> Self {
+        let known = PackageType::VARIANTS
+            .iter()
+            .find(|(variant_name, _)| *variant_name == name);
+        match known {
+            Some((_, package_type)) => Self::Known(*package_type),
+            None => Self::Unknown(name),
+        }
+    }
+}
+
+impl From<&str> for Ecosystem {
+    fn from(name: &str) -> Self {
+        Self::from(name.to_owned())
+    }
+}
+
+impl From<Ecosystem> for String {
+    fn from(ecosystem: Ecosystem) -> Self {
+        ecosystem.to_string()
+    }
+}
+
+impl fmt::Display for Ecosystem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Ecosystem::Known(package_type) => package_type.fmt(f),
+            Ecosystem::Unknown(name) => f.write_str(name),
+        }
+    }
+}
+
+impl JsonSchema for Ecosystem {
+    fn schema_name() -> String {
+        "Ecosystem".into()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// Reference to a specific package version.
+///
+/// Replaces both [`PackageDescriptor`] and [`PackageSpecifier`], and can be
+/// converted from either without loss.
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageRef {
+    pub name: String,
+    pub version: String,
+    #[serde(rename = "type", alias = "registry")]
+    pub ecosystem: Ecosystem,
+    /// The Maven group, npm scope or Go module path prefix, if it is not
+    /// already part of `name`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+}
+
+#[allow(deprecated)]
+impl PackageRef {
+    pub fn new(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        ecosystem: impl Into<Ecosystem>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            ecosystem: ecosystem.into(),
+            namespace: None,
+        }
+    }
+
+    /// Refer to a Conan package by its reference, such as
+    /// `zlib/1.2.13@user/channel`.
+    ///
+    /// As with [`PackageDescriptor::conan`], the user and channel, if any,
+    /// stay part of the version. Returns `None` if the reference has no
+    /// version.
+    pub fn conan(reference: &str) -> Option<Self> {
+        PackageDescriptor::conan(reference).map(Self::from)
+    }
+
+    pub fn package_type(&self) -> Option<PackageType> {
+        self.ecosystem.package_type()
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageDescriptor> for PackageRef {
+    fn from(descriptor: PackageDescriptor) -> Self {
+        Self {
+            name: descriptor.name,
+            version: descriptor.version,
+            ecosystem: descriptor.package_type.into(),
+            namespace: descriptor.namespace,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<&PackageDescriptor> for PackageRef {
+    fn from(descriptor: &PackageDescriptor) -> Self {
+        descriptor.clone().into()
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageSpecifier> for PackageRef {
+    fn from(specifier: PackageSpecifier) -> Self {
+        Self {
+            name: specifier.name,
+            version: specifier.version,
+            ecosystem: specifier.registry.into(),
+            namespace: None,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<&PackageSpecifier> for PackageRef {
+    fn from(specifier: &PackageSpecifier) -> Self {
+        specifier.clone().into()
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageRef> for PackageSpecifier {
+    fn from(package: PackageRef) -> Self {
+        let name = match &package.namespace {
+            Some(namespace) => {
+                let separator = package
+                    .package_type()
+                    .and_then(|package_type| package_type.namespace_separator())
+                    .unwrap_or('/');
+                format!("{namespace}{separator}{}", package.name)
+            }
+            None => package.name,
+        };
+
+        Self {
+            registry: package.ecosystem.to_string(),
+            name,
+            version: package.version,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<PackageRef> for PackageDescriptor {
+    type Error = String;
+
+    fn try_from(package: PackageRef) -> Result<Self, Self::Error> {
+        let package_type = package.package_type().ok_or_else(|| {
+            format!(
+                "Failed to convert registry {} to package type",
+                package.ecosystem
+            )
+        })?;
+        Ok(PackageDescriptor {
+            name: package.name,
+            version: package.version,
+            package_type,
+            namespace: package.namespace,
+        })
+    }
+}
+
+/// `PackageRefAndLockfile` represents a package (`package`) and the
+/// optional path to its lockfile (`lockfile`).
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PackageRefAndLockfile {
+    #[serde(flatten)]
+    pub package: PackageRef,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lockfile: Option<LockfilePath>,
+    /// How the package is depended upon; runtime unless stated
+    #[serde(default, skip_serializing_if = "DependencyKind::is_runtime")]
+    pub kind: DependencyKind,
+}
+
+impl From<PackageRef> for PackageRefAndLockfile {
+    fn from(package: PackageRef) -> Self {
+        Self {
+            package,
+            lockfile: None,
+            kind: DependencyKind::Runtime,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageDescriptorAndLockfile> for PackageRefAndLockfile {
+    fn from(value: PackageDescriptorAndLockfile) -> Self {
+        Self {
+            package: value.package_descriptor.into(),
+            lockfile: value.lockfile,
+            kind: value.kind,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl From<PackageSpecifierAndLockfile> for PackageRefAndLockfile {
+    fn from(value: PackageSpecifierAndLockfile) -> Self {
+        Self {
+            package: value.package_specifier.into(),
+            lockfile: value.lockfile,
+            kind: DependencyKind::Runtime,
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl TryFrom<PackageRefAndLockfile> for PackageDescriptorAndLockfile {
+    type Error = String;
+
+    fn try_from(value: PackageRefAndLockfile) -> Result<Self, Self::Error> {
+        Ok(Self {
+            package_descriptor: PackageDescriptor::try_from(value.package)?,
+            lockfile: value.lockfile,
+            kind: value.kind,
+        })
+    }
+}
+
+/// The results of an individual heuristic run
+///
+/// Version 1 payloads, with only a domain, score and risk level, parse with
+/// the other fields empty and a weight of 1.
+#[derive(PartialEq, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct HeuristicResult {
+    /// Identifier of the heuristic, such as `typosquatting`
+    #[serde(default, alias = "id", alias = "heuristicId")]
+    pub heuristic_id: String,
+    /// Display name of the heuristic
+    #[serde(default)]
+    pub name: String,
+    /// What the heuristic checks for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The risk domain
+    #[serde(alias = "riskDomain")]
+    pub domain: RiskDomain,
+    /// The score
+    pub score: f64,
+    /// Weight of the score within its domain
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+    /// The risk level bucket it falls into
+    #[serde(alias = "riskLevel")]
+    pub risk_level: RiskLevel,
+    /// Output of the heuristic, in a shape specific to it
+    #[serde(
+        default,
+        alias = "rawOutput",
+        alias = "output",
+        skip_serializing_if = "serde_json::Value::is_null"
+    )]
+    pub raw_output: serde_json::Value,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+impl From<v1::HeuristicResult> for HeuristicResult {
+    fn from(result: v1::HeuristicResult) -> Self {
+        Self {
+            heuristic_id: String::new(),
+            name: String::new(),
+            description: None,
+            domain: result.domain,
+            score: result.score,
+            weight: default_weight(),
+            risk_level: result.risk_level,
+            raw_output: serde_json::Value::Null,
+        }
+    }
+}
+
+impl From<HeuristicResult> for v1::HeuristicResult {
+    fn from(result: HeuristicResult) -> Self {
+        Self {
+            domain: result.domain,
+            score: result.score,
+            risk_level: result.risk_level,
+        }
+    }
+}
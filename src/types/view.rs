@@ -0,0 +1,220 @@
+//! This module contains saved views, which are named issue filters shared
+//! between the web UI, the CLI and other clients, so that a team triages the
+//! same set of issues everywhere.
+//!
+//! ```
+//! use phylum_types::types::package::{RiskDomain, RiskLevel};
+//! use phylum_types::types::view::IssueFilter;
+//!
+//! let filter = IssueFilter {
+//!     min_severity: Some(RiskLevel::High),
+//!     domains: vec![RiskDomain::Vulnerabilities],
+//!     ..Default::default()
+//! };
+//! assert!(!filter.is_empty());
+//! ```
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::common::ProjectId;
+use super::package::{
+    Issue, IssueStatus, PackageStatusExtended, PackageType, RiskDomain, RiskLevel,
+};
+use super::timestamp::Timestamp;
+
+/// Criteria selecting issues; empty criteria select every issue that is not
+/// ignored
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+pub struct IssueFilter {
+    /// Only issues at least this severe
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "minSeverity"
+    )]
+    pub min_severity: Option<RiskLevel>,
+    /// Only issues in one of these domains
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domains: Vec<RiskDomain>,
+    /// Only packages of one of these ecosystems
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ecosystems: Vec<PackageType>,
+    /// Only jobs of one of these projects
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectId>,
+    /// Text found in the title, id or tag of the issue, ignoring case
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub query: Option<String>,
+    /// Also select ignored issues
+    #[serde(
+        default,
+        skip_serializing_if = "std::ops::Not::not",
+        alias = "includeIgnored"
+    )]
+    pub include_ignored: bool,
+}
+
+impl IssueFilter {
+    /// Whether the filter has no criteria.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Whether an issue satisfies the severity, domain and text criteria.
+    pub fn matches_issue(&self, issue: &Issue) -> bool {
+        if self.min_severity.is_some_and(|min| issue.severity < min) {
+            return false;
+        }
+        if !self.domains.is_empty() && !self.domains.contains(&issue.domain) {
+            return false;
+        }
+        match &self.query {
+            Some(query) => {
+                let query = query.to_lowercase();
+                let contains = |text: &str| text.to_lowercase().contains(&query);
+                contains(&issue.title)
+                    || issue.id.as_deref().is_some_and(contains)
+                    || issue.tag.as_deref().is_some_and(contains)
+            }
+            None => true,
+        }
+    }
+
+    /// Whether an issue of a package satisfies the filter, including whether
+    /// it is ignored.
+    pub fn matches(&self, status: &IssueStatus) -> bool {
+        (self.include_ignored || status.ignored.is_none()) && self.matches_issue(&status.issue)
+    }
+
+    /// Whether the ecosystem of a package is selected.
+    pub fn matches_ecosystem(&self, package_type: PackageType) -> bool {
+        self.ecosystems.is_empty() || self.ecosystems.contains(&package_type)
+    }
+
+    /// Whether jobs of the project are selected.
+    pub fn matches_project(&self, project: &ProjectId) -> bool {
+        self.projects.is_empty() || self.projects.contains(project)
+    }
+
+    /// The issues of a package selected by the filter.
+    pub fn apply<'a>(
+        &'a self,
+        package: &'a PackageStatusExtended,
+    ) -> impl Iterator<Item = &'a IssueStatus> + 'a {
+        let issues = if self.matches_ecosystem(package.package_type) {
+            &package.issues[..]
+        } else {
+            &[]
+        };
+        issues.iter().filter(move |status| self.matches(status))
+    }
+}
+
+/// Who can see a saved view
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Default, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ViewScope {
+    /// Only the owner
+    #[default]
+    Private,
+    /// Members of a group
+    Group { group_name: String },
+    /// Members of an organization
+    Organization { org_name: String },
+}
+
+impl ViewScope {
+    /// Whether anyone but the owner can see the view.
+    pub fn is_shared(&self) -> bool {
+        !matches!(self, ViewScope::Private)
+    }
+}
+
+/// A named issue filter
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[cfg_attr(deny_unknown_fields, serde(deny_unknown_fields))]
+pub struct SavedView {
+    pub id: Uuid,
+    pub name: String,
+    /// Email of the user who created the view
+    pub owner: String,
+    #[serde(default)]
+    pub scope: ViewScope,
+    pub filter: IssueFilter,
+    #[serde(alias = "createdAt")]
+    pub created_at: Timestamp,
+    #[serde(default, alias = "updatedAt")]
+    pub updated_at: Option<Timestamp>,
+}
+
+impl SavedView {
+    /// Whether the view may be changed by the user with the given email.
+    ///
+    /// Shared views are changed by their owner only; group and organization
+    /// admins manage them through the API directly.
+    pub fn is_owned_by(&self, user_email: &str) -> bool {
+        self.owner.eq_ignore_ascii_case(user_email)
+    }
+}
+
+/// Create a saved view, or replace the filter of an existing one
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct SaveViewRequest {
+    pub name: String,
+    #[serde(default)]
+    pub scope: ViewScope,
+    pub filter: IssueFilter,
+}
+
+impl SaveViewRequest {
+    /// A request for a view visible only to its owner.
+    pub fn new(name: impl Into<String>, filter: IssueFilter) -> Self {
+        Self {
+            name: name.into(),
+            scope: ViewScope::default(),
+            filter,
+        }
+    }
+
+    pub fn with_scope(mut self, scope: ViewScope) -> Self {
+        self.scope = scope;
+        self
+    }
+}
+
+impl From<&SavedView> for SaveViewRequest {
+    fn from(view: &SavedView) -> Self {
+        Self {
+            name: view.name.clone(),
+            scope: view.scope.clone(),
+            filter: view.filter.clone(),
+        }
+    }
+}
+
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct ListViewsResponse {
+    pub views: Vec<SavedView>,
+}
+
+impl ListViewsResponse {
+    /// The view with the given name, preferring one owned by the user over a
+    /// shared one.
+    pub fn find(&self, name: &str, user_email: &str) -> Option<&SavedView> {
+        let mut views = self.views.iter().filter(|view| view.name == name);
+        let first = views.clone().next();
+        views.find(|view| view.is_owned_by(user_email)).or(first)
+    }
+}
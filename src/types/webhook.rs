@@ -0,0 +1,279 @@
+//! This module contains the payloads of webhooks, which notify CI systems
+//! and other integrations of events in Phylum.
+//!
+//! Each request carries a [`WebhookDelivery`] as its JSON body. The body is
+//! signed with a secret shared with the receiver: the [`SIGNATURE_HEADER`]
+//! holds an HMAC-SHA256 of the [`TIMESTAMP_HEADER`] value, a `.`, and the raw
+//! body, as `sha256=` followed by the lowercase hex digest. With the
+//! `webhook-verify` feature, [`verify`] checks it:
+//!
+//! ```
+//! # #[cfg(feature = "webhook-verify")]
+//! # {
+//! use phylum_types::types::webhook;
+//!
+//! let body = br#"{"id":"..."}"#;
+//! let signature = webhook::sign(b"secret", 1_650_000_000, body);
+//! assert!(webhook::verify(b"secret", &signature, 1_650_000_000, body).is_ok());
+//! assert!(webhook::verify(b"other", &signature, 1_650_000_000, body).is_err());
+//! # }
+//! ```
+
+use std::fmt;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::analysis::PolicyRejection;
+use super::common::{JobId, ProjectId};
+use super::firewall::QuarantinedPackage;
+use super::label::Label;
+use super::lenient;
+use super::package::{Issue, PackageRef};
+use super::timestamp::{self, Timestamp};
+
+/// Header holding the signature of the body
+pub const SIGNATURE_HEADER: &str = "X-Phylum-Signature";
+/// Header holding the time the delivery was signed, as epoch seconds
+pub const TIMESTAMP_HEADER: &str = "X-Phylum-Timestamp";
+/// Header holding the id of the delivery
+pub const DELIVERY_HEADER: &str = "X-Phylum-Delivery";
+
+/// Prefix of the hex digest in the signature header.
+const SIGNATURE_PREFIX: &str = "sha256=";
+
+/// A job finished processing
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct JobCompletedEvent {
+    #[serde(alias = "jobId")]
+    pub job_id: JobId,
+    pub project: ProjectId,
+    #[serde(alias = "projectName")]
+    pub project_name: String,
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
+    pub pass: bool,
+    pub msg: String,
+    /// Dependencies that have not completed processing
+    #[serde(default, alias = "numIncomplete")]
+    pub num_incomplete: u32,
+}
+
+/// A job's dependencies did not satisfy the project's policy
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct PolicyFailedEvent {
+    #[serde(alias = "jobId")]
+    pub job_id: JobId,
+    pub project: ProjectId,
+    #[serde(alias = "projectName")]
+    pub project_name: String,
+    #[serde(default, deserialize_with = "lenient::option_label")]
+    pub label: Option<Label>,
+    pub rejections: Vec<PolicyRejection>,
+    /// Link to the full report of the job
+    #[serde(alias = "reportUrl")]
+    pub report_url: Option<String>,
+}
+
+/// A critical issue was found in a package used by a project
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct CriticalIssueEvent {
+    pub project: ProjectId,
+    #[serde(alias = "projectName")]
+    pub project_name: String,
+    pub package: PackageRef,
+    pub issue: Issue,
+}
+
+/// Something which happened in Phylum
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum WebhookEvent {
+    JobCompleted(JobCompletedEvent),
+    PolicyFailed(PolicyFailedEvent),
+    CriticalIssue(Box<CriticalIssueEvent>),
+    PackageQuarantined(QuarantinedPackage),
+}
+
+impl WebhookEvent {
+    pub fn kind(&self) -> WebhookEventKind {
+        match self {
+            WebhookEvent::JobCompleted(_) => WebhookEventKind::JobCompleted,
+            WebhookEvent::PolicyFailed(_) => WebhookEventKind::PolicyFailed,
+            WebhookEvent::CriticalIssue(_) => WebhookEventKind::CriticalIssue,
+            WebhookEvent::PackageQuarantined(_) => WebhookEventKind::PackageQuarantined,
+        }
+    }
+}
+
+/// The kind of a [`WebhookEvent`], for subscribing to some events only
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEventKind {
+    JobCompleted,
+    PolicyFailed,
+    CriticalIssue,
+    PackageQuarantined,
+}
+
+impl WebhookEventKind {
+    /// The name used on the wire, such as `job_completed`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WebhookEventKind::JobCompleted => "job_completed",
+            WebhookEventKind::PolicyFailed => "policy_failed",
+            WebhookEventKind::CriticalIssue => "critical_issue",
+            WebhookEventKind::PackageQuarantined => "package_quarantined",
+        }
+    }
+}
+
+impl fmt::Display for WebhookEventKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The body of a webhook request
+#[derive(
+    PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Debug, Serialize, Deserialize, JsonSchema,
+)]
+pub struct WebhookDelivery {
+    /// Unique id of the delivery, the same for every attempt
+    pub id: Uuid,
+    /// Number of this attempt at delivering the event, starting at 1
+    pub attempt: u32,
+    #[serde(alias = "sentAt")]
+    pub sent_at: Timestamp,
+    pub event: WebhookEvent,
+}
+
+impl WebhookDelivery {
+    /// Whether this is a retry of an earlier, failed attempt.
+    pub fn is_retry(&self) -> bool {
+        self.attempt > 1
+    }
+}
+
+/// Why a webhook signature was rejected
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum SignatureError {
+    /// Not `sha256=` followed by 64 hex digits
+    Malformed,
+    /// The signature does not match the body
+    Mismatch,
+    /// The timestamp is too far from the current time
+    Expired,
+}
+
+impl fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SignatureError::Malformed => f.write_str("webhook signature is malformed"),
+            SignatureError::Mismatch => f.write_str("webhook signature does not match"),
+            SignatureError::Expired => f.write_str("webhook timestamp is outside the tolerance"),
+        }
+    }
+}
+
+impl std::error::Error for SignatureError {}
+
+/// The bytes which are signed: the timestamp, a `.`, and the body.
+pub fn signed_payload(timestamp: i64, body: &[u8]) -> Vec<u8> {
+    let mut payload = format!("{timestamp}.").into_bytes();
+    payload.extend_from_slice(body);
+    payload
+}
+
+/// The digest of a signature header value.
+pub fn parse_signature(header: &str) -> Result<[u8; 32], SignatureError> {
+    let hex = header
+        .trim()
+        .strip_prefix(SIGNATURE_PREFIX)
+        .ok_or(SignatureError::Malformed)?;
+    if hex.len() != 64 {
+        return Err(SignatureError::Malformed);
+    }
+
+    let mut digest = [0; 32];
+    for (byte, pair) in digest.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        let pair = std::str::from_utf8(pair).map_err(|_| SignatureError::Malformed)?;
+        *byte = u8::from_str_radix(pair, 16).map_err(|_| SignatureError::Malformed)?;
+    }
+    Ok(digest)
+}
+
+/// The signature header value of a digest.
+pub fn format_signature(digest: &[u8; 32]) -> String {
+    let mut header = String::with_capacity(SIGNATURE_PREFIX.len() + 64);
+    header.push_str(SIGNATURE_PREFIX);
+    for byte in digest {
+        header.push_str(&format!("{byte:02x}"));
+    }
+    header
+}
+
+/// Reject timestamps more than `tolerance_seconds` away from `now`, so that
+/// captured deliveries can't be replayed later.
+pub fn check_timestamp(
+    timestamp: i64,
+    now: &Timestamp,
+    tolerance_seconds: i64,
+) -> Result<(), SignatureError> {
+    let now = timestamp::to_epoch_seconds(now);
+    if now.abs_diff(timestamp) > tolerance_seconds.unsigned_abs() {
+        return Err(SignatureError::Expired);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "webhook-verify")]
+type HmacSha256 = hmac::Hmac<sha2::Sha256>;
+
+#[cfg(feature = "webhook-verify")]
+fn mac(secret: &[u8], timestamp: i64, body: &[u8]) -> HmacSha256 {
+    use hmac::Mac;
+
+    // HMAC accepts keys of any length
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC key of any length");
+    mac.update(&signed_payload(timestamp, body));
+    mac
+}
+
+/// The signature header value for a body.
+#[cfg(feature = "webhook-verify")]
+pub fn sign(secret: &[u8], timestamp: i64, body: &[u8]) -> String {
+    use hmac::Mac;
+
+    let digest = mac(secret, timestamp, body).finalize().into_bytes();
+    format_signature(&digest.into())
+}
+
+/// Check the signature header value of a body, in constant time.
+///
+/// This does not check the timestamp; see [`check_timestamp`].
+#[cfg(feature = "webhook-verify")]
+pub fn verify(
+    secret: &[u8],
+    signature: &str,
+    timestamp: i64,
+    body: &[u8],
+) -> Result<(), SignatureError> {
+    use hmac::Mac;
+
+    let digest = parse_signature(signature)?;
+    mac(secret, timestamp, body)
+        .verify_slice(&digest)
+        .map_err(|_| SignatureError::Mismatch)
+}
@@ -0,0 +1,263 @@
+//! Semantic checks of API payloads beyond what deserialization enforces.
+//!
+//! Combined with the `strict` feature, which rejects unknown fields, this
+//! catches API drift early rather than letting odd data through silently.
+
+use std::fmt;
+
+use uuid::Uuid;
+
+use crate::types::job::{JobDescriptor, JobStatusResponse, SubmitPackageRequest};
+use crate::types::metadata::Metadata;
+#[allow(deprecated)]
+use crate::types::package::{
+    Issue, IssueEvidence, Package, PackageDescriptor, PackageStatus, PackageStatusExtended,
+    RiskScores,
+};
+use crate::types::package_url::PurlError;
+use crate::types::project::CreateProjectRequest;
+
+/// A single failed check.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ValidationError {
+    /// Path of the offending field, such as `packages[3].name`
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Collects the errors found while validating a value.
+#[derive(Default, Debug)]
+pub struct Validator {
+    prefix: Vec<String>,
+    errors: Vec<ValidationError>,
+}
+
+impl Validator {
+    /// Record an error for `field` unless `valid` holds.
+    pub fn check(&mut self, valid: bool, field: &str, message: impl Into<String>) {
+        if !valid {
+            let mut path = self.prefix.join(".");
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(field);
+
+            self.errors.push(ValidationError {
+                path,
+                message: message.into(),
+            });
+        }
+    }
+
+    pub fn not_empty(&mut self, value: &str, field: &str) {
+        self.check(!value.trim().is_empty(), field, "must not be empty");
+    }
+
+    /// Check that an id is not the nil UUID, which the API uses as a
+    /// placeholder.
+    pub fn id(&mut self, value: &Uuid, field: &str) {
+        self.check(!value.is_nil(), field, "must not be the nil UUID");
+    }
+
+    /// Check that a score lies within `0.0..=1.0`.
+    pub fn score(&mut self, value: f64, field: &str) {
+        self.check(
+            (0.0..=1.0).contains(&value),
+            field,
+            format!("score {value} is outside of 0..=1"),
+        );
+    }
+
+    /// Record a package URL which is invalid or disagrees with its package.
+    pub fn purl(&mut self, result: Result<(), PurlError>) {
+        if let Err(error) = result {
+            self.check(false, "purl", error.to_string());
+        }
+    }
+
+    /// Check metadata sent to the API against its limits.
+    pub fn metadata(&mut self, value: &Metadata) {
+        if let Err(error) = value.check_limits() {
+            self.check(false, "metadata", error.to_string());
+        }
+    }
+
+    /// Validate a nested value, prefixing its errors with `field`.
+    pub fn nested<T: Validate + ?Sized>(&mut self, value: &T, field: impl Into<String>) {
+        self.prefix.push(field.into());
+        value.validate_into(self);
+        self.prefix.pop();
+    }
+
+    /// Validate every element of a list.
+    pub fn each<T: Validate>(&mut self, values: &[T], field: &str) {
+        for (i, value) in values.iter().enumerate() {
+            self.nested(value, format!("{field}[{i}]"));
+        }
+    }
+
+    pub fn finish(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+/// Types whose contents can be checked for semantic validity.
+pub trait Validate {
+    /// Record every problem with this value in `validator`.
+    fn validate_into(&self, validator: &mut Validator);
+
+    fn validate(&self) -> Result<(), Vec<ValidationError>> {
+        let mut validator = Validator::default();
+        self.validate_into(&mut validator);
+        validator.finish()
+    }
+}
+
+#[allow(deprecated)]
+impl Validate for PackageDescriptor {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.name, "name");
+        v.not_empty(&self.version, "version");
+    }
+}
+
+impl Validate for RiskScores {
+    fn validate_into(&self, v: &mut Validator) {
+        v.score(self.total.into(), "total");
+        v.score(self.vulnerability.into(), "vulnerability");
+        v.score(self.malicious.into(), "malicious_code");
+        v.score(self.author.into(), "author");
+        v.score(self.engineering.into(), "engineering");
+        v.score(self.license.into(), "license");
+    }
+}
+
+impl Validate for Issue {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.title, "title");
+        v.each(&self.evidence, "evidence");
+    }
+}
+
+impl Validate for IssueEvidence {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.path, "path");
+        if let Some(lines) = self.lines {
+            v.check(lines.start >= 1, "lines.start", "line numbers start at 1");
+            v.check(
+                lines.start <= lines.end,
+                "lines.end",
+                "must not be before the start",
+            );
+        }
+    }
+}
+
+impl Validate for Package {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.name, "name");
+        v.not_empty(&self.version, "version");
+        v.not_empty(&self.registry, "registry");
+        v.purl(self.validate_purl());
+        if let Some(risk_scores) = &self.risk_scores {
+            v.nested(risk_scores, "riskScores");
+        }
+        v.each(&self.issues_details, "issuesDetails");
+        for (i, version) in self.versions.iter().enumerate() {
+            if let Some(score) = version.total_risk_score {
+                v.score(score.into(), &format!("versions[{i}].total_risk_score"));
+            }
+        }
+        if let Some(dependencies) = &self.dependencies {
+            v.each(dependencies, "dependencies");
+        }
+    }
+}
+
+impl Validate for PackageStatus {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.name, "name");
+        v.not_empty(&self.version, "version");
+        v.purl(self.validate_purl());
+        if let Some(score) = self.package_score {
+            v.score(score, "package_score");
+        }
+    }
+}
+
+impl Validate for PackageStatusExtended {
+    fn validate_into(&self, v: &mut Validator) {
+        self.basic_status.validate_into(v);
+        // The name and version are already checked against the basic status
+        if let Err(error @ PurlError::Mismatch { field: "type", .. }) = self.validate_purl() {
+            v.purl(Err(error));
+        }
+        for (domain, score) in &self.risk_vectors {
+            v.score(*score, &format!("riskVectors.{domain}"));
+        }
+        for (i, issue) in self.issues.iter().enumerate() {
+            v.nested(&issue.issue, format!("issues[{i}]"));
+        }
+    }
+}
+
+impl<T: Validate> Validate for JobStatusResponse<T> {
+    fn validate_into(&self, v: &mut Validator) {
+        v.id(&self.job_id, "job_id");
+        v.id(&self.user_id, "user_id");
+        v.id(&self.project, "project");
+        v.not_empty(&self.project_name, "project_name");
+        v.check(
+            self.num_incomplete as usize <= self.packages.len(),
+            "num_incomplete",
+            "exceeds the number of packages",
+        );
+        v.each(&self.packages, "packages");
+    }
+}
+
+#[allow(deprecated)]
+impl Validate for JobDescriptor {
+    fn validate_into(&self, v: &mut Validator) {
+        v.id(&self.job_id, "job_id");
+        v.id(&self.project, "project");
+        v.check(
+            self.num_incomplete <= self.num_dependencies,
+            "num_incomplete",
+            "exceeds the number of dependencies",
+        );
+        for (i, package) in self.packages.iter().enumerate() {
+            v.nested(&package.package_descriptor, format!("packages[{i}]"));
+        }
+    }
+}
+
+#[allow(deprecated)]
+impl Validate for SubmitPackageRequest {
+    fn validate_into(&self, v: &mut Validator) {
+        v.id(&self.project, "project");
+        for (i, package) in self.packages.iter().enumerate() {
+            v.nested(&package.package_descriptor, format!("packages[{i}]"));
+        }
+        v.metadata(&self.metadata);
+    }
+}
+
+impl Validate for CreateProjectRequest {
+    fn validate_into(&self, v: &mut Validator) {
+        v.not_empty(&self.name, "name");
+        v.metadata(&self.metadata);
+    }
+}
@@ -0,0 +1,68 @@
+use phylum_types::types::aggregate::{dedupe_issues, IssueAggregator};
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::{Issue, PackageStatusExtended, RiskLevel};
+use serde_json::json;
+
+fn issue(id: Option<&str>, title: &str, severity: &str, description: &str) -> Issue {
+    serde_json::from_value(json!({
+        "tag": null,
+        "id": id,
+        "title": title,
+        "description": description,
+        "severity": severity,
+        "domain": "vulnerability",
+    }))
+    .unwrap()
+}
+
+#[test]
+fn merges_duplicates() {
+    let issues = [
+        issue(Some("CVE-1"), "Prototype pollution", "medium", "First"),
+        issue(None, "Typosquat", "low", "Similar name"),
+        issue(
+            Some("CVE-1"),
+            "Prototype pollution in merge",
+            "critical",
+            "Second",
+        ),
+        issue(Some("CVE-1"), "Prototype pollution", "high", "First"),
+    ];
+
+    let aggregated = dedupe_issues(&issues);
+    assert_eq!(aggregated.len(), 2);
+    assert_eq!(aggregated[0].id.as_deref(), Some("CVE-1"));
+    assert_eq!(aggregated[0].severity, RiskLevel::Critical);
+    assert_eq!(aggregated[0].descriptions, ["First", "Second"]);
+    assert_eq!(aggregated[0].occurrences, 3);
+    assert!(aggregated[0].packages.is_empty());
+    assert_eq!(aggregated[1].title, "Typosquat");
+}
+
+#[test]
+fn job_packages() {
+    let json = include_str!("fixtures/job_status_extended.json");
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_str(json).unwrap();
+
+    let mut aggregator = IssueAggregator::default();
+    aggregator.add_job(&job);
+    aggregator.add_job(&job);
+    let aggregated = aggregator.finish();
+
+    let total: usize = job
+        .packages
+        .iter()
+        .map(|package| package.issues.len())
+        .sum();
+    assert!(!aggregated.is_empty());
+    assert_eq!(
+        aggregated
+            .iter()
+            .map(|issue| issue.occurrences)
+            .sum::<usize>(),
+        2 * total
+    );
+    for issue in &aggregated {
+        assert!(!issue.packages.is_empty());
+    }
+}
@@ -0,0 +1,65 @@
+//! Heap allocation budgets for deserializing typical API responses.
+//!
+//! Raise a budget only when the extra allocations are expected, such as
+//! after adding a field. Each test counts the allocations made on its own
+//! thread, so tests can run in parallel without skewing each other's numbers.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::{Package, PackageStatusExtended};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Count the allocations made while running `f`.
+///
+/// `f` is run once beforehand, so one-off allocations such as the string
+/// interner's pool are not counted.
+fn allocations<T>(f: impl Fn() -> T) -> (T, usize) {
+    drop(f());
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    (value, ALLOCATIONS.with(Cell::get) - before)
+}
+
+#[test]
+fn job_status_extended() {
+    let json = include_str!("fixtures/job_status_extended.json");
+    let (job, count) = allocations(|| {
+        serde_json::from_str::<JobStatusResponse<PackageStatusExtended>>(json).unwrap()
+    });
+    drop(job);
+    assert!(count <= 52, "{} allocations", count);
+}
+
+#[test]
+fn package() {
+    let json = include_str!("fixtures/package.json");
+    let (package, count) = allocations(|| serde_json::from_str::<Package>(json).unwrap());
+    drop(package);
+    assert!(count <= 31, "{} allocations", count);
+}
@@ -0,0 +1,286 @@
+#![cfg(feature = "avro")]
+
+use std::collections::{BTreeSet, HashMap};
+use std::convert::TryFrom;
+
+use apache_avro::reader::datum::GenericDatumReader;
+use apache_avro::schema::{NamesRef, ResolvedSchema};
+use apache_avro::types::Value as AvroValue;
+use apache_avro::writer::datum::GenericDatumWriter;
+use apache_avro::Schema;
+use phylum_types::avro::{self, Mismatch};
+use phylum_types::types::feed::FeedStatusResponse;
+use phylum_types::types::group::UserGroup;
+use phylum_types::types::health::HealthResponse;
+use phylum_types::types::job::{
+    AllJobsStatusResponse, JobDescriptor, JobStatusResponse, SubmitPackageRequest,
+    SubmitPackageResponse,
+};
+use phylum_types::types::package::{Issue, Package, PackageStatus, PackageStatusExtended};
+use phylum_types::types::project::ProjectSummaryResponse;
+use phylum_types::types::report::ReportStatus;
+use phylum_types::types::webhook::WebhookDelivery;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+fn package() -> Package {
+    serde_json::from_str(include_str!("fixtures/package.json")).unwrap()
+}
+
+fn job_status() -> JobStatusResponse<PackageStatusExtended> {
+    serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap()
+}
+
+/// Names of the named types defined in a schema, failing on redefinitions.
+fn definitions(schema: &Value, names: &mut BTreeSet<String>) {
+    match schema {
+        Value::Array(union) => union.iter().for_each(|branch| definitions(branch, names)),
+        Value::Object(object) => {
+            if let Some(name) = object.get("name").and_then(Value::as_str) {
+                assert!(names.insert(name.to_owned()), "{} defined twice", name);
+            }
+            for key in ["items", "values"] {
+                if let Some(nested) = object.get(key) {
+                    definitions(nested, names);
+                }
+            }
+            if let Some(fields) = object.get("fields").and_then(Value::as_array) {
+                for field in fields {
+                    definitions(&field["type"], names);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+/// The name tagging values of a union branch in the Avro JSON encoding.
+fn branch_name(schema: &Schema) -> String {
+    match schema {
+        Schema::Null => "null".into(),
+        Schema::Boolean => "boolean".into(),
+        Schema::Long => "long".into(),
+        Schema::Float => "float".into(),
+        Schema::Double => "double".into(),
+        Schema::String => "string".into(),
+        Schema::Array(_) => "array".into(),
+        Schema::Map(_) => "map".into(),
+        Schema::Record(record) => record.name.fullname(None),
+        Schema::Enum(enumeration) => enumeration.name.fullname(None),
+        Schema::Ref { name } => name.fullname(None),
+        schema => panic!("unexpected schema {:?}", schema),
+    }
+}
+
+/// Decode the Avro JSON encoding of a value.
+fn decode(schema: &Schema, names: &NamesRef, json: &Value) -> AvroValue {
+    match (schema, json) {
+        (Schema::Ref { name }, json) => decode(names[name], names, json),
+        (Schema::Null, Value::Null) => AvroValue::Null,
+        (Schema::Boolean, Value::Bool(value)) => AvroValue::Boolean(*value),
+        (Schema::Long, Value::Number(value)) => AvroValue::Long(value.as_i64().unwrap()),
+        (Schema::Float, Value::Number(value)) => AvroValue::Float(value.as_f64().unwrap() as f32),
+        (Schema::Double, Value::Number(value)) => AvroValue::Double(value.as_f64().unwrap()),
+        (Schema::String, Value::String(value)) => AvroValue::String(value.clone()),
+        (Schema::Enum(enumeration), Value::String(symbol)) => {
+            let index = enumeration.symbols.iter().position(|s| s == symbol);
+            AvroValue::Enum(index.unwrap() as u32, symbol.clone())
+        }
+        (Schema::Array(array), Value::Array(items)) => AvroValue::Array(
+            items
+                .iter()
+                .map(|item| decode(&array.items, names, item))
+                .collect(),
+        ),
+        (Schema::Map(map), Value::Object(entries)) => AvroValue::Map(
+            entries
+                .iter()
+                .map(|(key, entry)| (key.clone(), decode(&map.types, names, entry)))
+                .collect::<HashMap<_, _>>(),
+        ),
+        (Schema::Record(record), Value::Object(fields)) => AvroValue::Record(
+            record
+                .fields
+                .iter()
+                .map(|field| {
+                    let value = decode(&field.schema, names, &fields[&field.name]);
+                    (field.name.clone(), value)
+                })
+                .collect(),
+        ),
+        (Schema::Union(union), Value::Null) => {
+            let index = union.variants().iter().position(|v| *v == Schema::Null);
+            AvroValue::Union(index.unwrap() as u32, Box::new(AvroValue::Null))
+        }
+        (Schema::Union(union), Value::Object(tagged)) => {
+            assert_eq!(tagged.len(), 1, "untagged union value {}", json);
+            let (tag, value) = tagged.iter().next().unwrap();
+            let (index, variant) = union
+                .variants()
+                .iter()
+                .enumerate()
+                .find(|(_, variant)| branch_name(variant) == *tag)
+                .unwrap_or_else(|| panic!("no branch {} in {:?}", tag, union));
+            let value = decode(variant, names, value);
+            AvroValue::Union(index as u32, Box::new(value))
+        }
+        (schema, json) => panic!("{} does not decode as {:?}", json, schema),
+    }
+}
+
+/// Parse the schema of `T` with apache-avro, and round-trip `value` through
+/// the binary encoding.
+fn encode_through_avro<T: JsonSchema + Serialize>(value: &T) {
+    let schema = avro::schema_for::<T>();
+    let parsed = Schema::parse(&schema).unwrap();
+    let resolved = ResolvedSchema::try_from(&parsed).unwrap();
+
+    let json = serde_json::to_value(value).unwrap();
+    let encoded = avro::to_avro_json(&schema, &json).unwrap();
+    let decoded = decode(&parsed, resolved.get_names(), &encoded);
+
+    let writer = GenericDatumWriter::builder(&parsed).build().unwrap();
+    let bytes = writer.write_value_to_vec(decoded.clone()).unwrap();
+    let reader = GenericDatumReader::builder(&parsed).build().unwrap();
+    let read = reader.read_value(&mut bytes.as_slice()).unwrap();
+    assert_eq!(read, decoded);
+}
+
+macro_rules! parse_all {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            let schema = avro::schema_for::<$ty>();
+            if let Err(error) = Schema::parse(&schema) {
+                panic!("{}: {}\n{:#}", stringify!($ty), error, schema);
+            }
+        )+
+    };
+}
+
+#[test]
+fn schemas_parse() {
+    parse_all!(
+        Package,
+        Issue,
+        PackageStatus,
+        PackageStatusExtended,
+        JobStatusResponse<PackageStatus>,
+        JobStatusResponse<PackageStatusExtended>,
+        AllJobsStatusResponse,
+        JobDescriptor,
+        SubmitPackageRequest,
+        SubmitPackageResponse,
+        ProjectSummaryResponse,
+        ReportStatus,
+        FeedStatusResponse,
+        HealthResponse,
+        UserGroup,
+        WebhookDelivery,
+    );
+}
+
+#[test]
+fn fixtures_encode() {
+    encode_through_avro(&package());
+    encode_through_avro(&job_status());
+    for issue in &package().issues_details {
+        encode_through_avro(issue);
+    }
+}
+
+#[test]
+fn unions_are_tagged() {
+    let schema = avro::schema_for::<Issue>();
+    let issue = &package().issues_details[0];
+    let json = serde_json::to_value(issue).unwrap();
+    let encoded = avro::to_avro_json(&schema, &json).unwrap();
+    assert_eq!(encoded["title"], json["title"]);
+    match &json["tag"] {
+        Value::Null => assert_eq!(encoded["tag"], Value::Null),
+        tag => assert_eq!(encoded["tag"], json!({ "string": tag })),
+    }
+}
+
+#[test]
+fn package_output_matches() {
+    let schema = avro::schema_for::<Package>();
+    let value = serde_json::to_value(package()).unwrap();
+    assert_eq!(avro::validate(&schema, &value), Ok(()));
+}
+
+#[test]
+fn job_status_output_matches() {
+    let schema = avro::schema_for::<JobStatusResponse<PackageStatusExtended>>();
+    assert_eq!(
+        schema["name"],
+        "JobStatusResponse_for_PackageStatusExtended"
+    );
+    let value = serde_json::to_value(job_status()).unwrap();
+    assert_eq!(avro::validate(&schema, &value), Ok(()));
+}
+
+#[test]
+fn issue_output_matches() {
+    let schema = avro::schema_for::<Issue>();
+    let package = package();
+    assert!(!package.issues_details.is_empty());
+    for issue in &package.issues_details {
+        let value = serde_json::to_value(issue).unwrap();
+        assert_eq!(avro::validate(&schema, &value), Ok(()));
+    }
+}
+
+#[test]
+fn named_types_are_defined_once() {
+    let mut names = BTreeSet::new();
+    definitions(&avro::schema_for::<Package>(), &mut names);
+    assert!(names.contains("Package"));
+    assert!(names.contains("RiskLevel"));
+
+    // Recursive dependencies refer back to the record by name
+    let schema = avro::schema_for::<Package>();
+    let fields = schema["fields"].as_array().unwrap();
+    let dependencies = fields.iter().find(|field| field["name"] == "dependencies");
+    assert_eq!(
+        dependencies.unwrap()["type"],
+        json!(["null", { "type": "array", "items": "phylum.Package" }])
+    );
+}
+
+#[test]
+fn optional_fields_default_to_null() {
+    let schema = avro::schema_for::<Issue>();
+    let fields = schema["fields"].as_array().unwrap();
+    let field = |name: &str| fields.iter().find(|field| field["name"] == name).unwrap();
+
+    assert_eq!(field("title")["type"], "string");
+    assert!(field("title").get("default").is_none());
+    assert_eq!(field("tag")["type"], json!(["null", "string"]));
+    assert_eq!(field("tag")["default"], Value::Null);
+    assert_eq!(field("severity")["type"]["type"], "enum");
+    assert_eq!(
+        field("severity")["type"]["symbols"],
+        json!(["info", "low", "medium", "high", "critical"])
+    );
+}
+
+#[test]
+fn mismatches() {
+    let schema = avro::schema_for::<JobStatusResponse<PackageStatusExtended>>();
+    let mut value = serde_json::to_value(job_status()).unwrap();
+    value["packages"][0]["status"] = json!("paused");
+    let error = avro::validate(&schema, &value).unwrap_err();
+    assert_eq!(error.path, "packages[0].status");
+
+    let mut value = serde_json::to_value(job_status()).unwrap();
+    value["priority"] = json!("high");
+    assert_eq!(
+        avro::validate(&schema, &value),
+        Err(Mismatch {
+            path: "priority".into(),
+            expected: "no such field in record \"JobStatusResponse_for_PackageStatusExtended\""
+                .into(),
+        })
+    );
+}
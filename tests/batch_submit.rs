@@ -0,0 +1,133 @@
+use phylum_types::types::common::ErrorCode;
+use phylum_types::types::job::{
+    BatchLimits, BatchSizeError, BatchSubmitRequest, BatchSubmitResponse, SubmitPackageRequest,
+};
+#[allow(deprecated)]
+use phylum_types::types::package::{PackageDescriptor, PackageType};
+use serde_json::json;
+
+const LIMITS: BatchLimits = BatchLimits {
+    max_submissions: 100,
+    max_packages: 50_000,
+};
+
+#[allow(deprecated)]
+fn submission(packages: usize) -> SubmitPackageRequest {
+    let mut request: SubmitPackageRequest = serde_json::from_value(json!({
+        "packages": [],
+        "is_user": false,
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "label": "main",
+    }))
+    .unwrap();
+    request.packages = (0..packages)
+        .map(|i| PackageDescriptor::new(format!("package-{i}"), "1.0.0", PackageType::Npm).into())
+        .collect();
+    request
+}
+
+#[test]
+fn size_limits() {
+    let batch = |submissions: Vec<SubmitPackageRequest>| BatchSubmitRequest { submissions };
+
+    assert_eq!(
+        batch(vec![]).validate_size(&LIMITS),
+        Err(BatchSizeError::Empty)
+    );
+    assert_eq!(
+        batch(vec![submission(3), submission(4)]).total_packages(),
+        7
+    );
+    assert_eq!(batch(vec![submission(3)]).validate_size(&LIMITS), Ok(()));
+
+    let too_many = vec![submission(0); LIMITS.max_submissions + 1];
+    assert_eq!(
+        batch(too_many).validate_size(&LIMITS),
+        Err(BatchSizeError::TooManySubmissions {
+            count: 101,
+            max: 100
+        })
+    );
+
+    let too_large = batch(vec![submission(LIMITS.max_packages), submission(1)]);
+    let error = too_large.validate_size(&LIMITS).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "batch has 50001 packages, but at most 50000 are allowed"
+    );
+}
+
+#[test]
+fn chunking() {
+    let batches = BatchSubmitRequest::chunked(vec![submission(1); 250], &LIMITS);
+    let sizes: Vec<_> = batches
+        .iter()
+        .map(|batch| batch.submissions.len())
+        .collect();
+    assert_eq!(sizes, [100, 100, 50]);
+
+    let half = LIMITS.max_packages / 2;
+    let batches = BatchSubmitRequest::chunked(
+        vec![submission(half), submission(half), submission(1)],
+        &LIMITS,
+    );
+    let sizes: Vec<_> = batches.iter().map(|batch| batch.total_packages()).collect();
+    assert_eq!(sizes, [2 * half, 1]);
+    assert!(batches
+        .iter()
+        .all(|batch| batch.validate_size(&LIMITS).is_ok()));
+
+    assert!(BatchSubmitRequest::chunked(vec![], &LIMITS).is_empty());
+
+    let small = BatchLimits::new(2, 3);
+    let batches = BatchSubmitRequest::chunked(
+        vec![submission(1), submission(1), submission(1), submission(4)],
+        &small,
+    );
+    let sizes: Vec<_> = batches
+        .iter()
+        .map(|batch| batch.submissions.len())
+        .collect();
+    assert_eq!(sizes, [2, 1, 1]);
+    assert_eq!(
+        batches[2].validate_size(&small),
+        Err(BatchSizeError::TooManyPackages { count: 4, max: 3 })
+    );
+}
+
+#[test]
+fn partial_failure() {
+    let response: BatchSubmitResponse = serde_json::from_value(json!({
+        "job_ids": ["59482a54-423b-448d-8325-f171c9dc336b", null],
+        "failures": [
+            { "index": 1, "error": { "code": "not_found", "description": "No such project" } },
+        ],
+    }))
+    .unwrap();
+
+    assert!(!response.is_complete_success());
+    assert_eq!(
+        response.result(0).unwrap().unwrap().to_string(),
+        "59482a54-423b-448d-8325-f171c9dc336b"
+    );
+    assert_eq!(
+        response.result(1).unwrap().unwrap_err().code,
+        ErrorCode::NotFound
+    );
+    assert_eq!(response.result(2), None);
+    assert_eq!(
+        response
+            .submitted()
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>(),
+        [0]
+    );
+}
+
+#[test]
+fn complete_success() {
+    let json = json!({ "job_ids": ["59482a54-423b-448d-8325-f171c9dc336b"] });
+    let response: BatchSubmitResponse = serde_json::from_value(json.clone()).unwrap();
+    assert!(response.is_complete_success());
+    assert_eq!(serde_json::to_value(&response).unwrap(), json);
+}
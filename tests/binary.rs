@@ -0,0 +1,78 @@
+//! Round trips through binary formats used for message queue transport.
+
+use std::fmt::Debug;
+
+use phylum_types::types::job::{
+    AllJobsStatusResponse, JobStatusResponse, JobStatusResponseVariant,
+};
+use phylum_types::types::package::{Package, PackageStatus, PackageStatusExtended};
+use phylum_types::types::project::ProjectSummaryResponse;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn fixture<T: DeserializeOwned>(json: &str) -> T {
+    serde_json::from_str(json).unwrap()
+}
+
+fn msgpack<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let bytes = rmp_serde::to_vec_named(value).unwrap();
+    assert_eq!(&rmp_serde::from_slice::<T>(&bytes).unwrap(), value);
+}
+
+fn cbor<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    let mut bytes = Vec::new();
+    ciborium::ser::into_writer(value, &mut bytes).unwrap();
+    assert_eq!(
+        &ciborium::de::from_reader::<T, _>(&bytes[..]).unwrap(),
+        value
+    );
+}
+
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    msgpack(value);
+    cbor(value);
+    #[cfg(feature = "binary")]
+    bincode_embedded(value);
+}
+
+#[cfg(feature = "binary")]
+fn bincode_embedded<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    use phylum_types::binary::Embedded;
+
+    let bytes = bincode::serialize(&Embedded(value)).unwrap();
+    let decoded: Embedded<T> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(&decoded.into_inner(), value);
+}
+
+#[test]
+fn job_statuses() {
+    let basic = include_str!("fixtures/job_status_basic.json");
+    let extended = include_str!("fixtures/job_status_extended.json");
+    round_trip(&fixture::<JobStatusResponse<PackageStatus>>(basic));
+    round_trip(&fixture::<JobStatusResponse<PackageStatusExtended>>(
+        extended,
+    ));
+    round_trip(&fixture::<JobStatusResponseVariant>(basic));
+    round_trip(&fixture::<JobStatusResponseVariant>(extended));
+    round_trip(&fixture::<AllJobsStatusResponse>(include_str!(
+        "fixtures/all_jobs_status_response.json"
+    )));
+}
+
+#[test]
+fn packages_and_projects() {
+    round_trip(&fixture::<Package>(include_str!("fixtures/package.json")));
+    round_trip(&fixture::<Vec<ProjectSummaryResponse>>(include_str!(
+        "fixtures/project_summaries.json"
+    )));
+}
+
+#[cfg(feature = "mock")]
+#[test]
+fn fake_jobs() {
+    for n_packages in [0, 1, 25] {
+        round_trip(&JobStatusResponse::<PackageStatusExtended>::fake_with(
+            n_packages,
+        ));
+    }
+}
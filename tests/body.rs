@@ -0,0 +1,84 @@
+use std::error::Error;
+
+use phylum_types::body::{BodyError, ContentType, FromBody, ToBody};
+use phylum_types::types::common::ErrorCode;
+use phylum_types::types::job::{CancelJobResponse, SubmitPackageResponse};
+use uuid::Uuid;
+
+#[test]
+fn content_type_headers() {
+    assert_eq!(
+        ContentType::from_header("Application/JSON; charset=utf-8"),
+        Some(ContentType::Json)
+    );
+    assert_eq!(
+        ContentType::from_header("application/problem+json"),
+        Some(ContentType::Json)
+    );
+    assert_eq!(ContentType::from_header("text/html"), None);
+    assert!(matches!(
+        "application/msgpack".parse::<ContentType>(),
+        Err(BodyError::UnsupportedContentType(_))
+    ));
+    assert_eq!(ContentType::Json.to_string(), "application/json");
+}
+
+#[test]
+fn negotiation() {
+    assert_eq!(ContentType::negotiate("*/*"), Some(ContentType::Json));
+    assert_eq!(
+        ContentType::negotiate("application/msgpack, application/json;q=0.5"),
+        Some(ContentType::Json)
+    );
+    assert_eq!(ContentType::negotiate("application/json;q=0"), None);
+    assert_eq!(ContentType::negotiate("text/html"), None);
+}
+
+#[test]
+fn round_trip() {
+    let response = SubmitPackageResponse {
+        job_id: Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap(),
+    };
+    let body = response.to_body(ContentType::Json).unwrap();
+    assert_eq!(
+        SubmitPackageResponse::from_body(ContentType::Json, &body).unwrap(),
+        response
+    );
+}
+
+#[test]
+fn malformed_body() {
+    let error = SubmitPackageResponse::from_response(200, None, b"{}").unwrap_err();
+    assert!(matches!(error, BodyError::Decode(_)));
+    assert!(error.source().is_some());
+
+    let error =
+        SubmitPackageResponse::from_response(200, Some("text/html"), b"<html>").unwrap_err();
+    assert!(matches!(error, BodyError::UnsupportedContentType(t) if t == "text/html"));
+}
+
+#[test]
+fn api_error_body() {
+    let body = br#"{"error": {"code": "rate_limited", "description": "slow down"}}"#;
+    let error = CancelJobResponse::from_response(429, Some("application/json"), body).unwrap_err();
+    match error {
+        BodyError::Api(error) => {
+            assert_eq!(error.code, ErrorCode::RateLimited);
+            assert_eq!(error.description, "slow down");
+        }
+        error => panic!("unexpected error {}", error),
+    }
+}
+
+#[test]
+fn error_without_body() {
+    let error = CancelJobResponse::from_response(503, Some("text/plain"), b"upstream timeout\n")
+        .unwrap_err();
+    match error {
+        BodyError::Api(error) => {
+            assert_eq!(error.code, ErrorCode::Unavailable);
+            assert_eq!(error.description, "upstream timeout");
+        }
+        error => panic!("unexpected error {}", error),
+    }
+}
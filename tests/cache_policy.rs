@@ -0,0 +1,90 @@
+use std::time::Duration;
+
+use phylum_types::cache_policy::{CachePolicy, Cacheable, LISTING_MAX_AGE};
+use phylum_types::types::common::Status;
+use phylum_types::types::job::{AllJobsStatusResponse, JobStatusResponseVariant};
+
+const JOB_STATUS_EXTENDED: &str = include_str!("fixtures/job_status_extended.json");
+const JOB_STATUS_BASIC: &str = include_str!("fixtures/job_status_basic.json");
+
+#[test]
+fn header_parsing() {
+    let max_age = |seconds| CachePolicy::MaxAge(Duration::from_secs(seconds));
+    assert_eq!(CachePolicy::from_header("max-age=300"), max_age(300));
+    assert_eq!(
+        CachePolicy::from_header("public, Max-Age=600, must-revalidate"),
+        max_age(600)
+    );
+    assert_eq!(
+        CachePolicy::from_header("max-age=600, no-cache"),
+        CachePolicy::NoStore
+    );
+    assert_eq!(CachePolicy::from_header("private"), CachePolicy::NoStore);
+    assert_eq!(
+        CachePolicy::from_header("max-age=soon"),
+        CachePolicy::NoStore
+    );
+    assert_eq!(
+        CachePolicy::from_header("immutable"),
+        CachePolicy::Immutable
+    );
+    assert_eq!(CachePolicy::from_header(""), CachePolicy::Immutable);
+}
+
+#[test]
+fn header_round_trip() {
+    for policy in [
+        CachePolicy::NoStore,
+        CachePolicy::MaxAge(Duration::from_secs(45)),
+    ] {
+        assert_eq!(CachePolicy::from_header(&policy.to_string()), policy);
+    }
+    assert_eq!(
+        CachePolicy::from_header(&CachePolicy::Immutable.to_string()).max_age(),
+        Some(Duration::from_secs(31_536_000))
+    );
+}
+
+#[test]
+fn policies_order_by_restrictiveness() {
+    let short = CachePolicy::MaxAge(Duration::from_secs(10));
+    let long = CachePolicy::MaxAge(Duration::from_secs(100));
+    assert_eq!(long.min(short), short);
+    assert_eq!(CachePolicy::Immutable.min(long), long);
+    assert_eq!(short.min(CachePolicy::NoStore), CachePolicy::NoStore);
+
+    assert!(!CachePolicy::MaxAge(Duration::ZERO).is_cacheable());
+    assert_eq!(CachePolicy::Immutable.max_age(), None);
+}
+
+#[test]
+fn jobs_are_immutable_once_complete() {
+    let complete: JobStatusResponseVariant = serde_json::from_str(JOB_STATUS_EXTENDED).unwrap();
+    assert_eq!(complete.cache_policy(), CachePolicy::Immutable);
+
+    let incomplete: JobStatusResponseVariant = serde_json::from_str(JOB_STATUS_BASIC).unwrap();
+    assert!(!incomplete.is_cacheable());
+
+    if let JobStatusResponseVariant::Basic(job) = incomplete {
+        let package = &job.packages[0];
+        assert_eq!(package.status, Status::Complete);
+        assert_eq!(package.cache_policy(), CachePolicy::Immutable);
+    } else {
+        panic!("expected basic package statuses");
+    }
+}
+
+#[test]
+fn listings_are_briefly_fresh() {
+    let jobs: AllJobsStatusResponse =
+        serde_json::from_str(include_str!("fixtures/all_jobs_status_response.json")).unwrap();
+    assert_eq!(jobs.cache_policy(), CachePolicy::MaxAge(LISTING_MAX_AGE));
+    assert_eq!(
+        jobs.cache_policy_with(Some("max-age=3600")),
+        CachePolicy::MaxAge(LISTING_MAX_AGE)
+    );
+    assert_eq!(
+        jobs.cache_policy_with(Some("no-store")),
+        CachePolicy::NoStore
+    );
+}
@@ -0,0 +1,69 @@
+use phylum_types::endpoint::{CancelJob, Endpoint};
+use phylum_types::types::common::Status;
+use phylum_types::types::job::{CancelJobRequest, CancelJobResponse, JobMessageCode};
+use serde_json::json;
+
+#[test]
+fn request() {
+    assert_eq!(
+        serde_json::to_value(CancelJobRequest::default()).unwrap(),
+        json!({ "force": false })
+    );
+
+    let request = CancelJobRequest::default()
+        .with_reason("Superseded by a newer commit")
+        .with_force(true);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(
+        value,
+        json!({ "reason": "Superseded by a newer commit", "force": true })
+    );
+    assert_eq!(
+        serde_json::from_value::<CancelJobRequest>(json!({})).unwrap(),
+        CancelJobRequest::default()
+    );
+
+    let _: &<CancelJob as Endpoint>::Request = &request;
+}
+
+#[test]
+fn response_from_older_servers() {
+    let response: CancelJobResponse =
+        serde_json::from_value(json!({ "msg": "Job canceled" })).unwrap();
+    assert_eq!(response.job_id, None);
+    assert_eq!(response.previous_status, None);
+    assert_eq!(response.cancelled_at, None);
+    assert!(response.was_cancelled());
+
+    assert_eq!(
+        serde_json::to_value(&response).unwrap(),
+        json!({ "msg": "Job canceled" })
+    );
+}
+
+#[test]
+fn response_details() {
+    let response: CancelJobResponse = serde_json::from_value(json!({
+        "msg": "Job already complete",
+        "code": "already_complete",
+        "job_id": "59482a54-423b-448d-8325-f171c9dc336b",
+        "previous_status": "complete",
+        "cancelled_at": "2022-04-15T05:20:00Z",
+    }))
+    .unwrap();
+
+    assert_eq!(response.message_code(), JobMessageCode::AlreadyComplete);
+    assert_eq!(response.previous_status, Some(Status::Complete));
+    assert!(!response.was_cancelled());
+    assert_eq!(
+        response.job_id.unwrap().to_string(),
+        "59482a54-423b-448d-8325-f171c9dc336b"
+    );
+
+    let value = serde_json::to_value(&response).unwrap();
+    assert_eq!(value["cancelled_at"], "2022-04-15T05:20:00Z");
+    assert_eq!(
+        serde_json::from_value::<CancelJobResponse>(value).unwrap(),
+        response
+    );
+}
@@ -0,0 +1,125 @@
+use std::borrow::Cow;
+
+use indexmap::IndexMap;
+use phylum_types::casing::{to_snake_case, SnakeCase};
+use phylum_types::types::common::Status;
+use phylum_types::types::package::*;
+use serde_json::json;
+
+fn package_status() -> PackageStatusExtended {
+    PackageStatusExtended {
+        basic_status: PackageStatus {
+            purl: None,
+            name: "@types/node".into(),
+            version: "18.0.0".into(),
+            status: Status::Complete,
+            last_updated: 1_650_000_000,
+            license: Some("MIT".into()),
+            package_score: Some(1.0),
+            num_dependencies: 1,
+            num_vulnerabilities: None,
+            lockfiles: Vec::new(),
+            #[cfg(feature = "preserve-unknown")]
+            extra: Default::default(),
+        },
+        package_type: PackageType::Npm,
+        risk_vectors: IndexMap::from([("maliciousCode".into(), 1.0)]),
+        dependencies: IndexMap::from([("isNumber".into(), "^1.0.0".into())]),
+        issues: vec![IssueStatus {
+            issue: Issue::new("Title", RiskLevel::Low, RiskDomain::Malicious)
+                .with_tag("HM0001")
+                .with_description("Description"),
+            ignored: None,
+            #[cfg(deny_unknown_fields)]
+            extra: Default::default(),
+        }],
+        #[cfg(deny_unknown_fields)]
+        extra: Default::default(),
+    }
+}
+
+#[test]
+fn snake_case_conversion() {
+    assert_eq!(to_snake_case("riskVectors"), "risk_vectors");
+    assert_eq!(to_snake_case("dateTime"), "date_time");
+    assert_eq!(to_snake_case("already_snake"), "already_snake");
+    assert_eq!(to_snake_case("HTTPStatus"), "http_status");
+    assert_eq!(to_snake_case("type"), "type");
+    assert!(matches!(to_snake_case("already_snake"), Cow::Borrowed(_)));
+}
+
+#[test]
+fn default_profile() {
+    let value = serde_json::to_value(package_status()).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "name": "@types/node",
+            "version": "18.0.0",
+            "status": "complete",
+            "last_updated": 1_650_000_000,
+            "license": "MIT",
+            "package_score": 1.0,
+            "num_dependencies": 1,
+            "type": "npm",
+            "riskVectors": { "maliciousCode": 1.0 },
+            "dependencies": { "isNumber": "^1.0.0" },
+            "issues": [{
+                "tag": "HM0001",
+                "id": null,
+                "title": "Title",
+                "description": "Description",
+                "severity": "low",
+                "domain": "malicious_code",
+                "ignored": null,
+            }],
+        })
+    );
+}
+
+#[test]
+fn snake_case_profile() {
+    let value = serde_json::to_value(SnakeCase(package_status())).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "name": "@types/node",
+            "version": "18.0.0",
+            "status": "complete",
+            "last_updated": 1_650_000_000,
+            "license": "MIT",
+            "package_score": 1.0,
+            "num_dependencies": 1,
+            "type": "npm",
+            "risk_vectors": { "maliciousCode": 1.0 },
+            "dependencies": { "isNumber": "^1.0.0" },
+            "issues": [{
+                "tag": "HM0001",
+                "id": null,
+                "title": "Title",
+                "description": "Description",
+                "severity": "low",
+                "domain": "malicious_code",
+                "ignored": null,
+            }],
+        })
+    );
+}
+
+#[test]
+fn snake_case_profile_nested_structs() {
+    let point = ScoreDynamicsPoint {
+        date_time: "2022-01-01T00:00:00Z".parse().unwrap(),
+        score: 0.5,
+        label: "main".into(),
+    };
+
+    assert_eq!(
+        serde_json::to_value(&point).unwrap(),
+        json!({ "dateTime": "2022-01-01T00:00:00Z", "score": 0.5, "label": "main" })
+    );
+    assert_eq!(
+        serde_json::to_value(SnakeCase(vec![Some(point)])).unwrap(),
+        json!([{ "date_time": "2022-01-01T00:00:00Z", "score": 0.5, "label": "main" }])
+    );
+}
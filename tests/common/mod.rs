@@ -0,0 +1,58 @@
+//! JSON factories shared by the tests of job status responses.
+
+#![allow(dead_code)]
+
+use serde_json::{json, Value};
+
+/// A job status response containing `packages`, listing the ecosystems of
+/// the extended ones.
+pub fn job(packages: Vec<Value>) -> Value {
+    let mut ecosystems: Vec<&str> = packages
+        .iter()
+        .filter_map(|package| package["type"].as_str())
+        .collect();
+    ecosystems.dedup();
+    if ecosystems.is_empty() {
+        ecosystems.push("npm");
+    }
+
+    json!({
+        "job_id": "59482a54-423b-448d-8325-f171c9dc336b",
+        "ecosystems": ecosystems,
+        "user_id": "86f8f5ab-1f18-4a4d-b2c4-d5d9c5b1a3f0",
+        "user_email": "developer@example.com",
+        "created_at": 1_650_000_000,
+        "status": "complete",
+        "pass": true,
+        "msg": "Project met threshold requirements",
+        "num_incomplete": 0,
+        "last_updated": 1_650_000_123,
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "project_name": "storefront",
+        "label": null,
+        "packages": packages,
+    })
+}
+
+/// A basic package status.
+pub fn basic_package(name: &str) -> Value {
+    json!({
+        "name": name,
+        "version": "1.0.0",
+        "status": "complete",
+        "last_updated": 1_650_000_000,
+        "license": "MIT",
+        "package_score": 1.0,
+        "num_dependencies": 0,
+    })
+}
+
+/// An extended package status without risk vectors, dependencies or issues.
+pub fn extended_package(name: &str, package_type: &str) -> Value {
+    let mut package = basic_package(name);
+    package["type"] = json!(package_type);
+    package["riskVectors"] = json!({});
+    package["dependencies"] = json!({});
+    package["issues"] = json!([]);
+    package
+}
@@ -0,0 +1,57 @@
+use phylum_types::types::job::SubmitPackageRequest;
+use phylum_types::types::package::{DependencyKind, PackageRefAndLockfile};
+use serde_json::json;
+
+fn request() -> SubmitPackageRequest {
+    serde_json::from_value(json!({
+        "packages": [
+            { "name": "express", "version": "4.18.1", "type": "npm" },
+            { "name": "jest", "version": "29.0.0", "type": "npm", "kind": "dev" },
+            { "name": "fsevents", "version": "2.3.2", "type": "npm", "kind": "optional" },
+            { "name": "react", "version": "18.2.0", "type": "npm", "kind": "peer" },
+            { "name": "eslint", "version": "8.0.0", "type": "npm", "kind": "development" },
+        ],
+        "is_user": false,
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "label": "main",
+    }))
+    .unwrap()
+}
+
+#[allow(deprecated)]
+#[test]
+fn defaults_to_runtime() {
+    let request = request();
+    assert_eq!(request.packages[0].kind, DependencyKind::Runtime);
+
+    // Runtime is left out, so older servers see the same payload as before
+    let value = serde_json::to_value(&request).unwrap();
+    assert!(value["packages"][0].get("kind").is_none());
+    assert_eq!(value["packages"][1]["kind"], "dev");
+}
+
+#[allow(deprecated)]
+#[test]
+fn filter_by_kind() {
+    let mut request = request();
+    let dev: Vec<_> = request
+        .packages_of_kind(DependencyKind::Dev)
+        .map(|package| package.package_descriptor.name.as_str())
+        .collect();
+    assert_eq!(dev, ["jest", "eslint"]);
+
+    request.exclude_kinds(&[DependencyKind::Dev, DependencyKind::Optional]);
+    let names: Vec<_> = request
+        .packages
+        .iter()
+        .map(|package| package.package_descriptor.name.as_str())
+        .collect();
+    assert_eq!(names, ["express", "react"]);
+}
+
+#[test]
+fn kept_by_conversions() {
+    let request = request();
+    let package = PackageRefAndLockfile::from(request.packages[2].clone());
+    assert_eq!(package.kind, DependencyKind::Optional);
+}
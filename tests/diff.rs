@@ -0,0 +1,40 @@
+#![cfg(feature = "diff")]
+
+use phylum_types::diff::{diff, ChangeKind, FieldPath, PathSegment};
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::PackageStatus;
+use serde_json::json;
+
+fn job_status() -> JobStatusResponse<PackageStatus> {
+    serde_json::from_str(include_str!("fixtures/job_status_basic.json")).unwrap()
+}
+
+#[test]
+fn equal_values_have_no_changes() {
+    assert!(diff(&job_status(), &job_status()).unwrap().is_empty());
+}
+
+#[test]
+fn nested_changes() {
+    let old = job_status();
+    let mut new = job_status();
+    new.msg = "Project failed threshold requirements".into();
+    new.packages[0].license = Some("GPL-3.0".into());
+    new.packages.push(old.packages[0].clone());
+
+    let changes = diff(&old, &new).unwrap();
+    let paths: Vec<String> = changes
+        .iter()
+        .map(|change| change.path.to_string())
+        .collect();
+    assert_eq!(paths, ["msg", "packages[0].license", "packages[2]"]);
+    assert_eq!(changes[0].kind(), ChangeKind::Modified);
+    assert_eq!(changes[2].kind(), ChangeKind::Added);
+    assert_eq!(changes[1].new, Some(json!("GPL-3.0")));
+
+    let packages = FieldPath(vec![PathSegment::Field("packages".into())]);
+    assert_eq!(changes.under(&packages).count(), 2);
+
+    let removed = diff(&new, &old).unwrap();
+    assert_eq!(removed[2].kind(), ChangeKind::Removed);
+}
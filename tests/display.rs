@@ -0,0 +1,97 @@
+use phylum_types::types::job::{JobDescriptor, JobStatusResponse};
+use phylum_types::types::package::{
+    Issue, Package, PackageStatus, PackageStatusExtended, RiskDomain, RiskLevel,
+};
+
+fn issue() -> Issue {
+    Issue::new(
+        "Command injection in lodash",
+        RiskLevel::High,
+        RiskDomain::Vulnerabilities,
+    )
+    .with_tag("HV00001")
+    .with_id("CVE-2021-23337")
+    .with_description("`template` allows command injection.")
+}
+
+#[test]
+fn package() {
+    let package: Package = serde_json::from_str(include_str!("fixtures/package.json")).unwrap();
+    assert_eq!(package.to_string(), "npm/left-pad@1.3.0");
+    assert_eq!(
+        package.summary(),
+        "npm/left-pad@1.3.0 [score 0.84, 1 issue]"
+    );
+}
+
+#[test]
+fn package_statuses() {
+    let json = include_str!("fixtures/job_status_extended.json");
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_str(json).unwrap();
+    let status = &job.packages[0];
+    assert_eq!(status.to_string(), "npm/express@4.18.1");
+    assert_eq!(status.summary(), "npm/express@4.18.1 [score 0.86, 1 issue]");
+
+    let json = include_str!("fixtures/job_status_basic.json");
+    let job: JobStatusResponse<PackageStatus> = serde_json::from_str(json).unwrap();
+    let status = &job.packages[0];
+    assert_eq!(status.to_string(), "requests@2.28.1");
+    // The count is unknown rather than zero
+    assert_eq!(status.num_vulnerabilities, None);
+    assert_eq!(status.summary(), "requests@2.28.1 [score 0.99]");
+
+    let mut status = status.clone();
+    status.num_vulnerabilities = Some(0);
+    assert_eq!(
+        status.summary(),
+        "requests@2.28.1 [score 0.99, 0 vulnerabilities]"
+    );
+    status.num_vulnerabilities = Some(1);
+    assert_eq!(
+        status.summary(),
+        "requests@2.28.1 [score 0.99, 1 vulnerability]"
+    );
+}
+
+#[test]
+fn issue_identifier() {
+    let mut issue = issue();
+    assert_eq!(
+        issue.to_string(),
+        "[high VLN] Command injection in lodash (CVE-2021-23337)"
+    );
+
+    issue.id = None;
+    assert_eq!(
+        issue.to_string(),
+        "[high VLN] Command injection in lodash (HV00001)"
+    );
+
+    issue.tag = None;
+    assert_eq!(issue.to_string(), "[high VLN] Command injection in lodash");
+}
+
+#[test]
+fn job_descriptor() {
+    let mut job: JobDescriptor = serde_json::from_value(serde_json::json!({
+        "job_id": "59482a54-423b-448d-8325-f171c9dc336b",
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "label": "main",
+        "num_dependencies": 12,
+        "packages": [],
+        "pass": true,
+        "msg": "Project met threshold requirements",
+        "date": "2022-06-01T12:00:00Z",
+    }))
+    .unwrap();
+    assert_eq!(
+        job.summary(),
+        "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8@main (job 59482a54-423b-448d-8325-f171c9dc336b) [pass, 12 dependencies]"
+    );
+
+    job.pass = false;
+    job.num_incomplete = 2;
+    assert!(job
+        .summary()
+        .ends_with("[fail, 12 dependencies, 2 incomplete]"));
+}
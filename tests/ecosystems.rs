@@ -0,0 +1,164 @@
+use std::convert::TryFrom;
+use std::str::FromStr;
+
+use phylum_types::types::job::{JobStatusResponse, JobStatusResponseVariant};
+use phylum_types::types::package::*;
+use serde_json::json;
+
+mod common;
+
+use common::{extended_package, job};
+
+fn mixed_job() -> serde_json::Value {
+    job(vec![
+        extended_package("left-pad", "npm"),
+        extended_package("monolog/monolog", "composer"),
+        extended_package("requests", "pypi"),
+    ])
+}
+
+#[test]
+fn mixed_ecosystem_job_deserializes() {
+    let job: JobStatusResponse<PackageStatusExtended> =
+        serde_json::from_value(mixed_job()).unwrap();
+
+    let types: Vec<_> = job.packages.iter().map(|p| p.package_type).collect();
+    assert_eq!(
+        types,
+        [PackageType::Npm, PackageType::Composer, PackageType::PyPi]
+    );
+
+    let roundtrip = serde_json::to_value(&job).unwrap();
+    assert_eq!(roundtrip["packages"][1]["type"], "composer");
+}
+
+#[test]
+fn mixed_ecosystem_job_is_extended_variant() {
+    let job: JobStatusResponseVariant = serde_json::from_value(mixed_job()).unwrap();
+    assert!(matches!(job, JobStatusResponseVariant::Extended(_)));
+}
+
+#[test]
+fn composer_aliases() {
+    for alias in ["composer", "Composer", "php", "packagist"] {
+        assert_eq!(PackageType::from_str(alias), Ok(PackageType::Composer));
+    }
+    assert_eq!(PackageType::Composer.to_string(), "composer");
+    assert_eq!(PackageType::Composer.language(), "PHP");
+    assert_eq!(PackageType::Composer.purl_type(), "composer");
+}
+
+#[allow(deprecated)]
+#[test]
+fn composer_descriptor_namespace() {
+    let descriptor = PackageDescriptor::new("monolog/monolog", "3.3.1", PackageType::Composer);
+    assert_eq!(descriptor.namespace(), Some("monolog"));
+    assert_eq!(descriptor.local_name(), "monolog");
+    assert_eq!(descriptor.full_name(), "monolog/monolog");
+}
+
+#[test]
+fn conan_aliases() {
+    for alias in ["conan", "Conan", "cpp", "c++"] {
+        assert_eq!(PackageType::from_str(alias), Ok(PackageType::Conan));
+    }
+    assert_eq!(PackageType::Conan.to_string(), "conan");
+    assert_eq!(PackageType::Conan.language(), "C++");
+    assert_eq!(PackageType::Conan.purl_type(), "conan");
+    assert!(purl::PackageType::try_from(PackageType::Conan).is_err());
+    assert_eq!(
+        serde_json::from_value::<PackageType>(json!("conan")).unwrap(),
+        PackageType::Conan
+    );
+}
+
+#[allow(deprecated)]
+#[test]
+fn conan_descriptor_reference() {
+    let descriptor = PackageDescriptor::conan("zlib/1.2.13@user/channel").unwrap();
+    assert_eq!(descriptor.package_type, PackageType::Conan);
+    assert_eq!(descriptor.name, "zlib");
+    assert_eq!(descriptor.version, "1.2.13@user/channel");
+    assert_eq!(descriptor.namespace(), None);
+    assert_eq!(descriptor.full_name(), "zlib");
+
+    let specifier = PackageSpecifier::from(&descriptor);
+    assert_eq!(specifier.registry, "conan");
+    assert_eq!(PackageDescriptor::try_from(specifier), Ok(descriptor));
+
+    let plain = PackageDescriptor::conan("fmt/10.0.0").unwrap();
+    assert_eq!(plain.version, "10.0.0");
+    assert_eq!(PackageDescriptor::conan("zlib"), None);
+    assert_eq!(PackageDescriptor::conan("zlib/"), None);
+}
+
+#[allow(deprecated)]
+#[test]
+fn conan_package_ref() {
+    let package = PackageRef::conan("zlib/1.2.13@user/channel").unwrap();
+    assert_eq!(package.package_type(), Some(PackageType::Conan));
+    assert_eq!(package.name, "zlib");
+    assert_eq!(package.version, "1.2.13@user/channel");
+    assert_eq!(package.namespace, None);
+    assert_eq!(
+        serde_json::to_value(&package).unwrap(),
+        json!({ "name": "zlib", "version": "1.2.13@user/channel", "type": "conan" })
+    );
+    assert_eq!(
+        PackageDescriptor::try_from(package),
+        Ok(PackageDescriptor::conan("zlib/1.2.13@user/channel").unwrap())
+    );
+
+    let cpp: PackageRef =
+        serde_json::from_value(json!({ "name": "fmt", "version": "10.0.0", "type": "c++" }))
+            .unwrap();
+    assert_eq!(cpp.package_type(), Some(PackageType::Conan));
+    assert_eq!(PackageRef::conan("zlib"), None);
+}
+
+#[test]
+fn job_package_types() {
+    let mut job = mixed_job();
+    job["ecosystems"] = json!(["npm", "python", "cobol"]);
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_value(job).unwrap();
+
+    assert_eq!(
+        job.ecosystems,
+        [
+            Ecosystem::Known(PackageType::Npm),
+            Ecosystem::Unknown("python".into()),
+            Ecosystem::Unknown("cobol".into()),
+        ]
+    );
+    assert_eq!(
+        job.package_types().collect::<Vec<_>>(),
+        [PackageType::Npm, PackageType::PyPi]
+    );
+
+    let value = serde_json::to_value(&job).unwrap();
+    assert_eq!(value["ecosystems"], json!(["npm", "python", "cobol"]));
+}
+
+#[test]
+fn ecosystem_normalization() {
+    assert_eq!(
+        Ecosystem::normalize(" Python"),
+        Ecosystem::Known(PackageType::PyPi)
+    );
+    assert_eq!(
+        Ecosystem::normalize("maven-central"),
+        Ecosystem::Known(PackageType::Maven)
+    );
+    assert_eq!(
+        Ecosystem::normalize(" cobol "),
+        Ecosystem::Unknown("cobol".into())
+    );
+
+    let ecosystem = Ecosystem::from("gem");
+    assert_eq!(ecosystem, Ecosystem::Unknown("gem".into()));
+    assert_eq!(
+        ecosystem.normalized(),
+        Ecosystem::Known(PackageType::RubyGems)
+    );
+    assert_eq!(ecosystem.normalized().to_string(), "rubygems");
+}
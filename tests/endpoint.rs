@@ -0,0 +1,79 @@
+use phylum_types::endpoint::{
+    CancelJob, Endpoint, GetHealth, GetPackage, ListJobs, Method, SubmitPackages,
+};
+use phylum_types::types::job::{JobListParams, SubmitPackageResponse};
+use phylum_types::types::package::{PackageRef, PackageType};
+use uuid::Uuid;
+
+/// What a generic client needs from an endpoint, without knowing which it is.
+fn request_line<E: Endpoint>(params: &E::Params, query: &E::Query) -> String {
+    let query = serde_urlencoded::to_string(query).unwrap();
+    let path = E::path(params);
+    if query.is_empty() {
+        format!("{} {path}", E::METHOD)
+    } else {
+        format!("{} {path}?{query}", E::METHOD)
+    }
+}
+
+#[test]
+fn paths_without_parameters() {
+    assert_eq!(GetHealth::path(&()), "/health");
+    assert_eq!(request_line::<SubmitPackages>(&(), &()), "POST /data/jobs");
+}
+
+#[test]
+fn job_path() {
+    let job_id = Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap();
+    assert_eq!(
+        request_line::<CancelJob>(&job_id, &()),
+        "DELETE /data/jobs/0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"
+    );
+}
+
+#[test]
+fn package_path_is_encoded() {
+    let package = PackageRef::new("@types/node", "18.0.0", PackageType::Npm);
+    assert_eq!(
+        GetPackage::path(&package),
+        "/data/packages/npm/%40types%2Fnode/18.0.0"
+    );
+
+    let mut package = PackageRef::new("commons-lang3", "3.12.0", PackageType::Maven);
+    package.namespace = Some("org.apache.commons".into());
+    assert_eq!(
+        GetPackage::path(&package),
+        "/data/packages/maven/org.apache.commons%3Acommons-lang3/3.12.0"
+    );
+}
+
+#[test]
+fn query_parameters() {
+    let params = JobListParams {
+        limit: Some(10),
+        ..Default::default()
+    };
+    assert_eq!(
+        request_line::<ListJobs>(&(), &params),
+        "GET /data/jobs?limit=10"
+    );
+}
+
+#[test]
+fn response_type() {
+    let response: <SubmitPackages as Endpoint>::Response =
+        serde_json::from_str(r#"{"job_id":"0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"}"#).unwrap();
+    assert_eq!(
+        response,
+        SubmitPackageResponse {
+            job_id: Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap(),
+        }
+    );
+}
+
+#[test]
+fn method_names() {
+    assert_eq!(Method::Delete.to_string(), "DELETE");
+    assert_eq!("patch".parse(), Ok(Method::Patch));
+    assert_eq!("TRACE".parse::<Method>(), Err(()));
+}
@@ -0,0 +1,72 @@
+use phylum_types::exhaustive::{ByRiskDomain, ByRiskLevel, Exhaustive};
+use phylum_types::types::package::{RiskDomain, RiskLevel};
+use phylum_types::{all_risk_domains, all_risk_levels};
+use serde_json::json;
+
+#[test]
+fn all_in_declaration_order() {
+    assert_eq!(RiskDomain::ALL, all_risk_domains!());
+    assert_eq!(RiskLevel::ALL, all_risk_levels!());
+
+    for (index, domain) in RiskDomain::ALL.iter().enumerate() {
+        assert_eq!(domain.index(), index);
+    }
+    for (index, level) in RiskLevel::ALL.iter().enumerate() {
+        assert_eq!(level.index(), index);
+    }
+
+    let mut levels = RiskLevel::ALL.to_vec();
+    levels.sort();
+    assert_eq!(levels, RiskLevel::ALL);
+    assert_eq!(RiskDomain::COUNT, 5);
+}
+
+#[test]
+fn macro_bodies() {
+    let scores = all_risk_levels!(|level| level.score());
+    assert_eq!(scores, [1., 0.8, 0.65, 0.35, 0.1]);
+
+    let names = all_risk_domains!(|domain| domain.to_string());
+    assert_eq!(names[2], RiskDomain::Malicious.to_string());
+}
+
+#[test]
+fn tables() {
+    let mut table = ByRiskLevel::from_fn(|level| level.index());
+    assert_eq!(table[RiskLevel::High], 3);
+    table[RiskLevel::High] = 10;
+    assert_eq!(table.high, 10);
+
+    let entries: Vec<_> = table.iter().map(|(level, value)| (level, *value)).collect();
+    assert_eq!(entries[3], (RiskLevel::High, 10));
+    assert_eq!(entries.len(), RiskLevel::COUNT);
+
+    let doubled = table.map(|value| value * 2);
+    assert_eq!(*doubled.get(RiskLevel::Critical), 8);
+}
+
+#[test]
+fn domain_table_serde() {
+    let weights = ByRiskDomain {
+        author: 1,
+        engineering: 2,
+        malicious: 3,
+        vulnerability: 4,
+        license: 5,
+    };
+    let value = serde_json::to_value(&weights).unwrap();
+    assert_eq!(
+        value,
+        json!({ "author": 1, "engineering": 2, "malicious_code": 3, "vulnerability": 4, "license": 5 })
+    );
+    assert_eq!(
+        serde_json::from_value::<ByRiskDomain<u8>>(value).unwrap(),
+        weights
+    );
+    assert_eq!(weights[RiskDomain::Malicious], 3);
+
+    let legacy =
+        json!({ "author": 0, "engineering": 0, "malicious": 7, "vulnerability": 0, "license": 0 });
+    let parsed: ByRiskDomain<u8> = serde_json::from_value(legacy).unwrap();
+    assert_eq!(parsed.malicious, 7);
+}
@@ -0,0 +1,55 @@
+use phylum_types::types::package::{ExploitMaturity, Issue};
+use serde_json::json;
+
+fn issue(exploit_maturity: Option<&str>) -> Issue {
+    let mut value = json!({
+        "tag": null,
+        "id": "CVE-2021-44228",
+        "title": "Remote code execution in log4j",
+        "description": "JNDI lookups in log messages allow remote code execution.",
+        "severity": "critical",
+        "domain": "vulnerability",
+    });
+    if let Some(exploit_maturity) = exploit_maturity {
+        value["exploit_maturity"] = exploit_maturity.into();
+    }
+    serde_json::from_value(value).unwrap()
+}
+
+#[test]
+fn aliases() {
+    assert_eq!(
+        issue(Some("poc")).exploit_maturity,
+        Some(ExploitMaturity::ProofOfConcept)
+    );
+    for name in ["weaponized", "itw", "in_the_wild"] {
+        assert_eq!(
+            issue(Some(name)).exploit_maturity,
+            Some(ExploitMaturity::Weaponized)
+        );
+    }
+
+    let value = serde_json::to_value(issue(Some("poc"))).unwrap();
+    assert_eq!(value["exploit_maturity"], "proof_of_concept");
+}
+
+#[test]
+fn optional() {
+    let issue = issue(None);
+    assert_eq!(issue.exploit_maturity, None);
+    assert!(serde_json::to_value(issue)
+        .unwrap()
+        .get("exploit_maturity")
+        .is_none());
+}
+
+#[test]
+fn ordered_by_maturity() {
+    assert!(ExploitMaturity::Unproven < ExploitMaturity::ProofOfConcept);
+    assert!(ExploitMaturity::Functional < ExploitMaturity::Weaponized);
+
+    assert!(!ExploitMaturity::Unproven.has_exploit());
+    assert!(ExploitMaturity::ProofOfConcept.has_exploit());
+    assert!(!ExploitMaturity::Functional.is_exploited());
+    assert!(ExploitMaturity::Weaponized.is_exploited());
+}
@@ -0,0 +1,276 @@
+//! Every field of the API types must serialize under the name recorded in
+//! `tests/fixtures/field_names.txt`, and deserialize from both its
+//! snake_case and camelCase spelling.
+//!
+//! Types with flattened fields cannot be probed for the names they accept,
+//! so only their golden names are checked; the flattened types are probed on
+//! their own.
+//!
+//! Run with `UPDATE_GOLDEN=1` to rewrite the list after an intended change to
+//! the wire format, and review the diff.
+
+use std::path::PathBuf;
+use std::{fmt, fs};
+
+use phylum_types::casing::to_snake_case;
+use phylum_types::types::aggregate::*;
+use phylum_types::types::analysis::*;
+use phylum_types::types::auth::*;
+use phylum_types::types::common::*;
+use phylum_types::types::feed::*;
+use phylum_types::types::firewall::*;
+use phylum_types::types::group::*;
+use phylum_types::types::health::*;
+use phylum_types::types::job::*;
+use phylum_types::types::lockfile::*;
+use phylum_types::types::notification::*;
+use phylum_types::types::organization::*;
+use phylum_types::types::package::*;
+use phylum_types::types::policy::*;
+use phylum_types::types::preferences::*;
+use phylum_types::types::project::*;
+use phylum_types::types::remediation::*;
+use phylum_types::types::report::*;
+use phylum_types::types::schedule::*;
+use phylum_types::types::ssvc::*;
+use phylum_types::types::token::*;
+use phylum_types::types::trend::*;
+use phylum_types::types::user::*;
+use phylum_types::types::user_settings::*;
+use phylum_types::types::view::*;
+use phylum_types::types::webhook::*;
+use phylum_types::types::{v1, v2};
+use schemars::JsonSchema;
+use serde::de::{self, Deserialize, Visitor};
+use serde::forward_to_deserialize_any;
+
+/// What a type asked [`Probe`] to deserialize.
+#[derive(Debug)]
+enum Probed {
+    /// A struct accepting these field names, including aliases
+    Struct(&'static [&'static str]),
+    /// Anything else, such as a struct with flattened fields
+    Other,
+}
+
+impl fmt::Display for Probed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl std::error::Error for Probed {}
+
+impl de::Error for Probed {
+    fn custom<T: fmt::Display>(_: T) -> Self {
+        Probed::Other
+    }
+}
+
+/// A deserializer which fails with the field names of the struct being
+/// deserialized.
+struct Probe;
+
+impl<'de> de::Deserializer<'de> for Probe {
+    type Error = Probed;
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Probed> {
+        Err(Probed::Other)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Probed> {
+        Err(Probed::Struct(fields))
+    }
+}
+
+/// Names accepted by `T` on deserialize; `None` if `T` has flattened fields,
+/// as serde does not list those.
+fn accepted<'de, T: Deserialize<'de>>() -> Option<&'static [&'static str]> {
+    match T::deserialize(Probe) {
+        Err(Probed::Struct(fields)) => Some(fields),
+        _ => None,
+    }
+}
+
+/// Names `T` serializes its fields as.
+fn canonical<T: JsonSchema>() -> Vec<String> {
+    let schema = schemars::schema_for!(T);
+    schema
+        .schema
+        .object
+        .map(|object| object.properties.keys().cloned().collect())
+        .unwrap_or_default()
+}
+
+fn to_camel_case(name: &str) -> String {
+    let mut parts = name.split('_');
+    let mut camel = parts.next().unwrap_or_default().to_owned();
+    for part in parts {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            camel.extend(first.to_uppercase());
+            camel.push_str(chars.as_str());
+        }
+    }
+    camel
+}
+
+/// The spelling of `name` in the other casing.
+fn other_casing(name: &str) -> String {
+    if name.contains('_') {
+        to_camel_case(name)
+    } else {
+        to_snake_case(name).into_owned()
+    }
+}
+
+struct Checked {
+    lines: Vec<String>,
+    missing_aliases: Vec<String>,
+}
+
+impl Checked {
+    fn check<T: JsonSchema + for<'de> Deserialize<'de>>(&mut self, name: &str) {
+        let accepted = accepted::<T>();
+        for field in canonical::<T>() {
+            if let Some(accepted) = accepted {
+                let other = other_casing(&field);
+                if !accepted.contains(&other.as_str()) {
+                    self.missing_aliases
+                        .push(format!("{name}.{field} ({other})"));
+                }
+            }
+            self.lines.push(format!("{name}.{field}"));
+        }
+    }
+}
+
+macro_rules! check_all {
+    ($checked:ident: $($ty:ty),+ $(,)?) => {
+        $($checked.check::<$ty>(&stringify!($ty).replace(' ', ""));)+
+    };
+}
+
+#[allow(deprecated)]
+#[test]
+fn field_names() {
+    let mut checked = Checked {
+        lines: Vec::new(),
+        missing_aliases: Vec::new(),
+    };
+    check_all!(checked:
+        AggregatedIssue,
+        LockfileMetadata, AnalyzeRequest, PolicyRejection, AnalysisPolicyResult, AnalyzeResponse,
+        TokenResponse, AccessTokenResponse,
+        StatusTransition, ApiError, ApiErrorResponse, PageParams,
+        Paginated<ProjectSummaryResponse>,
+        FeedState, FeedStatusResponse,
+        FirewallDecision, QuarantinedPackage, FirewallLogEntry, FirewallPolicy,
+        CreateGroupRequest, CreateGroupResponse, KickUserFromGroupRequest, UserGroup,
+        ListUserGroupsResponse, GroupMember, ListGroupMembersResponse, RoleAssignment,
+        ListRoleAssignmentsResponse,
+        ComponentHealth, HealthResponse, VersionResponse,
+        JobDescriptor, SubmitPackageRequest, SubmitOptions, SubmitPackageResponse,
+        BatchSubmitRequest, BatchSubmitFailure, BatchSubmitResponse, AllJobsStatusResponse,
+        JobListParams, JobStatusResponse<PackageStatus>,
+        JobStatusResponse<PackageStatusExtended>, CancelJobRequest, CancelJobResponse,
+        JobStateHistoryEntry, JobStateHistoryResponse, LockfileGroup, JobByLockfileView,
+        ParsedLockfile, LockfileRef,
+        AdvisorySummary, AdvisoryImpactNotification,
+        Organization, ListOrgsResponse, OrgMember, ListOrgMembersResponse, OrgInviteRequest,
+        OrgInviteResponse,
+        ScoredVersion, RiskScores, RiskWeights, ScoreDynamicsPoint, Issue, Reference, IssueBody,
+        DescriptionSection, AffectedArtifact, IssueEvidence, LineRange,
+        ExternalReference, IssuesListItem, Author, DeveloperResponsiveness, Package,
+        FundingLink, Stewardship, DisclosurePolicy, AnalysisProvenance, PackageReleaseData,
+        PackageUrlAndLockfile, PackageStatus, PackageStatusExtended, IssueStatus,
+        PolicyLayer, EffectiveThreshold, EffectiveIgnoredIssue, EffectivePolicy,
+        CorePreferences, ProjectPreferences, PreferencesPatch, RiskThresholds, IgnoredIssue,
+        ScoreConfig,
+        ProjectSummaryResponse, CreateProjectRequest, ProjectPatch, CreateProjectResponse,
+        AffectedJob, AffectedProject, AffectedProjectsResponse,
+        RemediationInfo, UpgradeStep, RemediationPlan, ManifestEdit, LockfileRegeneration,
+        FixPullRequest, ListFixPullRequestsResponse,
+        ReportRequest, ReportStatus, ReportDownloadInfo,
+        ReanalysisConfig, ScheduledRun, ListScheduledRunsResponse,
+        SsvcInputs, SsvcAssessment,
+        ListApiTokensResponse, RevokeTokenRequest,
+        ScoreTrend, ScoreDelta,
+        UserProfile, UserToken, CreateTokenRequest, CreateTokenResponse,
+        Threshold, UserProject, UserSettings,
+        v1::HeuristicResult, v1::Vulnerability, v1::PackageDescriptor, v1::PackageSpecifier,
+        v1::PackageDescriptorAndLockfile, v1::PackageSpecifierAndLockfile,
+        v2::PackageRef, v2::PackageRefAndLockfile, v2::HeuristicResult,
+        IssueFilter, SavedView, SaveViewRequest, ListViewsResponse,
+        JobCompletedEvent, PolicyFailedEvent, CriticalIssueEvent, WebhookDelivery,
+    );
+
+    assert!(
+        checked.missing_aliases.is_empty(),
+        "fields not accepted in their other casing: {:#?}",
+        checked.missing_aliases
+    );
+
+    let actual = checked.lines.join("\n") + "\n";
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "fixtures",
+        "field_names.txt",
+    ]
+    .iter()
+    .collect();
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("{}: {} (run with UPDATE_GOLDEN=1)", path.display(), err));
+    assert!(
+        actual == expected,
+        "field names differ from {}; rerun with UPDATE_GOLDEN=1 if intended\n{}",
+        path.display(),
+        actual
+    );
+}
+
+/// Borrowed views must accept every name the owned types do.
+///
+/// Unknown fields are flattened into the owned types with `preserve-unknown`,
+/// which hides their names.
+#[cfg(not(feature = "preserve-unknown"))]
+#[allow(deprecated)]
+#[test]
+fn borrowed_types_accept_the_same_names() {
+    use phylum_types::types::borrowed::*;
+
+    fn same<'de, Owned: Deserialize<'de>, Borrowed: Deserialize<'de>>() {
+        let owned = accepted::<Owned>().unwrap();
+        let borrowed = accepted::<Borrowed>().unwrap();
+        for name in owned {
+            assert!(
+                borrowed.contains(name),
+                "{} does not accept {}",
+                std::any::type_name::<Borrowed>(),
+                name
+            );
+        }
+    }
+
+    same::<v1::PackageDescriptor, PackageDescriptorRef>();
+    same::<PackageStatus, PackageStatusRef>();
+    same::<Issue, IssueRef>();
+    same::<SubmitPackageRequest, SubmitPackageRequestRef>();
+}
@@ -0,0 +1,33 @@
+#![cfg(feature = "fixtures")]
+
+use phylum_types::fixtures;
+
+#[test]
+fn every_fixture_is_listed_and_found_by_name() {
+    let on_disk = std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures"))
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "json"))
+        .count();
+    assert_eq!(fixtures::ALL.len(), on_disk);
+
+    for (name, json) in fixtures::ALL {
+        assert_eq!(fixtures::get(name), Some(*json));
+    }
+    assert_eq!(fixtures::get("missing.json"), None);
+}
+
+#[test]
+fn typed_fixtures_parse() {
+    assert!(!fixtures::all_jobs_status_response().jobs.is_empty());
+    assert!(!fixtures::api_error_not_found().error.description.is_empty());
+    assert!(!fixtures::job_status_basic().packages.is_empty());
+    assert!(!fixtures::job_status_extended().packages.is_empty());
+    assert!(!fixtures::package().name.is_empty());
+    assert!(!fixtures::project_summaries().is_empty());
+    assert!(!fixtures::submit_package_request().packages.is_empty());
+    assert_eq!(
+        fixtures::submit_package_response().job_id.to_string(),
+        fixtures::SUBMIT_PACKAGE_RESPONSE.split('"').nth(3).unwrap()
+    );
+}
@@ -0,0 +1,113 @@
+//! Golden-file tests: every fixture must serialize back to the JSON recorded
+//! under `tests/fixtures/golden`, whatever features are enabled.
+//!
+//! Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an intended
+//! change to the wire format, and review the diff.
+
+use std::fs;
+use std::path::PathBuf;
+
+use phylum_types::types::common::ApiErrorResponse;
+use phylum_types::types::job::{
+    AllJobsStatusResponse, JobStatusResponse, SubmitPackageRequest, SubmitPackageResponse,
+};
+use phylum_types::types::package::{Package, PackageStatus, PackageStatusExtended};
+use phylum_types::types::project::ProjectSummaryResponse;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+fn check<T: DeserializeOwned + Serialize>(name: &str, json: &str) {
+    let parsed: T = serde_json::from_str(json).unwrap();
+    let actual = serde_json::to_string_pretty(&parsed).unwrap() + "\n";
+
+    let path: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "fixtures",
+        "golden",
+        name,
+    ]
+    .iter()
+    .collect();
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("{}: {} (run with UPDATE_GOLDEN=1)", path.display(), err));
+    assert!(
+        actual == expected,
+        "{} differs from its golden file; rerun with UPDATE_GOLDEN=1 if intended\n{}",
+        name,
+        actual
+    );
+
+    // The golden file itself must be a fixed point.
+    let reparsed: T = serde_json::from_str(&expected).unwrap();
+    assert_eq!(
+        serde_json::to_string_pretty(&reparsed).unwrap() + "\n",
+        expected
+    );
+}
+
+#[test]
+fn all_jobs_status_response() {
+    check::<AllJobsStatusResponse>(
+        "all_jobs_status_response.json",
+        include_str!("fixtures/all_jobs_status_response.json"),
+    );
+}
+
+#[test]
+fn api_error_not_found() {
+    check::<ApiErrorResponse>(
+        "api_error_not_found.json",
+        include_str!("fixtures/api_error_not_found.json"),
+    );
+}
+
+#[test]
+fn job_status_basic() {
+    check::<JobStatusResponse<PackageStatus>>(
+        "job_status_basic.json",
+        include_str!("fixtures/job_status_basic.json"),
+    );
+}
+
+#[test]
+fn job_status_extended() {
+    check::<JobStatusResponse<PackageStatusExtended>>(
+        "job_status_extended.json",
+        include_str!("fixtures/job_status_extended.json"),
+    );
+}
+
+#[test]
+fn package() {
+    check::<Package>("package.json", include_str!("fixtures/package.json"));
+}
+
+#[test]
+fn project_summaries() {
+    check::<Vec<ProjectSummaryResponse>>(
+        "project_summaries.json",
+        include_str!("fixtures/project_summaries.json"),
+    );
+}
+
+#[test]
+fn submit_package_request() {
+    check::<SubmitPackageRequest>(
+        "submit_package_request.json",
+        include_str!("fixtures/submit_package_request.json"),
+    );
+}
+
+#[test]
+fn submit_package_response() {
+    check::<SubmitPackageResponse>(
+        "submit_package_response.json",
+        include_str!("fixtures/submit_package_response.json"),
+    );
+}
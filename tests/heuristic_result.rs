@@ -0,0 +1,52 @@
+use phylum_types::types::package::{RiskDomain, RiskLevel};
+use phylum_types::types::{v1, v2};
+use serde_json::json;
+
+#[test]
+fn old_payload() {
+    let json = r#"{"domain": "malicious_code", "score": 0.2, "risk_level": "high"}"#;
+    let result: v2::HeuristicResult = serde_json::from_str(json).unwrap();
+    assert_eq!(result.heuristic_id, "");
+    assert_eq!(result.domain, RiskDomain::Malicious);
+    assert_eq!(result.weight, 1.0);
+    assert!(result.raw_output.is_null());
+
+    let old: v1::HeuristicResult = serde_json::from_str(json).unwrap();
+    assert_eq!(v2::HeuristicResult::from(old), result);
+    assert_eq!(
+        serde_json::to_value(&result).unwrap(),
+        json!({
+            "heuristic_id": "",
+            "name": "",
+            "domain": "malicious_code",
+            "score": 0.2,
+            "weight": 1.0,
+            "risk_level": "high",
+        })
+    );
+}
+
+#[test]
+fn service_payload() {
+    let json = json!({
+        "heuristicId": "install_script_network",
+        "name": "Install script network access",
+        "description": "Install scripts which download code",
+        "riskDomain": "malicious_code",
+        "score": 0.1,
+        "weight": 2.5,
+        "riskLevel": "critical",
+        "rawOutput": {"hosts": ["example.com"]},
+    });
+    let result: v2::HeuristicResult = serde_json::from_value(json).unwrap();
+    assert_eq!(result.heuristic_id, "install_script_network");
+    assert_eq!(result.weight, 2.5);
+    assert_eq!(result.raw_output["hosts"][0], "example.com");
+
+    let old = v1::HeuristicResult::from(result.clone());
+    assert_eq!(old.risk_level, RiskLevel::Critical);
+
+    let reparsed: v2::HeuristicResult =
+        serde_json::from_value(serde_json::to_value(&result).unwrap()).unwrap();
+    assert_eq!(reparsed, result);
+}
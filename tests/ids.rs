@@ -0,0 +1,63 @@
+use phylum_types::types::job::{AllJobsStatusResponse, JobStatusResponse};
+use phylum_types::types::package::PackageStatus;
+use phylum_types::validation::Validate;
+use serde_json::Value;
+use uuid::Uuid;
+
+fn job_status(
+    edit: impl FnOnce(&mut Value),
+) -> serde_json::Result<JobStatusResponse<PackageStatus>> {
+    let mut value: Value =
+        serde_json::from_str(include_str!("fixtures/job_status_basic.json")).unwrap();
+    edit(&mut value);
+    serde_json::from_value(value)
+}
+
+#[test]
+fn hyphenless_ids() {
+    let response = job_status(|value| {
+        value["project"] = "0bd8a7b621f34e8b9d3e3e6e1ad0a7e8".into();
+    })
+    .unwrap();
+
+    let project = Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap();
+    assert_eq!(response.project, project);
+    let serialized = serde_json::to_value(&response).unwrap();
+    assert_eq!(
+        serialized["project"],
+        "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"
+    );
+}
+
+#[test]
+fn invalid_ids_are_rejected() {
+    assert!(job_status(|value| value["project"] = "web-frontend".into()).is_err());
+    assert!(job_status(|value| value["job_id"] = "".into()).is_err());
+}
+
+#[test]
+fn nil_ids_fail_validation() {
+    let response = job_status(|value| {
+        value["user_id"] = Uuid::nil().to_string().into();
+    })
+    .unwrap();
+    assert!(response.user_id.is_nil());
+
+    let errors = response.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "user_id");
+}
+
+#[test]
+fn job_descriptor_project_ids() {
+    let mut value: Value =
+        serde_json::from_str(include_str!("fixtures/all_jobs_status_response.json")).unwrap();
+    value["jobs"][0]["project"] = "web-frontend".into();
+    assert!(serde_json::from_value::<AllJobsStatusResponse>(value.clone()).is_err());
+
+    value["jobs"][0]["project"] = Uuid::nil().to_string().into();
+    let response: AllJobsStatusResponse = serde_json::from_value(value).unwrap();
+    let errors = response.jobs[0].validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "project");
+}
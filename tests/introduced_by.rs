@@ -0,0 +1,160 @@
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::{IssueStatus, PackageRef, PackageStatusExtended, PackageType};
+use serde_json::{json, Value};
+
+mod common;
+
+fn job_status() -> JobStatusResponse<PackageStatusExtended> {
+    serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap()
+}
+
+fn npm(name: &str, version: &str) -> PackageRef {
+    PackageRef::new(name, version, PackageType::Npm)
+}
+
+fn package(name: &str, version: &str, package_type: &str, dependencies: Value) -> Value {
+    let mut package = common::extended_package(name, package_type);
+    package["version"] = json!(version);
+    package["dependencies"] = dependencies;
+    package
+}
+
+#[test]
+fn paths_from_top_level() {
+    let job = job_status();
+    assert_eq!(
+        job.introduced_by(&npm("qs", "6.10.3")),
+        Some(vec![npm("express", "4.18.1"), npm("qs", "6.10.3")])
+    );
+    assert_eq!(
+        job.introduced_by(&npm("express", "4.18.1")),
+        Some(vec![npm("express", "4.18.1")])
+    );
+
+    // Dependencies without a status of their own, other versions and other
+    // ecosystems
+    assert_eq!(job.introduced_by(&npm("side-channel", "1.0.4")), None);
+    assert_eq!(job.introduced_by(&npm("qs", "6.11.0")), None);
+    let pypi = PackageRef::new("qs", "6.10.3", PackageType::PyPi);
+    assert_eq!(job.introduced_by(&pypi), None);
+}
+
+#[test]
+fn requirements_resolve_to_the_only_version() {
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_value(common::job(vec![
+        package("express", "4.18.1", "npm", json!({ "accepts": "~1.3.8" })),
+        package(
+            "accepts",
+            "1.3.8",
+            "npm",
+            json!({ "mime-types": "^2.1.34" }),
+        ),
+        package("mime-types", "2.1.35", "npm", json!({})),
+    ]))
+    .unwrap();
+    assert_eq!(
+        job.introduced_by(&npm("mime-types", "2.1.35")),
+        Some(vec![
+            npm("express", "4.18.1"),
+            npm("accepts", "1.3.8"),
+            npm("mime-types", "2.1.35"),
+        ])
+    );
+}
+
+#[test]
+fn ambiguous_requirements_are_not_resolved() {
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_value(common::job(vec![
+        package("app", "1.0.0", "npm", json!({ "debug": "^2.6.0" })),
+        package("debug", "2.6.9", "npm", json!({})),
+        package("debug", "4.3.4", "npm", json!({})),
+    ]))
+    .unwrap();
+
+    // Neither version is known to be a dependency of `app`, so both are top
+    // level packages
+    assert_eq!(
+        job.introduced_by(&npm("debug", "2.6.9")),
+        Some(vec![npm("debug", "2.6.9")])
+    );
+    assert_eq!(
+        job.introduced_by(&npm("debug", "4.3.4")),
+        Some(vec![npm("debug", "4.3.4")])
+    );
+}
+
+#[test]
+fn dependencies_stay_within_their_ecosystem() {
+    let job: JobStatusResponse<PackageStatusExtended> = serde_json::from_value(common::job(vec![
+        package("requests", "2.28.1", "pypi", json!({ "idna": "3.4" })),
+        package("idna", "3.4", "pypi", json!({})),
+        package("idna", "3.4", "npm", json!({})),
+    ]))
+    .unwrap();
+    let idna = PackageRef::new("idna", "3.4", PackageType::PyPi);
+    assert_eq!(
+        job.introduced_by(&idna),
+        Some(vec![
+            PackageRef::new("requests", "2.28.1", PackageType::PyPi),
+            idna,
+        ])
+    );
+    assert_eq!(
+        job.introduced_by(&npm("idna", "3.4")),
+        Some(vec![npm("idna", "3.4")])
+    );
+}
+
+#[test]
+fn fill_issues() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap();
+    let issue = value["packages"][0]["issues"][0].clone();
+    value["packages"][1]["issues"] = json!([issue]);
+    let mut job: JobStatusResponse<PackageStatusExtended> = serde_json::from_value(value).unwrap();
+    assert!(job.packages[1].issues[0].issue.introduced_by.is_empty());
+    assert_eq!(job.packages[1].issues[0].issue.introduced_via(), None);
+
+    job.fill_introduced_by();
+    let issue = &job.packages[1].issues[0].issue;
+    assert_eq!(issue.introduced_by.len(), 2);
+    assert_eq!(issue.introduced_via().as_deref(), Some("express → qs"));
+    assert_eq!(
+        job.packages[0].issues[0].issue.introduced_via().as_deref(),
+        Some("express")
+    );
+
+    let value = serde_json::to_value(&job).unwrap();
+    assert_eq!(
+        value["packages"][1]["issues"][0]["introduced_by"][0],
+        json!({ "type": "npm", "name": "express", "version": "4.18.1" })
+    );
+    let roundtrip: JobStatusResponse<PackageStatusExtended> =
+        serde_json::from_value(value).unwrap();
+    assert_eq!(roundtrip, job);
+}
+
+#[test]
+fn issue_status() {
+    let mut job = job_status();
+    job.fill_introduced_by();
+    let status = &job.packages[0].issues[0];
+    assert_eq!(status.issue.introduced_by, [npm("express", "4.18.1")]);
+
+    let value = serde_json::to_value(status).unwrap();
+    assert_eq!(
+        value["introduced_by"],
+        json!([{ "type": "npm", "name": "express", "version": "4.18.1" }])
+    );
+    let roundtrip: IssueStatus = serde_json::from_value(value).unwrap();
+    assert_eq!(&roundtrip, status);
+}
+
+#[test]
+fn existing_paths_are_kept() {
+    let mut job = job_status();
+    let path = vec![npm("other", "1.0.0"), npm("express", "4.18.1")];
+    job.packages[0].issues[0].issue.introduced_by = path.clone();
+    job.fill_introduced_by();
+    assert_eq!(job.packages[0].issues[0].issue.introduced_by, path);
+}
@@ -0,0 +1,65 @@
+use phylum_types::types::package::{Issue, PackageType, Reference, ReferenceKind, SectionKind};
+use serde_json::json;
+
+fn issue() -> serde_json::Value {
+    json!({
+        "tag": "HV00001",
+        "id": "CVE-2021-23337",
+        "title": "Command injection in lodash",
+        "description": "## Summary\n\n`template` allows command injection.",
+        "severity": "high",
+        "domain": "vulnerability",
+    })
+}
+
+#[test]
+fn structured_body() {
+    let mut value = issue();
+    value["body"] = json!({
+        "sections": [
+            { "kind": "summary", "markdown": "`template` allows command injection." },
+            { "kind": "remediation", "heading": "Fix", "markdown": "Upgrade to 4.17.21.\n" },
+            { "kind": "timeline", "markdown": "Disclosed in 2021." },
+        ],
+        "references": [
+            { "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm", "title": "GHSA" },
+        ],
+        "affected": [{
+            "name": "lodash",
+            "package_type": "npm",
+            "affected_ranges": ["<4.17.21"],
+            "fixed_versions": ["4.17.21"],
+        }],
+    });
+
+    let issue: Issue = serde_json::from_value(value).unwrap();
+    let body = issue.body.as_deref().unwrap();
+    assert_eq!(body.sections[2].kind, SectionKind::Other);
+    assert_eq!(
+        body.section(SectionKind::Remediation)
+            .unwrap()
+            .heading
+            .as_deref(),
+        Some("Fix")
+    );
+    assert_eq!(
+        body.references,
+        [Reference::new(
+            "https://github.com/advisories/GHSA-35jh-r3h4-6jhm",
+            ReferenceKind::Other
+        )
+        .with_title("GHSA")]
+    );
+    assert_eq!(body.affected[0].package_type, Some(PackageType::Npm));
+    assert_eq!(
+        body.to_markdown(),
+        "`template` allows command injection.\n\n## Fix\n\nUpgrade to 4.17.21.\n\nDisclosed in 2021."
+    );
+}
+
+#[test]
+fn body_is_optional() {
+    let issue: Issue = serde_json::from_value(issue()).unwrap();
+    assert!(issue.body.is_none());
+    assert!(serde_json::to_value(&issue).unwrap().get("body").is_none());
+}
@@ -0,0 +1,72 @@
+use phylum_types::types::package::{IssueTag, Package};
+use serde_json::json;
+
+#[test]
+fn families() {
+    for tag in ["HM0012", "HL0004", "HA9999"] {
+        assert!(IssueTag::parse(tag).is_heuristic(), "{}", tag);
+    }
+    assert_eq!(IssueTag::parse("HM0012").heuristic_family(), Some('M'));
+
+    for tag in [
+        "CVE-2022-24999",
+        "CVE-2021-1234567",
+        "GHSA-29mw-wpgm-hmr9",
+        "RUSTSEC-2021-0078",
+        "PYSEC-2021-59",
+        "GO-2022-0603",
+    ] {
+        assert!(IssueTag::parse(tag).is_vulnerability(), "{}", tag);
+    }
+
+    let license = IssueTag::parse("license:AGPL-3.0-only");
+    assert!(license.is_license());
+    assert_eq!(license.license(), Some("AGPL-3.0-only"));
+    assert_eq!(license.heuristic_family(), None);
+}
+
+#[test]
+fn unknown_fallback() {
+    for tag in [
+        "",
+        "HM12",
+        "hm0012",
+        "HM00123",
+        "CVE-22-1",
+        "CVE-2022-12",
+        "GHSA-29mw-wpgm",
+        "RUSTSEC-",
+        "license:",
+        "typosquat",
+    ] {
+        assert_eq!(
+            IssueTag::parse(tag),
+            IssueTag::Unknown(tag.into()),
+            "{}",
+            tag
+        );
+    }
+}
+
+#[test]
+fn serialized_as_plain_strings() {
+    let tags = json!(["HM0012", "CVE-2022-24999", "license:MIT", "custom-rule"]);
+    let parsed: Vec<IssueTag> = serde_json::from_value(tags.clone()).unwrap();
+    assert!(parsed[0].is_heuristic());
+    assert_eq!(parsed[3], IssueTag::Unknown("custom-rule".into()));
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), tags);
+
+    for tag in &parsed {
+        assert_eq!(tag.to_string().parse::<IssueTag>().unwrap(), *tag);
+    }
+}
+
+#[test]
+fn issue_tags() {
+    let package: Package = serde_json::from_str(include_str!("fixtures/package.json")).unwrap();
+    let issue = &package.issues_details[0];
+    assert_eq!(
+        issue.parsed_tag(),
+        Some(IssueTag::Heuristic("HL0004".into()))
+    );
+}
@@ -0,0 +1,74 @@
+use phylum_types::types::job::{JobByLockfileView, JobStatusResponse};
+use phylum_types::types::lockfile::{LockfileFormat, LockfilePath, LockfileRef};
+use phylum_types::types::package::PackageStatusExtended;
+
+fn job() -> JobStatusResponse<PackageStatusExtended> {
+    serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap()
+}
+
+fn lockfile(path: &str, format: Option<LockfileFormat>) -> LockfileRef {
+    LockfileRef {
+        path: path.into(),
+        format,
+    }
+}
+
+#[test]
+fn partitions_packages_by_lockfile() {
+    let mut job = job();
+    let web = lockfile("web/package-lock.json", Some(LockfileFormat::PackageLock));
+    let api = lockfile("api/package-lock.json", None);
+    job.packages[0].basic_status.lockfiles = vec![web.clone(), api.clone()];
+    job.packages[1].basic_status.lockfiles = vec![api.clone(), api.clone()];
+
+    let view = JobByLockfileView::new(&job);
+    let paths: Vec<_> = view
+        .lockfiles
+        .iter()
+        .map(|group| {
+            group
+                .lockfile
+                .as_ref()
+                .map(|lockfile| lockfile.path.as_str())
+        })
+        .collect();
+    assert_eq!(
+        paths,
+        [Some("api/package-lock.json"), Some("web/package-lock.json")]
+    );
+
+    let api_group = view
+        .lockfile(&LockfilePath::new("api/package-lock.json"))
+        .unwrap();
+    assert_eq!(api_group.packages, job.packages[..2]);
+    let web_group = view.lockfile(&web.path).unwrap();
+    assert_eq!(web_group.packages, job.packages[..1]);
+    assert_eq!(web_group.issues.len(), job.packages[0].issues.len());
+
+    assert!(view.unattributed().is_none());
+}
+
+#[test]
+fn fills_in_missing_formats() {
+    let mut job = job();
+    job.packages[0].basic_status.lockfiles = vec![lockfile("yarn.lock", None)];
+    job.packages[1].basic_status.lockfiles =
+        vec![lockfile("./yarn.lock", Some(LockfileFormat::Yarn))];
+
+    let view = JobByLockfileView::new(&job);
+    let group = view.lockfile(&LockfilePath::new("yarn.lock")).unwrap();
+    assert_eq!(
+        group.lockfile.as_ref().unwrap().format,
+        Some(LockfileFormat::Yarn)
+    );
+    assert_eq!(group.packages.len(), 2);
+}
+
+#[test]
+fn without_lockfiles() {
+    let job = job();
+    let view = JobByLockfileView::from(&job);
+    assert_eq!(view.lockfiles.len(), 1);
+    assert_eq!(view.unattributed().unwrap().packages, job.packages);
+    assert_eq!(view.project_name, job.project_name);
+}
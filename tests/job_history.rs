@@ -0,0 +1,62 @@
+use phylum_types::types::common::{Status, StatusTransition};
+use phylum_types::types::job::JobStateHistoryResponse;
+use serde_json::{json, Value};
+
+fn history(entries: Value) -> JobStateHistoryResponse {
+    serde_json::from_value(json!({
+        "job_id": "59482a54-423b-448d-8325-f171c9dc336b",
+        "history": entries,
+    }))
+    .unwrap()
+}
+
+fn entry(status: &str, actor: Option<&str>) -> Value {
+    json!({ "status": status, "timestamp": "2022-09-01T12:00:00Z", "actor": actor })
+}
+
+#[test]
+fn repeated_statuses_are_skipped() {
+    let history = history(json!([
+        entry("incomplete", None),
+        entry("incomplete", Some("alice@example.com")),
+        entry("incomplete", Some("bob@example.com")),
+        entry("complete", None),
+        entry("complete", Some("alice@example.com")),
+    ]));
+    let transitions = history.transitions().unwrap();
+    assert_eq!(
+        transitions,
+        [StatusTransition::new(Status::Incomplete, Status::Complete).unwrap()]
+    );
+}
+
+#[test]
+fn backwards_transitions_are_rejected() {
+    let history = history(json!([
+        entry("incomplete", None),
+        entry("complete", None),
+        entry("incomplete", None),
+    ]));
+    assert_eq!(history.transitions(), None);
+}
+
+#[test]
+fn transitions_are_checked_when_deserialized() {
+    let transition: StatusTransition =
+        serde_json::from_value(json!({ "from": "incomplete", "to": "complete" })).unwrap();
+    assert_eq!(transition.from(), Status::Incomplete);
+    assert_eq!(transition.to(), Status::Complete);
+    assert_eq!(
+        serde_json::to_value(transition).unwrap(),
+        json!({ "from": "incomplete", "to": "complete" })
+    );
+
+    let error = serde_json::from_value::<StatusTransition>(
+        json!({ "from": "complete", "to": "incomplete" }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "status cannot change from Complete to Incomplete"
+    );
+}
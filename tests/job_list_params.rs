@@ -0,0 +1,63 @@
+use phylum_types::types::job::{JobListParams, JobSortKey, SortOrder};
+use phylum_types::types::label::Label;
+use phylum_types::types::package::PackageType;
+use phylum_types::types::timestamp;
+use uuid::Uuid;
+
+#[test]
+fn empty_query() {
+    let query = serde_urlencoded::to_string(JobListParams::default()).unwrap();
+    assert_eq!(query, "");
+    let params: JobListParams = serde_urlencoded::from_str("").unwrap();
+    assert_eq!(params, JobListParams::default());
+}
+
+#[test]
+fn query_round_trip() {
+    let params = JobListParams {
+        project: Some(Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap()),
+        label: Label::new("feature/new parser").ok(),
+        created_after: timestamp::from_epoch_seconds(1_654_084_800),
+        created_before: None,
+        pass: Some(false),
+        ecosystems: vec![PackageType::Npm, PackageType::PyPi],
+        limit: Some(50),
+        offset: None,
+        sort: Some(JobSortKey::LastUpdated),
+        order: Some(SortOrder::Asc),
+    };
+
+    let query = serde_urlencoded::to_string(&params).unwrap();
+    assert_eq!(
+        query,
+        "project=0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8&label=feature%2Fnew+parser\
+         &created_after=2022-06-01T12%3A00%3A00Z&pass=false&ecosystems=npm%2Cpypi\
+         &limit=50&sort=last_updated&order=asc"
+    );
+    assert_eq!(
+        serde_urlencoded::from_str::<JobListParams>(&query).unwrap(),
+        params
+    );
+}
+
+#[test]
+fn lenient_ecosystems() {
+    let params: JobListParams =
+        serde_urlencoded::from_str("ecosystems=NPM,%20python,&pass=true").unwrap();
+    assert_eq!(params.ecosystems, [PackageType::Npm, PackageType::PyPi]);
+    assert_eq!(params.pass, Some(true));
+
+    assert!(serde_urlencoded::from_str::<JobListParams>("ecosystems=npm,cobol").is_err());
+}
+
+#[test]
+fn next_page() {
+    let params = JobListParams {
+        limit: Some(25),
+        ..Default::default()
+    };
+    let next = params.next_page(25);
+    assert_eq!(next.offset, Some(25));
+    assert_eq!(next.next_page(25).offset, Some(50));
+    assert_eq!(next.limit, Some(25));
+}
@@ -0,0 +1,137 @@
+use phylum_types::types::job::JobStatusResponseVariant;
+use phylum_types::types::lockfile::LockfileFormat;
+use phylum_types::types::package::PackageType;
+use serde_json::{json, Value};
+
+mod common;
+
+use common::job;
+
+fn basic_package() -> Value {
+    common::basic_package("left-pad")
+}
+
+fn extended_package() -> Value {
+    let mut package = common::extended_package("left-pad", "npm");
+    package["riskVectors"] = json!({ "author": 1.0 });
+    package
+}
+
+fn parse(value: Value) -> Result<JobStatusResponseVariant, serde_json::Error> {
+    serde_json::from_value(value)
+}
+
+#[test]
+fn basic() {
+    let variant = parse(job(vec![basic_package()])).unwrap();
+    assert!(matches!(variant, JobStatusResponseVariant::Basic(_)));
+}
+
+#[test]
+fn extended() {
+    let variant = parse(job(vec![extended_package()])).unwrap();
+    let response = variant.as_extended().unwrap();
+    assert_eq!(response.packages[0].package_type, PackageType::Npm);
+    assert_eq!(response.packages[0].risk_vectors["author"], 1.0);
+}
+
+#[test]
+fn extended_with_null_fields() {
+    let mut package = extended_package();
+    package["riskVectors"] = Value::Null;
+    package["dependencies"] = Value::Null;
+    package["issues"] = Value::Null;
+
+    let variant = parse(job(vec![package])).unwrap();
+    let response = variant.as_extended().unwrap();
+    assert!(response.packages[0].risk_vectors.is_empty());
+    assert!(response.packages[0].issues.is_empty());
+}
+
+#[test]
+fn extended_with_only_risk_vectors() {
+    let mut package = extended_package();
+    package.as_object_mut().unwrap().remove("type");
+
+    let error = parse(job(vec![package])).unwrap_err();
+    assert!(error.to_string().contains("has no type"), "{}", error);
+}
+
+#[test]
+fn extended_with_null_type() {
+    let mut package = extended_package();
+    package["type"] = Value::Null;
+
+    assert!(parse(job(vec![package])).is_err());
+}
+
+#[test]
+fn basic_with_null_type() {
+    let mut package = basic_package();
+    package["type"] = Value::Null;
+
+    let variant = parse(job(vec![package])).unwrap();
+    assert!(matches!(variant, JobStatusResponseVariant::Basic(_)));
+}
+
+#[test]
+fn mixed() {
+    let error = parse(job(vec![extended_package(), basic_package()])).unwrap_err();
+    assert!(error.to_string().contains("mixes"), "{}", error);
+}
+
+#[test]
+fn empty() {
+    let variant = parse(job(Vec::new())).unwrap();
+    assert!(matches!(variant, JobStatusResponseVariant::Extended(_)));
+}
+
+#[test]
+fn fixtures() {
+    let basic = include_str!("fixtures/job_status_basic.json");
+    let variant: JobStatusResponseVariant = serde_json::from_str(basic).unwrap();
+    assert!(matches!(variant, JobStatusResponseVariant::Basic(_)));
+
+    let extended = include_str!("fixtures/job_status_extended.json");
+    let variant: JobStatusResponseVariant = serde_json::from_str(extended).unwrap();
+    assert!(matches!(variant, JobStatusResponseVariant::Extended(_)));
+}
+
+#[test]
+fn into_basic() {
+    let variant = parse(job(vec![extended_package()])).unwrap();
+    let response = variant.into_basic();
+    assert_eq!(response.packages[0].name, "left-pad");
+}
+
+#[test]
+fn package_lockfiles() {
+    let mut package = basic_package();
+    package["lockfiles"] = json!([
+        { "path": "web\\package-lock.json", "format": "npm" },
+        { "path": "./admin/package-lock.json" },
+    ]);
+
+    let response: JobStatusResponseVariant = serde_json::from_value(job(vec![package])).unwrap();
+    let JobStatusResponseVariant::Basic(response) = response else {
+        panic!("expected basic package statuses");
+    };
+    let lockfiles = &response.packages[0].lockfiles;
+    assert_eq!(lockfiles.len(), 2);
+    assert_eq!(lockfiles[0].path.as_str(), "web/package-lock.json");
+    assert_eq!(lockfiles[0].format, Some(LockfileFormat::PackageLock));
+    assert_eq!(lockfiles[1].path.as_str(), "admin/package-lock.json");
+    assert_eq!(lockfiles[1].format, None);
+}
+
+#[test]
+fn package_lockfiles_default_to_empty() {
+    let response: JobStatusResponseVariant =
+        serde_json::from_value(job(vec![basic_package()])).unwrap();
+    let JobStatusResponseVariant::Basic(response) = response else {
+        panic!("expected basic package statuses");
+    };
+    assert!(response.packages[0].lockfiles.is_empty());
+    let serialized = serde_json::to_value(&response).unwrap();
+    assert!(serialized["packages"][0].get("lockfiles").is_none());
+}
@@ -0,0 +1,91 @@
+use std::convert::TryFrom;
+
+use phylum_types::types::job::{JobDescriptor, JobStatusResponse};
+use phylum_types::types::label::{Label, LabelError};
+use phylum_types::types::package::PackageStatus;
+
+#[test]
+fn normalizes_whitespace() {
+    assert_eq!(Label::try_from("main").unwrap(), "main");
+    assert_eq!(Label::try_from(" release\t 1.0\n").unwrap(), "release 1.0");
+    assert_eq!(Label::try_from("a  b").unwrap().into_string(), "a b");
+    assert_eq!(Label::try_from("feature\nfix").unwrap(), "feature fix");
+}
+
+#[test]
+fn rejects_invalid_labels() {
+    assert_eq!(Label::try_from(""), Err(LabelError::Empty));
+    assert_eq!(Label::try_from(" \n "), Err(LabelError::Empty));
+    assert_eq!(
+        Label::try_from("a\u{0}b"),
+        Err(LabelError::InvalidCharacter('\u{0}'))
+    );
+    assert_eq!(
+        Label::try_from("a\u{1b}b"),
+        Err(LabelError::InvalidCharacter('\u{1b}'))
+    );
+    assert_eq!(
+        Label::try_from("x".repeat(256)),
+        Err(LabelError::TooLong(256))
+    );
+    assert!(Label::try_from("é".repeat(255)).is_ok());
+}
+
+#[test]
+fn serializes_as_string() {
+    let label: Label = serde_json::from_str(r#"" feature/x ""#).unwrap();
+    assert_eq!(serde_json::to_string(&label).unwrap(), r#""feature/x""#);
+
+    let error = serde_json::from_str::<Label>(r#""""#).unwrap_err();
+    assert!(error.to_string().contains("label is empty"));
+}
+
+#[test]
+fn job_status_label() {
+    let json = include_str!("fixtures/job_status_basic.json");
+    let response: JobStatusResponse<PackageStatus> = serde_json::from_str(json).unwrap();
+    assert_eq!(response.label, None);
+
+    let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+    value["label"] = "  main ".into();
+    let response: JobStatusResponse<PackageStatus> = serde_json::from_value(value).unwrap();
+    assert_eq!(response.label.unwrap(), "main");
+}
+
+#[test]
+fn response_labels_are_lenient() {
+    assert_eq!(Label::lenient("  main "), Label::try_from("main").ok());
+    assert_eq!(Label::lenient(" "), None);
+    let long = "x".repeat(300);
+    assert_eq!(Label::lenient(long.as_str()).unwrap(), long.as_str());
+
+    let json = include_str!("fixtures/job_status_basic.json");
+    let mut value: serde_json::Value = serde_json::from_str(json).unwrap();
+    value["label"] = "".into();
+    let response: JobStatusResponse<PackageStatus> = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(response.label, None);
+
+    value["label"] = long.as_str().into();
+    let response: JobStatusResponse<PackageStatus> = serde_json::from_value(value).unwrap();
+    assert_eq!(response.label.unwrap(), long.as_str());
+}
+
+#[test]
+fn job_descriptor_without_label() {
+    let job: JobDescriptor = serde_json::from_value(serde_json::json!({
+        "job_id": "59482a54-423b-448d-8325-f171c9dc336b",
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "label": "",
+        "num_dependencies": 0,
+        "packages": [],
+        "pass": true,
+        "msg": "Project met threshold requirements",
+        "date": "2022-06-01T12:00:00Z",
+    }))
+    .unwrap();
+    assert_eq!(job.label, None);
+    assert_eq!(
+        job.to_string(),
+        "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8 (job 59482a54-423b-448d-8325-f171c9dc336b)"
+    );
+}
@@ -0,0 +1,106 @@
+use phylum_types::types::job::AllJobsStatusResponse;
+use phylum_types::types::package::Package;
+use serde::Deserialize;
+use serde_json::json;
+
+fn package(json: &str) -> Package {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn download_count_beyond_u32() {
+    let package = package(include_str!(
+        "fixtures/drift/package_large_download_count.json"
+    ));
+    assert_eq!(package.download_count, 5_368_709_120);
+
+    let responsiveness = package.developer_responsiveness.unwrap();
+    assert_eq!(responsiveness.total_issue_count, Some(4120));
+    assert_eq!(responsiveness.open_pull_request_avg_duration, Some(172_800));
+}
+
+#[test]
+fn float_counts() {
+    let package = package(include_str!("fixtures/drift/package_float_counts.json"));
+    assert_eq!(package.download_count, 1_200_000_000);
+
+    let responsiveness = package.developer_responsiveness.unwrap();
+    assert_eq!(responsiveness.open_issue_count, Some(212));
+    assert_eq!(responsiveness.open_issue_avg_duration, Some(4822));
+    assert_eq!(responsiveness.open_pull_request_avg_duration, None);
+}
+
+#[test]
+fn string_counts() {
+    let package = package(include_str!("fixtures/drift/package_string_counts.json"));
+    assert_eq!(package.download_count, 48_213_377);
+
+    let responsiveness = package.developer_responsiveness.unwrap();
+    assert_eq!(responsiveness.open_issue_count, Some(12));
+    assert_eq!(responsiveness.total_issue_count, Some(860));
+    assert_eq!(responsiveness.open_issue_avg_duration, None);
+
+    // Serialized back as plain integers
+    let value = serde_json::to_value(&package).unwrap();
+    assert_eq!(value["downloadCount"], 48_213_377);
+    assert_eq!(value["developerResponsiveness"]["open_issue_count"], 12);
+}
+
+#[test]
+fn rejects_invalid_counts() {
+    #[derive(Deserialize, Debug)]
+    struct Counter {
+        #[serde(deserialize_with = "phylum_types::types::lenient::u64")]
+        #[allow(dead_code)]
+        count: u64,
+    }
+
+    for count in [
+        json!(-1),
+        json!(-0.9),
+        json!("many"),
+        json!(1e20),
+        json!(true),
+    ] {
+        let result = serde_json::from_value::<Counter>(json!({ "count": count }));
+        assert!(result.is_err(), "{}", count);
+    }
+}
+
+#[test]
+fn unknown_scores_and_completeness_are_omitted() {
+    let value = serde_json::to_value(Package::default()).unwrap();
+    assert!(value.get("riskScores").is_none(), "{}", value);
+    assert!(value.get("complete").is_none(), "{}", value);
+
+    let package: Package = serde_json::from_value(value).unwrap();
+    assert_eq!(package.risk_scores, None);
+    assert_eq!(package.complete, None);
+}
+
+#[test]
+fn job_dates() {
+    let mut value: serde_json::Value =
+        serde_json::from_str(include_str!("fixtures/all_jobs_status_response.json")).unwrap();
+    for date in [
+        json!("2022-04-15T05:20:00Z"),
+        json!("2022-04-15T07:20:00+02:00"),
+        json!("2022-04-15 05:20:00"),
+        json!("2022-04-15 05:20:00 UTC"),
+        json!("2022-04-15 05:20:00.000+00:00"),
+        json!("Fri, 15 Apr 2022 05:20:00 GMT"),
+        json!(1_650_000_000),
+    ] {
+        value["jobs"][0]["date"] = date.clone();
+        let jobs: AllJobsStatusResponse = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(
+            serde_json::to_value(jobs.jobs[0].date).unwrap(),
+            "2022-04-15T05:20:00Z",
+            "{}",
+            date
+        );
+    }
+
+    value["jobs"][0]["date"] = json!("last Tuesday");
+    assert!(serde_json::from_value::<AllJobsStatusResponse>(value).is_err());
+}
@@ -0,0 +1,63 @@
+#![cfg(feature = "spdx")]
+
+use phylum_types::types::license::{LicenseCategory, LicenseExpression};
+use phylum_types::types::package::Package;
+
+fn category(expression: &str) -> LicenseCategory {
+    LicenseExpression::parse(expression).unwrap().category()
+}
+
+#[test]
+fn categories() {
+    assert_eq!(category("MIT"), LicenseCategory::Permissive);
+    assert_eq!(
+        category("Apache-2.0 WITH LLVM-exception"),
+        LicenseCategory::Permissive
+    );
+    assert_eq!(category("GPL-2.0-or-later"), LicenseCategory::Copyleft);
+    assert_eq!(category("MIT OR GPL-3.0-only"), LicenseCategory::Permissive);
+    assert_eq!(category("MIT AND GPL-3.0-only"), LicenseCategory::Copyleft);
+    assert_eq!(category("LicenseRef-Proprietary"), LicenseCategory::Unknown);
+    assert_eq!(
+        category("LicenseRef-Proprietary OR MPL-2.0"),
+        LicenseCategory::Copyleft
+    );
+}
+
+#[test]
+fn lenient_parsing() {
+    let license = LicenseExpression::parse("MIT/Apache-2.0").unwrap();
+    assert_eq!(license.licenses(), ["MIT", "Apache-2.0"]);
+    assert_eq!(license.to_string(), "MIT/Apache-2.0");
+    assert!(LicenseExpression::parse("MIT OR").is_err());
+}
+
+#[test]
+fn package_license() {
+    let mut package = Package {
+        license: Some("BSD-3-Clause".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        package.parsed_license().unwrap().unwrap().licenses(),
+        ["BSD-3-Clause"]
+    );
+    assert_eq!(package.license_category(), LicenseCategory::Permissive);
+
+    package.license = Some("Not a license".into());
+    assert!(package.parsed_license().unwrap().is_err());
+    assert_eq!(package.license_category(), LicenseCategory::Unknown);
+
+    package.license = None;
+    assert!(package.parsed_license().is_none());
+}
+
+#[test]
+fn serde() {
+    let license: LicenseExpression = serde_json::from_str(r#""MIT OR Apache-2.0""#).unwrap();
+    assert_eq!(
+        serde_json::to_string(&license).unwrap(),
+        r#""MIT OR Apache-2.0""#
+    );
+    assert!(serde_json::from_str::<LicenseExpression>(r#""(""#).is_err());
+}
@@ -0,0 +1,60 @@
+use phylum_types::types::lockfile::LockfilePath;
+use phylum_types::types::package::PackageRefAndLockfile;
+
+fn normalized(path: &str) -> String {
+    LockfilePath::new(path).as_str().to_owned()
+}
+
+#[test]
+fn normalization() {
+    assert_eq!(normalized("package-lock.json"), "package-lock.json");
+    assert_eq!(
+        normalized("./web//package-lock.json"),
+        "web/package-lock.json"
+    );
+    assert_eq!(normalized(r"web\api\Cargo.lock"), "web/api/Cargo.lock");
+    assert_eq!(normalized("web/api/../Cargo.lock"), "web/Cargo.lock");
+    assert_eq!(normalized("../shared/yarn.lock"), "../shared/yarn.lock");
+    assert_eq!(normalized("/../srv/poetry.lock"), "/srv/poetry.lock");
+    assert_eq!(
+        normalized(r"C:\builds\.\app\go.sum"),
+        "C:/builds/app/go.sum"
+    );
+    assert_eq!(normalized("web/"), "web");
+    assert_eq!(normalized("/"), "/");
+}
+
+#[test]
+fn relative_resolution() {
+    let root = LockfilePath::new(r"C:\builds\shop");
+    let path = LockfilePath::new(r"C:\builds\shop\web\package-lock.json");
+    assert!(path.is_absolute());
+    assert_eq!(path.file_name(), "package-lock.json");
+    assert_eq!(
+        path.relative_to(&root).unwrap().as_str(),
+        "web/package-lock.json"
+    );
+
+    let elsewhere = LockfilePath::new("C:/builds/shopfront/package-lock.json");
+    assert_eq!(elsewhere.relative_to(&root), None);
+
+    let relative = LockfilePath::new("web/package-lock.json");
+    assert_eq!(relative.relative_to(&root), Some(relative.clone()));
+}
+
+#[test]
+fn deserialized_paths_compare_equal() {
+    let windows: PackageRefAndLockfile = serde_json::from_str(
+        r#"{"name":"lodash","version":"4.17.21","type":"npm","lockfile":"web\\package-lock.json"}"#,
+    )
+    .unwrap();
+    let unix: PackageRefAndLockfile = serde_json::from_str(
+        r#"{"name":"lodash","version":"4.17.21","type":"npm","lockfile":"./web/package-lock.json"}"#,
+    )
+    .unwrap();
+    assert_eq!(windows, unix);
+    assert_eq!(
+        serde_json::to_value(&windows).unwrap()["lockfile"],
+        "web/package-lock.json"
+    );
+}
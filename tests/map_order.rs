@@ -0,0 +1,28 @@
+use phylum_types::types::package::PackageStatusExtended;
+
+const PACKAGE: &str = r#"{"name":"express","version":"4.18.1","status":"complete","last_updated":1650000000,"license":"MIT","package_score":0.9,"num_dependencies":3,"num_vulnerabilities":0,"type":"npm","riskVectors":{"vulnerability":1.0,"author":0.9,"malicious_code":1.0,"license":1.0,"engineering":0.8},"dependencies":{"qs":"6.10.3","body-parser":"1.20.0","accepts":"~1.3.8"},"issues":[]}"#;
+
+#[test]
+fn maps_keep_their_order() {
+    let package: PackageStatusExtended = serde_json::from_str(PACKAGE).unwrap();
+
+    let domains: Vec<_> = package.risk_vectors.keys().map(String::as_str).collect();
+    assert_eq!(
+        domains,
+        [
+            "vulnerability",
+            "author",
+            "malicious_code",
+            "license",
+            "engineering"
+        ]
+    );
+    let dependencies: Vec<_> = package.dependencies.keys().map(String::as_str).collect();
+    assert_eq!(dependencies, ["qs", "body-parser", "accepts"]);
+
+    // Serializing twice, or after a round trip, gives the same bytes
+    let json = serde_json::to_string(&package).unwrap();
+    assert_eq!(json, PACKAGE);
+    let again: PackageStatusExtended = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&again).unwrap(), json);
+}
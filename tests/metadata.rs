@@ -0,0 +1,111 @@
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::metadata::{Metadata, MetadataError};
+use phylum_types::types::package::PackageStatus;
+use phylum_types::types::preferences::IgnoredIssue;
+use phylum_types::types::project::CreateProjectRequest;
+use phylum_types::validation::Validate;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(PartialEq, Debug, Serialize, Deserialize)]
+struct Build {
+    number: u32,
+    branch: String,
+}
+
+#[test]
+fn typed_accessors() {
+    let mut metadata = Metadata::default();
+    let build = Build {
+        number: 42,
+        branch: "main".into(),
+    };
+    assert_eq!(metadata.insert("ci:build", &build).unwrap(), None);
+    assert_eq!(metadata.get::<Build>("ci:build").unwrap(), Some(build));
+    assert_eq!(metadata.get::<Build>("missing").unwrap(), None);
+    assert!(matches!(
+        metadata.get::<String>("ci:build"),
+        Err(MetadataError::Json(_))
+    ));
+
+    let previous = metadata.insert("ci:build", "replaced").unwrap();
+    assert_eq!(previous.unwrap()["number"], 42);
+    assert_eq!(metadata.get_str("ci:build"), Some("replaced"));
+    assert_eq!(metadata.len(), 1);
+}
+
+#[test]
+fn limits() {
+    let mut metadata = Metadata::default();
+    for key in ["", "has space", "ünïcode"] {
+        assert!(matches!(
+            metadata.insert(key, &1),
+            Err(MetadataError::InvalidKey(_))
+        ));
+    }
+    assert!(metadata
+        .insert("k".repeat(Metadata::MAX_KEY_LEN + 1), &1)
+        .is_err());
+
+    for i in 0..Metadata::MAX_KEYS {
+        metadata.insert(format!("key-{i}"), &i).unwrap();
+    }
+    assert!(matches!(
+        metadata.insert("one-too-many", &0),
+        Err(MetadataError::TooManyKeys(_))
+    ));
+    assert!(!metadata.contains_key("one-too-many"));
+
+    // Rejected updates leave the previous value in place
+    let large = "x".repeat(Metadata::MAX_SIZE);
+    assert!(matches!(
+        metadata.insert("key-0", &large),
+        Err(MetadataError::TooLarge(_))
+    ));
+    assert_eq!(metadata.get::<usize>("key-0").unwrap(), Some(0));
+}
+
+#[test]
+fn limits_checked_only_when_validating() {
+    let metadata: Metadata = serde_json::from_value(json!({ "bad key": 1 })).unwrap();
+    assert!(matches!(
+        metadata.check_limits(),
+        Err(MetadataError::InvalidKey(_))
+    ));
+
+    let large = json!({ "blob": "x".repeat(Metadata::MAX_SIZE) });
+    let metadata: Metadata = serde_json::from_value(large).unwrap();
+    assert!(matches!(
+        metadata.check_limits(),
+        Err(MetadataError::TooLarge(_))
+    ));
+
+    let request = CreateProjectRequest::new("storefront").with_metadata(metadata);
+    let errors = request.validate().unwrap_err();
+    assert_eq!(errors[0].path, "metadata");
+    assert!(CreateProjectRequest::new("storefront").validate().is_ok());
+}
+
+#[test]
+fn attached_to_entities() {
+    let mut job: JobStatusResponse<PackageStatus> =
+        serde_json::from_str(include_str!("fixtures/job_status_basic.json")).unwrap();
+    assert!(job.metadata.is_empty());
+    assert!(serde_json::to_value(&job)
+        .unwrap()
+        .get("metadata")
+        .is_none());
+
+    job.metadata.insert("correlation-id", "abc-123").unwrap();
+    let value = serde_json::to_value(&job).unwrap();
+    assert_eq!(value["metadata"], json!({ "correlation-id": "abc-123" }));
+
+    let issue: IgnoredIssue = serde_json::from_value(json!({
+        "id": "CVE-2021-23337",
+        "tag": "HV00001",
+        "reason": "Not reachable",
+        "metadata": { "jira": "SEC-123" },
+    }))
+    .unwrap();
+    assert_eq!(issue.metadata.get_str("jira"), Some("SEC-123"));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "mock-server")]
+
+use phylum_types::body::{BodyError, ContentType, FromBody};
+use phylum_types::endpoint::{Endpoint, GetPackage, Method, SubmitPackages};
+use phylum_types::mock_server::{self, Fixtures};
+use phylum_types::types::common::ErrorCode;
+use phylum_types::types::job::{JobStatusResponseVariant, SubmitPackageRequest};
+use uuid::Uuid;
+
+#[test]
+fn every_exchange_is_found_by_its_request() {
+    let catalog = mock_server::catalog();
+    assert!(!catalog.is_empty());
+    for exchange in &catalog {
+        let found = mock_server::find(exchange.method, &exchange.path).unwrap();
+        // Only one exchange per method and path
+        assert_eq!(&found, exchange);
+    }
+    assert_eq!(mock_server::find(Method::Delete, "/data/jobs"), None);
+}
+
+#[test]
+fn bodies_parse_as_endpoint_types() {
+    for exchange in SubmitPackages::exchanges() {
+        let request = exchange.request.unwrap();
+        SubmitPackageRequest::from_body(ContentType::Json, request.as_bytes()).unwrap();
+        <SubmitPackages as Endpoint>::Response::from_response(
+            exchange.status,
+            None,
+            exchange.response.as_bytes(),
+        )
+        .unwrap();
+    }
+}
+
+#[test]
+fn package_path_matches_payload() {
+    let exchanges = GetPackage::exchanges();
+    assert_eq!(exchanges[0].path, "/data/packages/npm/left-pad/1.3.0");
+}
+
+#[test]
+fn missing_job() {
+    let path = format!("/data/jobs/{}", Uuid::nil());
+    let exchange = mock_server::find(Method::Get, &path).unwrap();
+    let error = JobStatusResponseVariant::from_response(
+        exchange.status,
+        None,
+        exchange.response.as_bytes(),
+    )
+    .unwrap_err();
+    match error {
+        BodyError::Api(error) => assert_eq!(error.code, ErrorCode::NotFound),
+        error => panic!("unexpected error {}", error),
+    }
+}
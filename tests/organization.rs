@@ -0,0 +1,86 @@
+use phylum_types::types::organization::*;
+use phylum_types::types::timestamp;
+use serde_json::json;
+
+#[test]
+fn list_orgs() {
+    let response: ListOrgsResponse = serde_json::from_value(json!({
+        "organizations": [
+            {
+                "name": "acme",
+                "created_at": "2022-06-01T12:00:00Z",
+                "groups": ["web", "infra"],
+                "role": "admin",
+            },
+            { "name": "acme-labs", "created_at": "2023-01-01T00:00:00Z" },
+        ],
+    }))
+    .unwrap();
+
+    assert_eq!(response.organizations[0].role, Some(OrgRole::Admin));
+    assert_eq!(response.organizations[0].groups, ["web", "infra"]);
+    assert!(response.organizations[1].groups.is_empty());
+
+    let value = serde_json::to_value(&response.organizations[1]).unwrap();
+    assert!(value.get("role").is_none());
+}
+
+#[test]
+fn members() {
+    let response: ListOrgMembersResponse = serde_json::from_value(json!({
+        "members": [
+            {
+                "user_email": "owner@acme.example",
+                "first_name": "Ada",
+                "last_name": "Lovelace",
+                "role": "owner",
+                "joined_at": "2022-06-01T12:00:00Z",
+            },
+            {
+                "user_email": "dev@acme.example",
+                "first_name": "Grace",
+                "last_name": "Hopper",
+                "role": "member",
+            },
+        ],
+    }))
+    .unwrap();
+
+    let owners: Vec<_> = response.with_role(OrgRole::Owner).collect();
+    assert_eq!(owners.len(), 1);
+    assert!(owners[0].role.can_manage_members());
+    assert!(!response.members[1].role.can_manage_members());
+    assert_eq!(response.members[1].joined_at, None);
+}
+
+#[test]
+fn invites() {
+    let request = OrgInviteRequest {
+        user_email: "new@acme.example".into(),
+        role: OrgRole::Admin,
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "user_email": "new@acme.example", "role": "admin" })
+    );
+
+    let response: OrgInviteResponse = serde_json::from_value(json!({
+        "invite_id": "7b1d2c3e-6f3a-4b9e-9d0c-5a4f1e2d3c4b",
+        "org_name": "acme",
+        "user_email": "new@acme.example",
+        "role": "admin",
+        "expires_at": "2022-06-08T12:00:00Z",
+    }))
+    .unwrap();
+    let before = timestamp::from_epoch_seconds(1_654_084_800).unwrap();
+    let after = timestamp::from_epoch_seconds(1_654_689_600).unwrap();
+    assert!(!response.is_expired_at(before));
+    assert!(response.is_expired_at(after));
+}
+
+#[test]
+fn role_names_match_serde() {
+    for role in [OrgRole::Owner, OrgRole::Admin, OrgRole::Member] {
+        assert_eq!(serde_json::to_value(role).unwrap(), role.to_string());
+    }
+}
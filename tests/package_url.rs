@@ -0,0 +1,158 @@
+use phylum_types::types::package::{Package, PackageStatusExtended};
+use phylum_types::types::package_url::{PackageUrl, PurlError};
+use phylum_types::validation::Validate;
+use serde_json::json;
+
+fn package(purl: &str, name: &str, version: &str, registry: &str) -> Package {
+    Package {
+        purl: Some(purl.into()),
+        name: name.into(),
+        version: version.into(),
+        registry: registry.into(),
+        ..Default::default()
+    }
+}
+
+fn mismatch(package: &Package) -> &'static str {
+    match package.validate_purl() {
+        Err(PurlError::Mismatch { field, .. }) => field,
+        result => panic!("expected a mismatch, got {:?}", result),
+    }
+}
+
+#[test]
+fn missing_purl() {
+    let package = Package::default();
+    assert!(package.parsed_purl().is_none());
+    assert!(package.validate_purl().is_ok());
+}
+
+#[test]
+fn invalid_purl() {
+    let package = package("npm/left-pad@1.3.0", "left-pad", "1.3.0", "npm");
+    assert!(matches!(
+        package.parsed_purl(),
+        Some(Err(PurlError::Parse(_)))
+    ));
+    assert!(matches!(package.validate_purl(), Err(PurlError::Parse(_))));
+}
+
+#[test]
+fn consistent_purls() {
+    for package in [
+        package("pkg:npm/left-pad@1.3.0", "left-pad", "1.3.0", "npm"),
+        package("pkg:gem/rails@7.0.4", "rails", "7.0.4", "rubygems"),
+        package(
+            "pkg:maven/org.apache.commons/commons-lang3@3.12.0",
+            "org.apache.commons:commons-lang3",
+            "3.12.0",
+            "maven",
+        ),
+        package(
+            "pkg:pypi/typing-extensions@4.4.0",
+            "typing_extensions",
+            "4.4.0",
+            "pypi",
+        ),
+        package("pkg:hackage/aeson@2.1.0", "aeson", "2.1.0", "hackage"),
+        // Versionless package URLs refer to every version
+        package("pkg:cargo/serde", "serde", "1.0.0", "cargo"),
+    ] {
+        assert!(package.validate_purl().is_ok(), "{:?}", package.purl);
+    }
+}
+
+#[test]
+fn mismatched_purls() {
+    assert_eq!(
+        mismatch(&package(
+            "pkg:npm/left-pad@1.3.0",
+            "left-pad",
+            "1.3.0",
+            "pypi"
+        )),
+        "type"
+    );
+    assert_eq!(
+        mismatch(&package(
+            "pkg:npm/left-pad@1.3.0",
+            "right-pad",
+            "1.3.0",
+            "npm"
+        )),
+        "name"
+    );
+    assert_eq!(
+        mismatch(&package(
+            "pkg:npm/left-pad@1.3.0",
+            "left-pad",
+            "1.2.0",
+            "npm"
+        )),
+        "version"
+    );
+
+    let error = package("pkg:npm/left-pad@1.3.0", "left-pad", "1.2.0", "npm")
+        .validate()
+        .unwrap_err();
+    assert_eq!(error[0].path, "purl");
+    assert_eq!(
+        error[0].message,
+        "package URL version 1.3.0 does not match package version 1.2.0"
+    );
+}
+
+#[test]
+fn with_purl() {
+    let purl: PackageUrl = "pkg:maven/org.apache.commons/commons-lang3@3.12.0"
+        .parse()
+        .unwrap();
+    let package = Package::with_purl(purl);
+    assert_eq!(package.name, "org.apache.commons:commons-lang3");
+    assert_eq!(package.version, "3.12.0");
+    assert_eq!(&*package.registry, "maven");
+    assert_eq!(package.id, "maven:org.apache.commons:commons-lang3:3.12.0");
+    assert_eq!(
+        package.purl.as_deref(),
+        Some("pkg:maven/org.apache.commons/commons-lang3@3.12.0")
+    );
+    assert!(package.validate_purl().is_ok());
+
+    let package = Package::with_purl("pkg:gem/rails@7.0.4".parse().unwrap());
+    assert_eq!(&*package.registry, "rubygems");
+}
+
+#[test]
+fn package_status_purl() {
+    let status = |purl: &str| -> PackageStatusExtended {
+        serde_json::from_value(json!({
+            "purl": purl,
+            "name": "express",
+            "version": "4.18.1",
+            "status": "complete",
+            "last_updated": 1649999000,
+            "license": "MIT",
+            "package_score": 0.9,
+            "num_dependencies": 0,
+            "type": "npm",
+            "riskVectors": {},
+            "dependencies": {},
+            "issues": [],
+        }))
+        .unwrap()
+    };
+
+    let consistent = status("pkg:npm/express@4.18.1");
+    assert!(consistent.basic_status.validate_purl().is_ok());
+    assert!(consistent.validate().is_ok());
+
+    let other_type = status("pkg:cargo/express@4.18.1");
+    // The basic status has no type to compare
+    assert!(other_type.basic_status.validate_purl().is_ok());
+    let errors = other_type.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].path, "purl");
+
+    let other_name = status("pkg:npm/koa@4.18.1");
+    assert_eq!(other_name.validate().unwrap_err().len(), 1);
+}
@@ -0,0 +1,105 @@
+use std::cell::Cell;
+
+use phylum_types::endpoint::ListProjectsPage;
+use phylum_types::pagination::PageWalker;
+use phylum_types::types::common::{PageCursor, PageParams, Paginated};
+use phylum_types::types::project::ProjectSummaryResponse;
+
+fn project(name: &str) -> ProjectSummaryResponse {
+    serde_json::from_value(serde_json::json!({
+        "name": name,
+        "id": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "created_at": "2022-01-10T09:00:00Z",
+        "updated_at": "2022-04-15T05:20:00Z",
+        "group_name": null,
+        "repository_url": null,
+    }))
+    .unwrap()
+}
+
+fn page(names: &[&str], next_cursor: Option<&str>) -> Paginated<ProjectSummaryResponse> {
+    Paginated {
+        values: names.iter().map(|name| project(name)).collect(),
+        next_cursor: next_cursor.map(|cursor| PageCursor(cursor.into())),
+        total: None,
+    }
+}
+
+fn names(walker: impl Iterator<Item = Result<ProjectSummaryResponse, String>>) -> Vec<String> {
+    walker.map(|project| project.unwrap().name).collect()
+}
+
+#[test]
+fn walks_every_page_lazily() {
+    let fetches = Cell::new(0);
+    let mut walker =
+        PageWalker::<ListProjectsPage, _, _, _>::new(PageParams::default(), |params| {
+            fetches.set(fetches.get() + 1);
+            Ok::<_, String>(
+                match params.cursor.as_ref().map(|cursor| cursor.0.as_str()) {
+                    None => page(&["a", "b"], Some("2")),
+                    Some("2") => page(&[], Some("3")),
+                    Some("3") => page(&["c"], None),
+                    Some(cursor) => panic!("unexpected cursor {}", cursor),
+                },
+            )
+        });
+
+    assert_eq!(walker.next().unwrap().unwrap().name, "a");
+    assert_eq!(fetches.get(), 1);
+    assert_eq!(names(walker), ["b", "c"]);
+    assert_eq!(fetches.get(), 3);
+}
+
+#[test]
+fn keeps_the_limit() {
+    let params = PageParams {
+        cursor: None,
+        limit: Some(1),
+    };
+    let walker = PageWalker::<ListProjectsPage, _, _, _>::new(params, |params| {
+        assert_eq!(params.limit, Some(1));
+        Ok(match params.cursor {
+            None => page(&["a"], Some("2")),
+            Some(_) => page(&["b"], None),
+        })
+    });
+    assert_eq!(names(walker), ["a", "b"]);
+}
+
+#[test]
+fn stops_after_an_error() {
+    let mut walker =
+        PageWalker::<ListProjectsPage, _, _, _>::new(PageParams::default(), |params| match params
+            .cursor
+        {
+            None => Ok(page(&["a"], Some("2"))),
+            Some(_) => Err("unavailable".to_string()),
+        });
+    assert!(walker.next().unwrap().is_ok());
+    assert_eq!(walker.next().unwrap().unwrap_err(), "unavailable");
+    assert!(walker.next().is_none());
+}
+
+#[test]
+fn stops_on_a_repeated_cursor() {
+    let start = PageParams {
+        cursor: Some(PageCursor("same".into())),
+        limit: None,
+    };
+    let walker =
+        PageWalker::<ListProjectsPage, _, _, _>::new(start, |_| Ok(page(&["a"], Some("same"))));
+    assert_eq!(names(walker), ["a"]);
+}
+
+#[test]
+fn page_query() {
+    let params = PageParams {
+        cursor: Some(PageCursor("abc=".into())),
+        limit: Some(50),
+    };
+    assert_eq!(
+        serde_urlencoded::to_string(&params).unwrap(),
+        "cursor=abc%3D&limit=50"
+    );
+}
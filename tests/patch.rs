@@ -0,0 +1,122 @@
+use phylum_types::endpoint::{Endpoint, Method, PatchProject};
+use phylum_types::types::common::{Patch, ProjectId};
+use phylum_types::types::metadata::Metadata;
+use phylum_types::types::preferences::{
+    CorePreferences, IgnoredIssue, PreferencesPatch, RiskThresholds,
+};
+use phylum_types::types::project::{ProjectPatch, ProjectSummaryResponse};
+use serde_json::json;
+
+fn project() -> ProjectSummaryResponse {
+    serde_json::from_value(json!({
+        "name": "web",
+        "id": "00000000-0000-0000-0000-000000000001",
+        "updated_at": "2024-01-02T00:00:00Z",
+        "created_at": "2024-01-01T00:00:00Z",
+        "group_name": "platform",
+        "repository_url": "https://example.com/web.git",
+        "metadata": { "team": "frontend" },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn project_patch_is_lossless() {
+    let patch: ProjectPatch = serde_json::from_value(
+        json!({ "group_name": null, "repository_url": "https://example.com/new.git" }),
+    )
+    .unwrap();
+    assert_eq!(patch.name, Patch::Absent);
+    assert_eq!(patch.group_name, Patch::Null);
+    assert_eq!(
+        patch.repository_url,
+        Patch::Value("https://example.com/new.git".into())
+    );
+    assert_eq!(patch.metadata, Patch::Absent);
+
+    assert_eq!(
+        serde_json::to_value(&patch).unwrap(),
+        json!({ "group_name": null, "repository_url": "https://example.com/new.git" })
+    );
+
+    let empty: ProjectPatch = serde_json::from_value(json!({})).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(serde_json::to_value(&empty).unwrap(), json!({}));
+}
+
+#[test]
+fn project_patch_apply() {
+    let mut project = project();
+    ProjectPatch::default()
+        .with_name("website")
+        .with_group_name(None)
+        .with_metadata(None)
+        .apply_to(&mut project);
+    assert_eq!(project.name, "website");
+    assert_eq!(project.group_name, None);
+    assert_eq!(
+        project.repository_url.as_deref(),
+        Some("https://example.com/web.git")
+    );
+    assert_eq!(project.metadata, Metadata::default());
+
+    // The name cannot be cleared
+    let patch: ProjectPatch = serde_json::from_value(json!({ "name": null })).unwrap();
+    patch.apply_to(&mut project);
+    assert_eq!(project.name, "website");
+}
+
+#[test]
+fn project_patch_endpoint() {
+    assert_eq!(PatchProject::METHOD, Method::Patch);
+    let project_id = ProjectId::nil();
+    assert_eq!(
+        PatchProject::path(&project_id),
+        "/data/projects/00000000-0000-0000-0000-000000000000"
+    );
+    let _: &<PatchProject as Endpoint>::Request = &ProjectPatch::default();
+}
+
+#[test]
+fn preferences_patch_is_lossless() {
+    let patch: PreferencesPatch =
+        serde_json::from_value(json!({ "defaultLabel": null, "ignoredIssues": [] })).unwrap();
+    assert_eq!(patch.default_label, Patch::Null);
+    assert_eq!(patch.thresholds, Patch::Absent);
+    assert_eq!(patch.ignored_issues, Patch::Value(Vec::new()));
+    assert_eq!(
+        serde_json::to_value(&patch).unwrap(),
+        json!({ "defaultLabel": null, "ignoredIssues": [] })
+    );
+    assert!(PreferencesPatch::default().is_empty());
+}
+
+#[test]
+fn preferences_patch_apply() {
+    let ignored = IgnoredIssue {
+        id: "1".into(),
+        tag: "HV0001".into(),
+        reason: "false positive".into(),
+        metadata: Metadata::default(),
+    };
+    let mut preferences = CorePreferences {
+        default_label: Some("main".into()),
+        ignored_issues: Some(vec![ignored.clone()]),
+        ..Default::default()
+    };
+    preferences.thresholds.total.threshold = 0.9;
+
+    PreferencesPatch::default()
+        .with_thresholds(None)
+        .apply_to(&mut preferences);
+    assert_eq!(preferences.thresholds, RiskThresholds::default());
+    assert_eq!(preferences.default_label.as_deref(), Some("main"));
+    assert_eq!(preferences.ignored_issues, Some(vec![ignored]));
+
+    PreferencesPatch::default()
+        .with_default_label(Some("develop".into()))
+        .with_ignored_issues(None)
+        .apply_to(&mut preferences);
+    assert_eq!(preferences.default_label.as_deref(), Some("develop"));
+    assert_eq!(preferences.ignored_issues, None);
+}
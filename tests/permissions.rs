@@ -0,0 +1,46 @@
+use phylum_types::types::group::{Permission, Permissions, Role, RoleAssignment};
+use serde_json::json;
+
+#[test]
+fn permissions_serialize_as_names() {
+    let permissions = Permission::SubmitJobs | Permission::ViewProjects;
+    assert_eq!(
+        serde_json::to_value(permissions).unwrap(),
+        json!(["view_projects", "submit_jobs"])
+    );
+    assert_eq!(
+        serde_json::to_value(Permissions::empty()).unwrap(),
+        json!([])
+    );
+}
+
+#[test]
+fn unknown_permissions_are_dropped() {
+    let permissions: Permissions =
+        serde_json::from_value(json!(["manage_members", "launch_rockets"])).unwrap();
+    assert_eq!(permissions, Permission::ManageMembers.into());
+}
+
+#[test]
+fn role_assignment() {
+    let assignment: RoleAssignment = serde_json::from_value(json!({
+        "user_email": "observer@example.com",
+        "group_name": "security",
+        "role": "observer",
+        "permissions": ["view_projects"],
+    }))
+    .unwrap();
+    assert_eq!(assignment.permissions, Role::Observer.default_permissions());
+    assert!(assignment.allows(Permission::ViewProjects));
+    assert!(!assignment.allows(Permission::SubmitJobs));
+
+    assert!(Role::Owner
+        .default_permissions()
+        .contains(Permission::ManageGroup));
+    assert!(!Role::Admin
+        .default_permissions()
+        .contains(Permission::ManageGroup));
+    assert!(Role::Admin
+        .default_permissions()
+        .contains(Permission::ManageMembers));
+}
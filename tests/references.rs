@@ -0,0 +1,147 @@
+use phylum_types::types::package::{Issue, Reference, ReferenceKind, Vulnerability};
+use serde_json::json;
+
+fn issue(references: serde_json::Value) -> Issue {
+    serde_json::from_value(json!({
+        "tag": null,
+        "id": "CVE-2021-23337",
+        "title": "Command injection in lodash",
+        "description": "`template` allows command injection.",
+        "severity": "high",
+        "domain": "vulnerability",
+        "references": references,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn plain_urls_are_unclassified() {
+    let issue = issue(json!(["https://nvd.nist.gov/vuln/detail/CVE-2021-23337"]));
+    assert_eq!(
+        issue.references,
+        [Reference::from(
+            "https://nvd.nist.gov/vuln/detail/CVE-2021-23337"
+        )]
+    );
+    assert_eq!(issue.references[0].kind, ReferenceKind::Other);
+
+    let value = serde_json::to_value(&issue).unwrap();
+    assert_eq!(
+        value["references"],
+        json!(["https://nvd.nist.gov/vuln/detail/CVE-2021-23337"])
+    );
+}
+
+#[test]
+fn malformed_urls_are_kept() {
+    let issue = issue(json!(["GHSA-35jh-r3h4-6jhm", { "url": "", "kind": "advisory" }]));
+    assert_eq!(issue.references[0].url, "GHSA-35jh-r3h4-6jhm");
+    assert_eq!(
+        issue.references[1],
+        Reference::new("", ReferenceKind::Advisory)
+    );
+}
+
+#[test]
+fn classified_references() {
+    let references = json!([
+        { "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm", "kind": "advisory" },
+        { "url": "https://github.com/lodash/lodash/commit/3469357", "kind": "fix" },
+        { "url": "https://example.com/lodash-rce", "kind": "podcast" },
+        "https://snyk.io/blog/lodash",
+    ]);
+    let issue = issue(references);
+
+    let kinds: Vec<_> = issue
+        .references
+        .iter()
+        .map(|reference| reference.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ReferenceKind::Advisory,
+            ReferenceKind::Fix,
+            ReferenceKind::Other,
+            ReferenceKind::Other,
+        ]
+    );
+    let fixes: Vec<_> = issue.references_of(ReferenceKind::Fix).collect();
+    assert_eq!(fixes.len(), 1);
+    assert_eq!(
+        fixes[0].url,
+        "https://github.com/lodash/lodash/commit/3469357"
+    );
+
+    let value = serde_json::to_value(&issue).unwrap();
+    assert_eq!(
+        value["references"][0],
+        json!({ "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm", "kind": "advisory" })
+    );
+    assert_eq!(
+        value["references"][2],
+        json!("https://example.com/lodash-rce")
+    );
+}
+
+#[test]
+fn titled_references() {
+    let issue = issue(json!([
+        { "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm", "title": "GHSA" },
+        { "url": "https://github.com/lodash/lodash/commit/3469357", "kind": "fix", "title": "Fix" },
+    ]));
+    assert_eq!(
+        issue.references,
+        [
+            Reference::new(
+                "https://github.com/advisories/GHSA-35jh-r3h4-6jhm",
+                ReferenceKind::Other
+            )
+            .with_title("GHSA"),
+            Reference::new(
+                "https://github.com/lodash/lodash/commit/3469357",
+                ReferenceKind::Fix
+            )
+            .with_title("Fix"),
+        ]
+    );
+
+    // A title keeps the object form, even for unclassified references
+    let value = serde_json::to_value(&issue).unwrap();
+    assert_eq!(
+        value["references"][0],
+        json!({
+            "url": "https://github.com/advisories/GHSA-35jh-r3h4-6jhm",
+            "kind": "other",
+            "title": "GHSA",
+        })
+    );
+    let roundtrip: Issue = serde_json::from_value(value).unwrap();
+    assert_eq!(roundtrip, issue);
+}
+
+#[test]
+fn vulnerability_references() {
+    let vulnerability: Vulnerability = serde_json::from_value(json!({
+        "cve": ["CVE-2021-23337"],
+        "severity": 7.2,
+        "risk_level": "high",
+        "title": "Command injection in lodash",
+        "description": "`template` allows command injection.",
+        "remediation": "Upgrade to 4.17.21",
+    }))
+    .unwrap();
+    assert!(vulnerability.references.is_empty());
+
+    let mut value = serde_json::to_value(&vulnerability).unwrap();
+    assert!(value.get("references").is_none());
+    value["references"] = json!([{ "url": "https://www.exploit-db.com/1", "kind": "exploit" }]);
+    let vulnerability: Vulnerability = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        vulnerability.references,
+        [Reference::new(
+            "https://www.exploit-db.com/1",
+            ReferenceKind::Exploit
+        )]
+    );
+}
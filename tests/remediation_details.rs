@@ -0,0 +1,81 @@
+use phylum_types::types::package::{Issue, Vulnerability};
+use phylum_types::types::remediation::RemediationInfo;
+use serde_json::json;
+
+#[test]
+fn vulnerability_details() {
+    let vulnerability: Vulnerability = serde_json::from_value(json!({
+        "cve": ["CVE-2021-23337"],
+        "severity": 7.2,
+        "risk_level": "high",
+        "title": "Command injection in lodash",
+        "description": "`template` allows command injection.",
+        "remediation": "Upgrade to 4.17.21",
+        "remediation_details": {
+            "fixedVersions": ["4.17.21"],
+            "patchUrls": ["https://github.com/lodash/lodash/commit/3469357"],
+            "workaround": "Do not pass untrusted input to `template`.",
+        },
+    }))
+    .unwrap();
+
+    let details = vulnerability.remediation_details.as_ref().unwrap();
+    assert_eq!(details.suggested_version(), Some("4.17.21"));
+    assert!(details.has_fix());
+    assert!(details.upgrade_path.is_empty());
+
+    let value = serde_json::to_value(&vulnerability).unwrap();
+    assert!(value["remediation_details"].get("upgrade_path").is_none());
+    assert_eq!(
+        serde_json::from_value::<Vulnerability>(value).unwrap(),
+        vulnerability
+    );
+}
+
+#[test]
+fn issue_details() {
+    let mut issue: Issue = serde_json::from_value(json!({
+        "tag": "CV0001",
+        "id": "b4ac0b5e-8aa4-4b3b-a6ae-f1b3ba8cb8f1",
+        "title": "Prototype pollution in minimist",
+        "description": "",
+        "severity": "medium",
+        "domain": "vulnerability",
+    }))
+    .unwrap();
+    assert_eq!(issue.remediation_details, None);
+    assert!(serde_json::to_value(&issue)
+        .unwrap()
+        .get("remediation_details")
+        .is_none());
+
+    issue.remediation_details = Some(Box::new(RemediationInfo {
+        upgrade_path: vec!["1.2.6".into(), "1.2.8".into()],
+        fixed_versions: vec!["1.2.6".into(), "0.2.4".into()],
+        ..Default::default()
+    }));
+    let value = serde_json::to_value(&issue).unwrap();
+    assert_eq!(
+        value["remediation_details"],
+        json!({ "fixed_versions": ["1.2.6", "0.2.4"], "upgrade_path": ["1.2.6", "1.2.8"] })
+    );
+
+    let issue: Issue = serde_json::from_value(value).unwrap();
+    let details = issue.remediation_details.unwrap();
+    assert_eq!(details.suggested_version(), Some("1.2.8"));
+}
+
+#[test]
+fn no_fix() {
+    let details = RemediationInfo {
+        workaround: Some("Disable the feature".into()),
+        ..Default::default()
+    };
+    assert!(!details.has_fix());
+    assert!(!details.is_empty());
+    assert!(RemediationInfo::default().is_empty());
+    assert_eq!(
+        serde_json::from_value::<RemediationInfo>(json!({})).unwrap(),
+        RemediationInfo::default()
+    );
+}
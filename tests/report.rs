@@ -0,0 +1,104 @@
+use phylum_types::endpoint::{Endpoint, GenerateReport, GetReportDownload, GetReportStatus};
+use phylum_types::types::report::{
+    ReportDownloadInfo, ReportFormat, ReportRequest, ReportScope, ReportState, ReportStatus,
+};
+use serde_json::json;
+use uuid::Uuid;
+
+#[test]
+fn request() {
+    let project_id = Uuid::nil();
+    let request = ReportRequest::new(
+        ReportFormat::Pdf,
+        ReportScope::Project {
+            project_id,
+            label: Some("main".into()),
+        },
+    )
+    .with_title("Quarterly review");
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "format": "pdf",
+            "scope": {
+                "type": "project",
+                "project_id": "00000000-0000-0000-0000-000000000000",
+                "label": "main",
+            },
+            "title": "Quarterly review",
+            "include_ignored": false,
+        })
+    );
+
+    let parsed: ReportRequest = serde_json::from_value(json!({
+        "format": "sarif",
+        "scope": { "type": "group", "groupName": "platform" },
+    }))
+    .unwrap();
+    assert_eq!(
+        parsed,
+        ReportRequest::new(
+            ReportFormat::Sarif,
+            ReportScope::Group {
+                group_name: "platform".into()
+            }
+        )
+    );
+
+    let _: &<GenerateReport as Endpoint>::Request = &request;
+}
+
+#[test]
+fn formats() {
+    assert_eq!(ReportFormat::Html.to_string(), "html");
+    assert_eq!(ReportFormat::Pdf.media_type(), "application/pdf");
+    assert_eq!(ReportFormat::Sarif.extension(), "sarif.json");
+    assert_eq!(
+        serde_json::to_value(ReportFormat::Json).unwrap(),
+        json!(ReportFormat::Json.name())
+    );
+}
+
+#[test]
+fn status() {
+    let status: ReportStatus = serde_json::from_value(json!({
+        "id": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "state": "generating",
+        "format": "html",
+        "scope": { "type": "job", "job_id": "00000000-0000-0000-0000-000000000000" },
+        "requested_at": "2024-01-01T00:00:00Z",
+    }))
+    .unwrap();
+    assert_eq!(status.state, ReportState::Generating);
+    assert!(!status.state.is_finished());
+    assert!(!status.is_ready());
+    assert_eq!(status.completed_at, None);
+
+    assert!(ReportState::Ready.is_finished());
+    assert!(ReportState::Expired.is_finished());
+    assert_eq!(
+        GetReportStatus::path(&status.id),
+        "/data/reports/0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8"
+    );
+}
+
+#[test]
+fn download_info() {
+    let info: ReportDownloadInfo = serde_json::from_value(json!({
+        "url": "https://reports.example.com/r/abc?sig=123",
+        "expires_at": "2024-01-01T01:00:00Z",
+        "format": "pdf",
+        "file_name": "web.pdf",
+        "size_bytes": 20480,
+    }))
+    .unwrap();
+    assert_eq!(info.file_name.as_deref(), Some("web.pdf"));
+    assert_eq!(info.sha256, None);
+    assert!(!info.is_expired_at("2024-01-01T00:30:00Z".parse().unwrap()));
+    assert!(info.is_expired_at("2024-01-01T01:00:00Z".parse().unwrap()));
+
+    assert_eq!(
+        GetReportDownload::path(&Uuid::nil()),
+        "/data/reports/00000000-0000-0000-0000-000000000000/download"
+    );
+}
@@ -0,0 +1,78 @@
+use std::convert::TryFrom;
+
+use phylum_types::types::group::CreateGroupRequest;
+use phylum_types::types::job::{
+    BatchLimits, BatchSubmitRequest, JobPriority, SubmitOptions, SubmitPackageRequest,
+};
+use phylum_types::types::label::Label;
+use phylum_types::types::organization::{OrgInviteRequest, OrgRole};
+#[allow(deprecated)]
+use phylum_types::types::package::{PackageDescriptor, PackageType};
+use phylum_types::types::project::CreateProjectRequest;
+use phylum_types::types::token::TokenScope;
+use phylum_types::types::user::CreateTokenRequest;
+use phylum_types::types::view::{IssueFilter, SaveViewRequest, ViewScope};
+use serde_json::json;
+use uuid::Uuid;
+
+#[allow(deprecated)]
+#[test]
+fn submit_request() {
+    let project = Uuid::parse_str("0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8").unwrap();
+    let label = Label::try_from("main").unwrap();
+    let package = PackageDescriptor::new("left-pad", "1.3.0", PackageType::Npm);
+
+    let request = SubmitPackageRequest::new(project, label)
+        .with_packages(vec![package.into()])
+        .with_group_name("platform")
+        .with_options(SubmitOptions {
+            priority: JobPriority::High,
+            ..Default::default()
+        });
+
+    assert!(!request.is_user);
+    assert_eq!(request.packages.len(), 1);
+    let value = serde_json::to_value(&request).unwrap();
+    assert_eq!(value["group_name"], "platform");
+    assert_eq!(value["options"]["priority"], "high");
+    assert!(value.get("metadata").is_none());
+
+    let batch = BatchSubmitRequest::new(vec![request.with_is_user(true)]);
+    assert!(batch.submissions[0].is_user);
+    assert_eq!(batch.validate_size(&BatchLimits::new(1, 1)), Ok(()));
+}
+
+#[test]
+fn project_request() {
+    let request = CreateProjectRequest::new("storefront")
+        .with_repository_url("https://github.com/example/storefront");
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({ "name": "storefront", "repository_url": "https://github.com/example/storefront" })
+    );
+    assert_eq!(
+        request.with_group_name("web").group_name.as_deref(),
+        Some("web")
+    );
+    assert_eq!(CreateProjectRequest::default().name, "");
+}
+
+#[test]
+fn other_requests() {
+    let group = CreateGroupRequest::new("platform");
+    assert_eq!(group.group_name, "platform");
+
+    let invite = OrgInviteRequest::new("developer@example.com", OrgRole::Admin);
+    assert_eq!(invite.role, OrgRole::Admin);
+
+    let token = CreateTokenRequest::new("ci").with_scopes(vec![TokenScope::SubmitJobs]);
+    assert_eq!(token.expires_at, None);
+    assert_eq!(token.scopes, [TokenScope::SubmitJobs]);
+
+    let view = SaveViewRequest::new("Critical", IssueFilter::default());
+    assert_eq!(view.scope, ViewScope::Private);
+    let view = view.with_scope(ViewScope::Group {
+        group_name: "platform".into(),
+    });
+    assert!(view.scope.is_shared());
+}
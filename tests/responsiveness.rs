@@ -0,0 +1,57 @@
+use phylum_types::types::package::{DeveloperResponsiveness, RiskLevel};
+
+fn responsiveness(
+    issues: Option<(u64, u64)>,
+    pull_requests: Option<(u64, u64)>,
+) -> DeveloperResponsiveness {
+    DeveloperResponsiveness {
+        open_issue_count: issues.map(|(open, _)| open),
+        total_issue_count: issues.map(|(_, total)| total),
+        open_pull_request_count: pull_requests.map(|(open, _)| open),
+        total_pull_request_count: pull_requests.map(|(_, total)| total),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn rates() {
+    let metrics = responsiveness(Some((25, 100)), Some((5, 10)));
+    assert_eq!(metrics.issue_close_rate(), Some(0.75));
+    assert_eq!(metrics.pr_merge_rate(), Some(0.5));
+    assert_eq!(metrics.resolution_rate(), Some(0.625));
+    assert_eq!(metrics.responsiveness_grade(), RiskLevel::Low);
+}
+
+#[test]
+fn missing_counts() {
+    let metrics = responsiveness(None, Some((1, 10)));
+    assert_eq!(metrics.issue_close_rate(), None);
+    assert_eq!(metrics.resolution_rate(), Some(0.9));
+    assert_eq!(metrics.responsiveness_grade(), RiskLevel::Info);
+
+    let metrics = DeveloperResponsiveness {
+        open_issue_count: Some(3),
+        ..Default::default()
+    };
+    assert_eq!(metrics.issue_close_rate(), None);
+    assert_eq!(metrics.responsiveness_grade(), RiskLevel::Info);
+}
+
+#[test]
+fn empty_and_inconsistent_counts() {
+    assert_eq!(responsiveness(Some((0, 0)), None).issue_close_rate(), None);
+    assert_eq!(
+        responsiveness(Some((12, 10)), None).issue_close_rate(),
+        Some(0.0)
+    );
+}
+
+#[test]
+fn grades() {
+    let grade = |open| responsiveness(Some((open, 100)), None).responsiveness_grade();
+    assert_eq!(grade(20), RiskLevel::Info);
+    assert_eq!(grade(21), RiskLevel::Low);
+    assert_eq!(grade(60), RiskLevel::Medium);
+    assert_eq!(grade(79), RiskLevel::High);
+    assert_eq!(grade(81), RiskLevel::Critical);
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "sarif")]
+
+use phylum_types::sarif::SarifLog;
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::PackageStatusExtended;
+use serde_json::{json, Value};
+
+mod common;
+
+fn package(name: &str, package_type: &str, lockfiles: Value) -> Value {
+    let mut package = common::extended_package(name, package_type);
+    package["lockfiles"] = lockfiles;
+    package["issues"] = json!([{
+        "tag": "HV0001",
+        "title": "Known vulnerability",
+        "description": "",
+        "severity": "high",
+        "domain": "vulnerability",
+    }]);
+    package
+}
+
+fn job() -> JobStatusResponse<PackageStatusExtended> {
+    serde_json::from_value(common::job(vec![
+        package(
+            "left-pad",
+            "npm",
+            json!([{ "path": "web/package-lock.json" }]),
+        ),
+        package("requests", "pypi", json!([])),
+    ]))
+    .unwrap()
+}
+
+#[test]
+fn package_results_have_physical_locations() {
+    let log = serde_json::to_value(SarifLog::from(&job())).unwrap();
+    let results = &log["runs"][0]["results"];
+
+    let left_pad = &results[0]["locations"][0];
+    assert_eq!(
+        left_pad["physicalLocation"]["artifactLocation"]["uri"],
+        "web/package-lock.json"
+    );
+    assert_eq!(
+        left_pad["logicalLocations"][0]["fullyQualifiedName"],
+        "left-pad@1.0.0"
+    );
+
+    let requests = &results[1]["locations"][0];
+    assert_eq!(
+        requests["physicalLocation"]["artifactLocation"]["uri"],
+        "requirements.txt"
+    );
+}
@@ -0,0 +1,73 @@
+use std::cmp::Ordering;
+
+use phylum_types::types::package::{Issue, IssueStatus, RiskDomain, RiskLevel, SortBySeverity};
+
+fn issue(title: &str, severity: RiskLevel, domain: RiskDomain) -> Issue {
+    Issue::new(title, severity, domain)
+}
+
+#[test]
+fn worst_of() {
+    assert_eq!(RiskLevel::max_of(Vec::<RiskLevel>::new()), None);
+    assert_eq!(
+        RiskLevel::max_of([RiskLevel::Low, RiskLevel::High, RiskLevel::Medium]),
+        Some(RiskLevel::High)
+    );
+    let levels = vec![RiskLevel::Critical, RiskLevel::Info];
+    assert_eq!(RiskLevel::max_of(&levels), Some(RiskLevel::Critical));
+
+    let issues = [
+        issue("a", RiskLevel::Medium, RiskDomain::Malicious),
+        issue("b", RiskLevel::High, RiskDomain::LicenseRisk),
+    ];
+    let worst: RiskLevel = issues.iter().map(|issue| issue.severity).sum();
+    assert_eq!(worst, RiskLevel::High);
+    assert_eq!([RiskLevel::Low].iter().sum::<RiskLevel>(), RiskLevel::Low);
+    assert_eq!(
+        std::iter::empty::<RiskLevel>().sum::<RiskLevel>(),
+        RiskLevel::Info
+    );
+}
+
+#[test]
+fn sort_issues() {
+    let mut issues = vec![
+        issue("low", RiskLevel::Low, RiskDomain::Malicious),
+        issue("high license", RiskLevel::High, RiskDomain::LicenseRisk),
+        issue("critical", RiskLevel::Critical, RiskDomain::EngineeringRisk),
+        issue("high malicious", RiskLevel::High, RiskDomain::Malicious),
+        issue(
+            "high malicious again",
+            RiskLevel::High,
+            RiskDomain::Malicious,
+        ),
+    ];
+    assert_eq!(issues[2].cmp_severity(&issues[0]), Ordering::Less);
+
+    issues.sort_by_severity();
+    let titles: Vec<_> = issues.iter().map(|issue| issue.title.as_str()).collect();
+    assert_eq!(
+        titles,
+        [
+            "critical",
+            "high malicious",
+            "high malicious again",
+            "high license",
+            "low"
+        ]
+    );
+
+    let mut statuses: Vec<_> = issues
+        .into_iter()
+        .rev()
+        .map(|issue| IssueStatus {
+            issue,
+            ignored: None,
+            #[cfg(deny_unknown_fields)]
+            extra: Default::default(),
+        })
+        .collect();
+    statuses.sort_by_severity();
+    assert_eq!(statuses[0].issue.title, "critical");
+    assert_eq!(statuses[4].issue.title, "low");
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "simd-json")]
+
+use phylum_types::simd;
+use phylum_types::types::job::{AllJobsStatusResponse, JobStatusResponse};
+use phylum_types::types::package::{Package, PackageStatus, PackageStatusExtended};
+use phylum_types::types::project::ProjectSummaryResponse;
+use serde::de::DeserializeOwned;
+
+/// Parsing with simd-json gives the same result as with serde_json.
+fn same_as_serde_json<T: DeserializeOwned + PartialEq + std::fmt::Debug>(
+    json: &str,
+    parse: fn(&mut [u8]) -> Result<T, simd::Error>,
+) {
+    let expected: T = serde_json::from_str(json).unwrap();
+    let mut bytes = json.as_bytes().to_vec();
+    assert_eq!(parse(&mut bytes).unwrap(), expected);
+}
+
+#[test]
+fn fixtures() {
+    same_as_serde_json(
+        include_str!("fixtures/all_jobs_status_response.json"),
+        AllJobsStatusResponse::from_slice,
+    );
+    same_as_serde_json(
+        include_str!("fixtures/job_status_basic.json"),
+        JobStatusResponse::<PackageStatus>::from_slice,
+    );
+    same_as_serde_json(
+        include_str!("fixtures/job_status_extended.json"),
+        JobStatusResponse::<PackageStatusExtended>::from_slice,
+    );
+    same_as_serde_json(include_str!("fixtures/package.json"), Package::from_slice);
+    same_as_serde_json(
+        include_str!("fixtures/project_summaries.json"),
+        simd::from_slice::<Vec<ProjectSummaryResponse>>,
+    );
+}
+
+#[test]
+fn invalid() {
+    let mut bytes = br#"{"jobs": [}"#.to_vec();
+    assert!(AllJobsStatusResponse::from_slice(&mut bytes).is_err());
+
+    let mut bytes = br#"{"jobs": []}"#.to_vec();
+    assert!(AllJobsStatusResponse::from_slice(&mut bytes).is_err());
+}
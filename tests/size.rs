@@ -0,0 +1,45 @@
+//! Size budgets for types which are held in large numbers.
+//!
+//! Raise a budget only when the growth is expected, such as after adding a
+//! field. Capturing unknown fields adds one map to each type which has them,
+//! and interned strings are smaller than `String`s.
+
+// The deprecated `PackageDescriptor` keeps a budget while it is still sent
+#![allow(deprecated)]
+
+use std::mem::size_of;
+
+use phylum_types::types::package::{Issue, PackageDescriptor, PackageStatusExtended};
+use static_assertions::const_assert;
+
+#[cfg(not(feature = "preserve-unknown"))]
+const EXTRA: usize = 0;
+#[cfg(feature = "preserve-unknown")]
+const EXTRA: usize = 24;
+
+// Strict builds capture the fields left over by the basic status, to reject
+// them
+#[cfg(not(any(feature = "preserve-unknown", deny_unknown_fields)))]
+const STATUS_EXTRA: usize = 0;
+#[cfg(any(feature = "preserve-unknown", deny_unknown_fields))]
+const STATUS_EXTRA: usize = 24;
+
+#[cfg(not(feature = "compact"))]
+const TEXT: usize = 0;
+#[cfg(feature = "compact")]
+const TEXT: usize = 8;
+
+const_assert!(size_of::<PackageDescriptor>() <= 80);
+const_assert!(size_of::<Issue>() <= 240 + EXTRA);
+const_assert!(size_of::<PackageStatusExtended>() <= 336 + STATUS_EXTRA - TEXT);
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn sizes() {
+    assert_eq!(size_of::<PackageDescriptor>(), 80);
+    assert_eq!(size_of::<Issue>(), 240 + EXTRA);
+    assert_eq!(
+        size_of::<PackageStatusExtended>(),
+        336 + STATUS_EXTRA - TEXT
+    );
+}
@@ -0,0 +1,184 @@
+use phylum_types::types::package::{ExploitMaturity, Issue};
+use phylum_types::types::ssvc::*;
+use serde_json::json;
+
+const EXPLOITATION: [Exploitation; 3] =
+    [Exploitation::None, Exploitation::Poc, Exploitation::Active];
+const AUTOMATABLE: [Automatable; 2] = [Automatable::No, Automatable::Yes];
+const TECHNICAL_IMPACT: [TechnicalImpact; 2] = [TechnicalImpact::Partial, TechnicalImpact::Total];
+const IMPACT: [MissionImpact; 3] = [
+    MissionImpact::Low,
+    MissionImpact::Medium,
+    MissionImpact::High,
+];
+
+/// Every combination of inputs, in the order of the guide's tree.
+fn all_inputs() -> Vec<SsvcInputs> {
+    let mut inputs = Vec::new();
+    for exploitation in EXPLOITATION {
+        for automatable in AUTOMATABLE {
+            for technical_impact in TECHNICAL_IMPACT {
+                for mission_impact in IMPACT {
+                    inputs.push(SsvcInputs {
+                        exploitation,
+                        automatable,
+                        technical_impact,
+                        mission_impact,
+                    });
+                }
+            }
+        }
+    }
+    inputs
+}
+
+/// The decision tree of the CISA SSVC Guide, November 2022, as rows of
+/// exploitation, automatable, technical impact, mission and well-being, and
+/// decision.
+const CISA_TREE: &str = "\
+none,no,partial,low,track
+none,no,partial,medium,track
+none,no,partial,high,track
+none,no,total,low,track
+none,no,total,medium,track
+none,no,total,high,track*
+none,yes,partial,low,track
+none,yes,partial,medium,track
+none,yes,partial,high,attend
+none,yes,total,low,track
+none,yes,total,medium,track
+none,yes,total,high,attend
+poc,no,partial,low,track
+poc,no,partial,medium,track
+poc,no,partial,high,track*
+poc,no,total,low,track
+poc,no,total,medium,track*
+poc,no,total,high,attend
+poc,yes,partial,low,track
+poc,yes,partial,medium,track
+poc,yes,partial,high,attend
+poc,yes,total,low,track
+poc,yes,total,medium,track*
+poc,yes,total,high,attend
+active,no,partial,low,track
+active,no,partial,medium,track
+active,no,partial,high,attend
+active,no,total,low,track
+active,no,total,medium,attend
+active,no,total,high,act
+active,yes,partial,low,attend
+active,yes,partial,medium,attend
+active,yes,partial,high,act
+active,yes,total,low,attend
+active,yes,total,medium,act
+active,yes,total,high,act
+";
+
+#[test]
+fn cisa_tree() {
+    let rows: Vec<_> = CISA_TREE.lines().collect();
+    assert_eq!(rows.len(), 36);
+    for row in rows {
+        let fields: Vec<_> = row.split(',').collect();
+        let inputs: SsvcInputs = serde_json::from_value(json!({
+            "exploitation": fields[0],
+            "automatable": fields[1],
+            "technical_impact": fields[2],
+            "mission_impact": fields[3],
+        }))
+        .unwrap();
+        let decision: SsvcDecision = serde_json::from_value(json!(fields[4])).unwrap();
+        assert_eq!(inputs.decide(), decision, "{}", row);
+    }
+}
+
+#[test]
+fn decisions_are_monotonic() {
+    let inputs = all_inputs();
+    for a in &inputs {
+        for b in &inputs {
+            let dominates = a.exploitation >= b.exploitation
+                && a.automatable >= b.automatable
+                && a.technical_impact >= b.technical_impact
+                && a.mission_impact >= b.mission_impact;
+            if dominates {
+                assert!(a.decide() >= b.decide(), "{:?} < {:?}", a, b);
+            }
+        }
+    }
+}
+
+#[test]
+fn decision_extremes() {
+    let inputs = all_inputs();
+    assert_eq!(inputs[0].decide(), SsvcDecision::Track);
+    assert_eq!(inputs[inputs.len() - 1].decide(), SsvcDecision::Act);
+
+    // Only active exploitation calls for acting
+    assert!(inputs
+        .iter()
+        .filter(|inputs| inputs.decide() == SsvcDecision::Act)
+        .all(|inputs| inputs.exploitation == Exploitation::Active));
+}
+
+#[test]
+fn inputs_for_issue() {
+    let mut issue: Issue = serde_json::from_value(json!({
+        "tag": null,
+        "id": "CVE-2021-23337",
+        "title": "Command injection in lodash",
+        "description": "",
+        "severity": "high",
+        "domain": "vulnerability",
+    }))
+    .unwrap();
+
+    let inputs = SsvcInputs::for_issue(&issue, Automatable::No, MissionImpact::Medium);
+    assert_eq!(inputs.exploitation, Exploitation::None);
+    assert_eq!(inputs.technical_impact, TechnicalImpact::Partial);
+    assert_eq!(inputs.decide(), SsvcDecision::Track);
+
+    issue.exploit_maturity = Some(ExploitMaturity::Functional);
+    let inputs = SsvcInputs::for_issue(&issue, Automatable::Yes, MissionImpact::High);
+    assert_eq!(inputs.exploitation, Exploitation::Poc);
+    assert_eq!(inputs.decide(), SsvcDecision::Attend);
+}
+
+#[test]
+fn serialization() {
+    let assessment = SsvcAssessment::new(
+        Some("CVE-2021-23337".into()),
+        SsvcInputs {
+            exploitation: Exploitation::Poc,
+            automatable: Automatable::Yes,
+            technical_impact: TechnicalImpact::Total,
+            mission_impact: MissionImpact::Medium,
+        },
+    );
+    assert_eq!(assessment.decision, SsvcDecision::TrackStar);
+    assert_eq!(assessment.decision.to_string(), "Track*");
+
+    let value = serde_json::to_value(&assessment).unwrap();
+    assert_eq!(
+        value,
+        json!({
+            "issue_id": "CVE-2021-23337",
+            "inputs": {
+                "exploitation": "poc",
+                "automatable": "yes",
+                "technical_impact": "total",
+                "mission_impact": "medium",
+            },
+            "decision": "track*",
+            "decided_at": null,
+        })
+    );
+    assert_eq!(
+        serde_json::from_value::<SsvcAssessment>(value).unwrap(),
+        assessment
+    );
+    assert_eq!(
+        serde_json::from_value::<SsvcDecision>(json!("track_star")).unwrap(),
+        SsvcDecision::TrackStar
+    );
+}
@@ -0,0 +1,69 @@
+#![cfg(deny_unknown_fields)]
+
+use phylum_types::types::job::{
+    JobStatusResponse, JobStatusResponseVariant, SubmitPackageResponse,
+};
+use phylum_types::types::package::{IssueStatus, PackageStatusExtended};
+use serde_json::{json, Value};
+
+#[test]
+fn unknown_fields_are_rejected() {
+    let value = json!({ "job_id": "59482a54-423b-448d-8325-f171c9dc336b" });
+    assert!(serde_json::from_value::<SubmitPackageResponse>(value.clone()).is_ok());
+
+    let mut value = value;
+    value["queued"] = true.into();
+    let error = serde_json::from_value::<SubmitPackageResponse>(value).unwrap_err();
+    assert!(error.to_string().contains("unknown field `queued`"));
+}
+
+fn extended_job() -> Value {
+    serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap()
+}
+
+/// Checks that `value` is accepted, and rejected once `field` is added to the
+/// object at the pointer `at`.
+fn rejects<T: serde::de::DeserializeOwned + std::fmt::Debug>(
+    mut value: Value,
+    at: &str,
+    field: &str,
+) {
+    assert!(serde_json::from_value::<T>(value.clone()).is_ok());
+
+    value.pointer_mut(at).unwrap()[field] = true.into();
+    let error = serde_json::from_value::<T>(value).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains(&format!("unknown field `{field}`")),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn unknown_fields_in_flattened_types_are_rejected() {
+    let job = extended_job();
+    let package = job["packages"][0].clone();
+    let issue = package["issues"][0].clone();
+
+    rejects::<PackageStatusExtended>(package.clone(), "", "flagged");
+    rejects::<IssueStatus>(issue, "", "suppressed");
+    rejects::<PackageStatusExtended>(package, "/issues/0", "suppressed");
+    rejects::<JobStatusResponse<PackageStatusExtended>>(job.clone(), "/packages/0", "flagged");
+    rejects::<JobStatusResponseVariant>(job, "/packages/0", "flagged");
+}
+
+#[test]
+fn aliases_of_flattened_fields_are_accepted() {
+    let mut package = extended_job()["packages"][0].take();
+    let object = package.as_object_mut().unwrap();
+    for (name, alias) in [
+        ("last_updated", "lastUpdated"),
+        ("package_score", "packageScore"),
+    ] {
+        let value = object.remove(name).unwrap();
+        object.insert(alias.into(), value);
+    }
+    assert!(serde_json::from_value::<PackageStatusExtended>(package).is_ok());
+}
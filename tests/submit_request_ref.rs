@@ -0,0 +1,137 @@
+use std::borrow::Cow;
+use std::convert::TryFrom;
+
+use phylum_types::types::borrowed::{
+    IssueRef, PackageDescriptorAndLockfileRef, PackageStatusRef, SubmitPackageRequestRef,
+};
+use phylum_types::types::job::SubmitPackageRequest;
+use phylum_types::types::package::{DependencyKind, PackageRef, PackageRefAndLockfile};
+use serde_json::json;
+
+fn request() -> SubmitPackageRequest {
+    serde_json::from_value(json!({
+        "packages": [
+            {
+                "name": "express",
+                "version": "4.18.1",
+                "type": "npm",
+                "lockfile": "web/package-lock.json",
+            },
+            { "name": "jest", "version": "29.0.0", "type": "npm", "kind": "dev" },
+            {
+                "name": "slf4j-api",
+                "version": "1.7.36",
+                "type": "maven",
+                "namespace": "org.slf4j",
+            },
+        ],
+        "is_user": true,
+        "project": "0bd8a7b6-21f3-4e8b-9d3e-3e6e1ad0a7e8",
+        "label": "main",
+        "group_name": "web",
+        "options": { "priority": "high" },
+        "metadata": { "pipeline": 42 },
+    }))
+    .unwrap()
+}
+
+#[test]
+fn serializes_identically() {
+    let request = request();
+    let borrowed = SubmitPackageRequestRef::from(&request);
+    assert_eq!(
+        serde_json::to_string(&borrowed).unwrap(),
+        serde_json::to_string(&request).unwrap()
+    );
+    assert_eq!(borrowed.into_owned(), request);
+
+    let mut minimal = request;
+    minimal.packages.truncate(1);
+    minimal.group_name = None;
+    minimal.options = Default::default();
+    minimal.metadata = Default::default();
+    let borrowed = SubmitPackageRequestRef::from(&minimal);
+    assert_eq!(
+        serde_json::to_value(&borrowed).unwrap(),
+        serde_json::to_value(&minimal).unwrap()
+    );
+}
+
+#[test]
+fn borrows_from_input() {
+    let json = serde_json::to_string(&request()).unwrap();
+    let borrowed: SubmitPackageRequestRef = serde_json::from_str(&json).unwrap();
+
+    let express = &borrowed.packages[0];
+    assert!(matches!(express.package_descriptor.name, Cow::Borrowed(_)));
+    assert!(matches!(
+        express.package_descriptor.version,
+        Cow::Borrowed(_)
+    ));
+    assert!(matches!(express.lockfile, Some(Cow::Borrowed(_))));
+    assert_eq!(borrowed.packages[1].kind, DependencyKind::Dev);
+    assert!(borrowed.packages[1].lockfile.is_none());
+    let slf4j = &borrowed.packages[2];
+    assert!(matches!(
+        slf4j.package_descriptor.namespace,
+        Some(Cow::Borrowed("org.slf4j"))
+    ));
+    assert!(matches!(borrowed.group_name, Some(Cow::Borrowed("web"))));
+    assert_eq!(borrowed.into_owned(), request());
+}
+
+#[test]
+fn borrows_optional_fields() {
+    let json = r#"{
+        "purl": "pkg:npm/left-pad@1.3.0",
+        "name": "left-pad",
+        "version": "1.3.0",
+        "status": "complete",
+        "last_updated": 1650000000,
+        "license": "WTFPL",
+        "package_score": 0.5,
+        "num_dependencies": 0,
+        "num_vulnerabilities": null
+    }"#;
+    let status: PackageStatusRef = serde_json::from_str(json).unwrap();
+    assert!(matches!(status.purl, Some(Cow::Borrowed(_))));
+    assert!(matches!(status.license, Some(Cow::Borrowed("WTFPL"))));
+
+    let json = r#"{
+        "tag": "HV0001",
+        "id": "issue-1",
+        "title": "Typosquat",
+        "description": "Looks like another package",
+        "severity": "high",
+        "domain": "malicious_code"
+    }"#;
+    let issue: IssueRef = serde_json::from_str(json).unwrap();
+    assert!(matches!(issue.tag, Some(Cow::Borrowed("HV0001"))));
+    assert!(matches!(issue.id, Some(Cow::Borrowed("issue-1"))));
+
+    let json = json.replace(r#""id": "issue-1","#, r#""id": null,"#);
+    let issue: IssueRef = serde_json::from_str(&json).unwrap();
+    assert!(issue.id.is_none());
+}
+
+#[test]
+fn from_package_refs() {
+    let package = PackageRefAndLockfile {
+        package: PackageRef::new("requests", "2.31.0", "pypi"),
+        lockfile: Some("requirements.txt".into()),
+        kind: DependencyKind::Runtime,
+    };
+    let borrowed = PackageDescriptorAndLockfileRef::try_from(&package).unwrap();
+    assert_eq!(
+        serde_json::to_value(&borrowed).unwrap(),
+        json!({
+            "name": "requests",
+            "version": "2.31.0",
+            "type": "pypi",
+            "lockfile": "requirements.txt",
+        })
+    );
+
+    let unknown = PackageRefAndLockfile::from(PackageRef::new("x", "1", "hex"));
+    assert!(PackageDescriptorAndLockfileRef::try_from(&unknown).is_err());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "csv")]
+
+use phylum_types::tabular::{self, FlatPackageRow};
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::lockfile::LockfilePath;
+use phylum_types::types::package::PackageStatusExtended;
+
+fn job() -> JobStatusResponse<PackageStatusExtended> {
+    serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap()
+}
+
+#[test]
+fn package_rows() {
+    let mut job = job();
+    job.packages[0].basic_status.lockfiles = vec![
+        LockfilePath::new("web/package-lock.json").into(),
+        LockfilePath::new("api/package-lock.json").into(),
+    ];
+
+    let rows = tabular::package_rows(&job);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].name, "express");
+    assert_eq!(rows[0].label.as_deref(), Some("main"));
+    assert_eq!(rows[0].num_issues, 1);
+    assert_eq!(
+        rows[0].lockfiles,
+        "web/package-lock.json;api/package-lock.json"
+    );
+    assert_eq!(rows[1].lockfiles, "");
+}
+
+#[test]
+fn package_csv_round_trips() {
+    let job = job();
+    let mut csv = Vec::new();
+    tabular::write_packages(&job, &mut csv).unwrap();
+
+    let text = String::from_utf8(csv.clone()).unwrap();
+    assert_eq!(text.lines().count(), 3);
+    assert!(text
+        .lines()
+        .nth(2)
+        .unwrap()
+        .contains(",qs,6.10.3,complete,BSD-3-Clause,"));
+
+    let rows: Vec<FlatPackageRow> = csv::Reader::from_reader(&csv[..])
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(rows, tabular::package_rows(&job));
+}
+
+#[test]
+fn issue_csv() {
+    let job = job();
+    let rows = tabular::issue_rows(&job);
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].name, "express");
+    assert_eq!(rows[0].tag.as_deref(), Some("CVE-2022-24999"));
+
+    let mut csv = Vec::new();
+    tabular::write_issues(&job, &mut csv).unwrap();
+    let text = String::from_utf8(csv).unwrap();
+    let mut lines = text.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "job_id,project_name,label,ecosystem,name,version,issue_id,tag,title,severity,\
+             domain,ignored"
+        )
+    );
+    assert!(lines
+        .next()
+        .unwrap()
+        .contains(",qs vulnerable to Prototype Pollution,"));
+}
+
+#[test]
+fn empty_job_writes_nothing() {
+    let mut job = job();
+    job.packages.clear();
+    let mut csv = Vec::new();
+    tabular::write_packages(&job, &mut csv).unwrap();
+    assert!(csv.is_empty());
+}
@@ -0,0 +1,74 @@
+//! Wire encodings which must match between the chrono and time backends.
+
+use phylum_types::types::timestamp::{self, Duration, Timestamp};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+#[derive(Serialize, Deserialize)]
+struct Encodings {
+    rfc3339: Timestamp,
+    #[serde(with = "timestamp::epoch_seconds")]
+    seconds: Timestamp,
+    #[serde(with = "timestamp::epoch_millis")]
+    millis: Timestamp,
+}
+
+#[test]
+fn encodings_round_trip() {
+    let value = json!({
+        "rfc3339": "2022-06-01T12:00:00Z",
+        "seconds": 1654084800,
+        "millis": 1654084800123_i64,
+    });
+    let encodings: Encodings = serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(encodings.rfc3339, encodings.seconds);
+    assert_eq!(
+        encodings.millis - encodings.seconds,
+        Duration::milliseconds(123)
+    );
+    assert_eq!(serde_json::to_value(&encodings).unwrap(), value);
+}
+
+#[test]
+fn rfc3339_formats() {
+    let parse = |s: &str| serde_json::from_value::<Timestamp>(json!(s)).unwrap();
+    let format = |t: Timestamp| serde_json::to_value(t).unwrap();
+
+    assert_eq!(
+        format(parse("2022-06-01T14:00:00+02:00")),
+        json!("2022-06-01T12:00:00Z")
+    );
+    assert_eq!(
+        format(parse("2022-06-01T12:00:00.123Z")),
+        json!("2022-06-01T12:00:00.123Z")
+    );
+    assert_eq!(
+        timestamp::to_rfc3339(&parse("2022-06-01T12:00:00Z")),
+        "2022-06-01T12:00:00Z"
+    );
+    assert!(serde_json::from_value::<Timestamp>(json!("2022-06-01")).is_err());
+}
+
+#[test]
+fn epoch_conversions() {
+    let epoch = timestamp::from_epoch_seconds(0).unwrap();
+    assert_eq!(timestamp::to_epoch_millis(&epoch), 0);
+
+    let before = timestamp::from_epoch_millis(-1500).unwrap();
+    assert_eq!(timestamp::to_epoch_seconds(&before), -2);
+    assert_eq!(timestamp::to_epoch_millis(&before), -1500);
+    assert_eq!(epoch - before, Duration::milliseconds(1500));
+}
+
+#[cfg(all(feature = "chrono", feature = "time"))]
+#[test]
+fn time_conversions_with_chrono() {
+    let chrono: chrono::DateTime<chrono::Utc> =
+        serde_json::from_value(json!("2022-06-01T12:00:00.123Z")).unwrap();
+    let timestamp: Timestamp = chrono;
+
+    let time = timestamp::to_offset_date_time(&timestamp).unwrap();
+    assert_eq!(time.unix_timestamp(), 1654084800);
+    assert_eq!(time.millisecond(), 123);
+    assert_eq!(timestamp::from_offset_date_time(time), Some(timestamp));
+}
@@ -0,0 +1,103 @@
+use phylum_types::types::timestamp::Timestamp;
+use phylum_types::types::token::*;
+use phylum_types::types::user::*;
+use serde_json::json;
+
+fn timestamp(value: &str) -> Timestamp {
+    value.parse().unwrap()
+}
+
+#[test]
+fn create() {
+    let request = CreateTokenRequest {
+        name: "github-actions".into(),
+        expires_at: Some(timestamp("2023-01-01T00:00:00Z")),
+        scopes: vec![TokenScope::SubmitJobs, TokenScope::ReadProjects],
+    };
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "name": "github-actions",
+            "expires_at": "2023-01-01T00:00:00Z",
+            "scopes": ["jobs:submit", "projects:read"],
+        })
+    );
+
+    let response: CreateTokenResponse = serde_json::from_value(json!({
+        "id": "6f1e8f0a-3c1b-4d55-8b6e-2b7f0e9a1c33",
+        "name": "github-actions",
+        "masked_value": "****a1b2",
+        "scopes": ["jobs:submit", "projects:read"],
+        "created_at": "2022-06-01T12:00:00Z",
+        "expires_at": "2023-01-01T00:00:00Z",
+        "token": "ph0_5e3c7d1fa1b2",
+    }))
+    .unwrap();
+    assert_eq!(
+        response.metadata.masked_value,
+        UserToken::mask(&response.token)
+    );
+    assert_eq!(response.metadata.last_used_at, None);
+}
+
+#[test]
+fn scopes() {
+    assert!(TokenScope::Admin.implies(TokenScope::ManageGroups));
+    assert!(TokenScope::WriteProjects.implies(TokenScope::ReadProjects));
+    assert!(!TokenScope::ReadProjects.implies(TokenScope::WriteProjects));
+    assert!(!TokenScope::SubmitJobs.implies(TokenScope::ReadPackages));
+    assert_eq!(TokenScope::ReadPackages.to_string(), "packages:read");
+
+    let name = serde_json::to_value(TokenScope::ManageGroups).unwrap();
+    assert_eq!(name, TokenScope::ManageGroups.name());
+}
+
+#[test]
+fn list() {
+    let response: ListApiTokensResponse = serde_json::from_value(json!({
+        "tokens": [
+            {
+                "id": "6f1e8f0a-3c1b-4d55-8b6e-2b7f0e9a1c33",
+                "name": "ci",
+                "masked_value": "****a1b2",
+                "scopes": ["projects:write"],
+                "created_at": "2022-06-01T12:00:00Z",
+                "expires_at": "2022-07-01T00:00:00Z",
+                "last_used_at": "2022-06-30T08:00:00Z",
+            },
+            {
+                "id": "0a4b5c6d-7e8f-4a1b-9c2d-3e4f5a6b7c8d",
+                "name": "laptop",
+                "masked_value": "****9z8y",
+                "created_at": "2022-06-02T12:00:00Z",
+            },
+        ],
+    }))
+    .unwrap();
+
+    let ci = &response.tokens[0];
+    assert!(ci.allows(TokenScope::ReadProjects));
+    assert!(!ci.allows(TokenScope::SubmitJobs));
+    assert!(response.tokens[1].allows(TokenScope::ManageGroups));
+
+    let now = timestamp("2022-08-01T00:00:00Z");
+    assert!(ci.is_expired_at(now));
+    let active: Vec<_> = response.active_at(now).map(|token| &token.name).collect();
+    assert_eq!(active, ["laptop"]);
+}
+
+#[test]
+fn mask() {
+    assert_eq!(UserToken::mask("ph0_5e3c7d1fa1b2"), "****a1b2");
+    assert_eq!(UserToken::mask("ab"), "****ab");
+}
+
+#[test]
+fn revoke() {
+    let request: RevokeTokenRequest =
+        serde_json::from_value(json!({ "id": "6f1e8f0a-3c1b-4d55-8b6e-2b7f0e9a1c33" })).unwrap();
+    assert_eq!(
+        serde_json::to_value(&request).unwrap()["id"],
+        "6f1e8f0a-3c1b-4d55-8b6e-2b7f0e9a1c33"
+    );
+}
@@ -0,0 +1,110 @@
+use phylum_types::types::package::ScoreDynamicsPoint;
+use phylum_types::types::timestamp::{Duration, Timestamp};
+use phylum_types::types::trend::*;
+use serde_json::json;
+
+fn point(date: &str, score: f32) -> ScoreDynamicsPoint {
+    ScoreDynamicsPoint {
+        date_time: timestamp(date),
+        score,
+        label: date[..10].into(),
+    }
+}
+
+fn timestamp(date: &str) -> Timestamp {
+    date.parse().unwrap()
+}
+
+fn declining() -> Vec<ScoreDynamicsPoint> {
+    // Out of order on purpose
+    vec![
+        point("2022-01-11T00:00:00Z", 0.75),
+        point("2022-01-01T00:00:00Z", 1.),
+        point("2022-01-21T00:00:00Z", 0.25),
+        point("2022-01-31T00:00:00Z", 0.5),
+    ]
+}
+
+#[test]
+fn trend() {
+    let trend = declining().trend().unwrap();
+    assert_eq!(trend.direction, TrendDirection::Declining);
+    assert!(trend.slope_per_day < 0.);
+    assert_eq!(trend.start, timestamp("2022-01-01T00:00:00Z"));
+    assert_eq!(trend.points, 4);
+
+    let flat = [
+        point("2022-01-01T00:00:00Z", 0.5),
+        point("2022-03-01T00:00:00Z", 0.5),
+    ];
+    assert_eq!(flat.trend().unwrap().direction, TrendDirection::Stable);
+
+    assert!(flat[..1].trend().is_none());
+    assert!(Vec::<ScoreDynamicsPoint>::new().trend().is_none());
+
+    let value = serde_json::to_value(&trend).unwrap();
+    assert_eq!(value["direction"], "declining");
+    assert_eq!(value["points"], 4);
+    assert_eq!(value["end"], "2022-01-31T00:00:00Z");
+}
+
+#[test]
+fn delta_over() {
+    let points = declining();
+
+    let delta = points.delta_over(Duration::days(10)).unwrap();
+    assert_eq!(delta.from, timestamp("2022-01-21T00:00:00Z"));
+    assert_eq!((delta.from_score, delta.to_score), (0.25, 0.5));
+    assert!(delta.change > 0.);
+
+    // A window longer than the series compares against the earliest point
+    let delta = points.delta_over(Duration::days(365)).unwrap();
+    assert_eq!(delta.from_score, 1.);
+
+    assert!(points[..0].delta_over(Duration::days(1)).is_none());
+
+    // Windows reaching before the earliest representable time do not panic
+    let delta = points.delta_over(Duration::MAX).unwrap();
+    assert_eq!(delta.from_score, 1.);
+}
+
+#[test]
+fn worst_point() {
+    assert_eq!(declining().worst_point().unwrap().label, "2022-01-21");
+    assert!(Vec::<ScoreDynamicsPoint>::new().worst_point().is_none());
+}
+
+#[test]
+fn resample() {
+    let samples = declining().resample(Duration::days(7));
+    let scores: Vec<_> = samples
+        .iter()
+        .map(|sample| (sample.label.as_str(), sample.score))
+        .collect();
+    assert_eq!(
+        scores,
+        [
+            ("2022-01-01", 1.),
+            ("2022-01-01", 1.),
+            ("2022-01-11", 0.75),
+            ("2022-01-21", 0.25),
+            ("2022-01-21", 0.25),
+        ]
+    );
+    assert_eq!(
+        serde_json::to_value(&samples[1]).unwrap(),
+        json!({ "dateTime": "2022-01-08T00:00:00Z", "score": 1.0, "label": "2022-01-01" })
+    );
+
+    assert!(declining().resample(Duration::seconds(0)).is_empty());
+
+    // Stepping past the latest representable time ends the samples
+    let samples = declining().resample(Duration::MAX);
+    assert_eq!(samples.len(), 1);
+
+    // Intervals which would produce too many samples produce none
+    let thirty_days = Duration::days(30);
+    assert_eq!(declining().resample(thirty_days / 5_000).len(), 5_001);
+    assert_eq!(MAX_RESAMPLED_POINTS, 10_000);
+    assert!(declining().resample(thirty_days / 10_000).is_empty());
+}
@@ -0,0 +1,90 @@
+use std::collections::BTreeMap;
+
+use phylum_types::types::common::Status;
+use phylum_types::types::package::{PackageType, RiskDomain, RiskLevel};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+
+/// Every variant deserializes from its serialized name, in text and binary
+/// formats.
+fn round_trips<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(variants: &[T]) {
+    for variant in variants {
+        let value = serde_json::to_value(variant).unwrap();
+        assert!(value.is_string(), "{}", value);
+        assert_eq!(&serde_json::from_value::<T>(value).unwrap(), variant);
+
+        let mut cbor = Vec::new();
+        ciborium::ser::into_writer(variant, &mut cbor).unwrap();
+        assert_eq!(
+            &ciborium::de::from_reader::<T, _>(&cbor[..]).unwrap(),
+            variant
+        );
+
+        let msgpack = rmp_serde::to_vec_named(variant).unwrap();
+        assert_eq!(&rmp_serde::from_slice::<T>(&msgpack).unwrap(), variant);
+
+        // Written as the index of the variant
+        let bincode = bincode::serialize(variant).unwrap();
+        assert_eq!(&bincode::deserialize::<T>(&bincode).unwrap(), variant);
+    }
+}
+
+#[test]
+fn all_variants() {
+    round_trips(&[
+        PackageType::Npm,
+        PackageType::PyPi,
+        PackageType::Maven,
+        PackageType::RubyGems,
+        PackageType::Nuget,
+        PackageType::Cargo,
+        PackageType::Golang,
+        PackageType::Composer,
+        PackageType::Conan,
+    ]);
+    round_trips(&[
+        RiskLevel::Info,
+        RiskLevel::Low,
+        RiskLevel::Medium,
+        RiskLevel::High,
+        RiskLevel::Critical,
+    ]);
+    round_trips(&[
+        RiskDomain::AuthorRisk,
+        RiskDomain::EngineeringRisk,
+        RiskDomain::Malicious,
+        RiskDomain::Vulnerabilities,
+        RiskDomain::LicenseRisk,
+    ]);
+    round_trips(&[Status::Complete, Status::Incomplete]);
+}
+
+#[test]
+fn aliases() {
+    let domain: RiskDomain = serde_json::from_value(json!("malicious")).unwrap();
+    assert_eq!(domain, RiskDomain::Malicious);
+    assert_eq!(serde_json::to_value(domain).unwrap(), "malicious_code");
+}
+
+#[test]
+fn unknown_variant() {
+    let error = serde_json::from_value::<RiskLevel>(json!("severe")).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown variant `severe`, expected one of `info`, `low`, `medium`, `high`, `critical`"
+    );
+
+    assert!(serde_json::from_value::<PackageType>(json!("Npm")).is_err());
+    assert!(serde_json::from_value::<Status>(json!(1)).is_err());
+}
+
+#[test]
+fn map_keys() {
+    let counts: BTreeMap<RiskLevel, u32> =
+        serde_json::from_value(json!({ "high": 2, "low": 1 })).unwrap();
+    assert_eq!(counts[&RiskLevel::High], 2);
+
+    let escaped: PackageType = serde_json::from_str(r#""n\u0070m""#).unwrap();
+    assert_eq!(escaped, PackageType::Npm);
+}
@@ -0,0 +1,56 @@
+use std::convert::TryFrom;
+
+use phylum_types::types::package::{self, PackageType};
+use phylum_types::types::{v1, v2};
+
+#[allow(deprecated)]
+#[test]
+fn package_paths_are_versioned_types() {
+    let descriptor: v1::PackageDescriptor =
+        package::PackageDescriptor::new("lodash", "4.17.21", PackageType::Npm);
+    let package: package::PackageRef = v2::PackageRef::new("lodash", "4.17.21", PackageType::Npm);
+    assert_eq!(v2::PackageRef::from(&descriptor), package);
+}
+
+#[allow(deprecated)]
+#[test]
+fn v1_to_v2_and_back() {
+    let descriptor = v1::PackageDescriptor::maven("org.apache.commons", "commons-lang3", "3.12.0");
+    let package = v2::PackageRef::from(descriptor.clone());
+    assert_eq!(package.namespace.as_deref(), Some("org.apache.commons"));
+    assert_eq!(
+        v1::PackageDescriptor::try_from(package).unwrap(),
+        descriptor
+    );
+    assert_eq!(
+        v1::PackageDescriptor::new("commons-lang3", "3.12.0", PackageType::Maven)
+            .with_namespace("org.apache.commons"),
+        descriptor
+    );
+
+    let specifier = v1::PackageSpecifier {
+        registry: "cargo".into(),
+        name: "serde".into(),
+        version: "1.0.0".into(),
+    };
+    let package = v2::PackageRef::from(specifier.clone());
+    assert_eq!(package.package_type(), Some(PackageType::Cargo));
+    assert_eq!(v1::PackageSpecifier::from(package), specifier);
+}
+
+#[allow(deprecated)]
+#[test]
+fn unknown_ecosystems_only_convert_forward() {
+    let specifier = v1::PackageSpecifier {
+        registry: "hackage".into(),
+        name: "aeson".into(),
+        version: "2.1.0".into(),
+    };
+    let package =
+        v2::PackageRefAndLockfile::from(v1::PackageSpecifierAndLockfile::from(&specifier));
+    assert_eq!(
+        package.package.ecosystem,
+        v2::Ecosystem::Unknown("hackage".into())
+    );
+    assert!(v1::PackageDescriptorAndLockfile::try_from(package).is_err());
+}
@@ -0,0 +1,133 @@
+use phylum_types::types::job::JobStatusResponse;
+use phylum_types::types::package::{
+    IssueStatus, PackageStatusExtended, PackageType, RiskDomain, RiskLevel,
+};
+use phylum_types::types::view::*;
+use serde_json::json;
+
+fn package() -> PackageStatusExtended {
+    let job: JobStatusResponse<PackageStatusExtended> =
+        serde_json::from_str(include_str!("fixtures/job_status_extended.json")).unwrap();
+    let mut package = job.packages.into_iter().next().unwrap();
+
+    let issue = |title: &str, severity: &str, domain: &str, ignored: Option<&str>| -> IssueStatus {
+        serde_json::from_value(json!({
+            "title": title,
+            "description": "",
+            "severity": severity,
+            "domain": domain,
+            "ignored": ignored,
+        }))
+        .unwrap()
+    };
+    package.issues = vec![
+        issue("Prototype pollution", "high", "vulnerability", None),
+        issue("Unmaintained", "low", "engineering", None),
+        issue(
+            "Typosquat of expres",
+            "critical",
+            "malicious_code",
+            Some("false positive"),
+        ),
+    ];
+    package
+}
+
+fn titles<'a>(filter: &'a IssueFilter, package: &'a PackageStatusExtended) -> Vec<&'a str> {
+    filter
+        .apply(package)
+        .map(|status| status.issue.title.as_str())
+        .collect()
+}
+
+#[test]
+fn empty_filter() {
+    let filter = IssueFilter::default();
+    assert!(filter.is_empty());
+    assert_eq!(serde_json::to_value(&filter).unwrap(), json!({}));
+
+    let package = package();
+    assert_eq!(
+        titles(&filter, &package),
+        ["Prototype pollution", "Unmaintained"]
+    );
+}
+
+#[test]
+fn criteria() {
+    let package = package();
+
+    let filter = IssueFilter {
+        min_severity: Some(RiskLevel::Medium),
+        include_ignored: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        titles(&filter, &package),
+        ["Prototype pollution", "Typosquat of expres"]
+    );
+
+    let filter = IssueFilter {
+        domains: vec![RiskDomain::EngineeringRisk],
+        ..Default::default()
+    };
+    assert_eq!(titles(&filter, &package), ["Unmaintained"]);
+
+    let filter = IssueFilter {
+        query: Some("POLLUTION".into()),
+        ..Default::default()
+    };
+    assert_eq!(titles(&filter, &package), ["Prototype pollution"]);
+
+    let filter = IssueFilter {
+        ecosystems: vec![PackageType::PyPi],
+        ..Default::default()
+    };
+    assert!(titles(&filter, &package).is_empty());
+}
+
+#[test]
+fn saved_view() {
+    let response: ListViewsResponse = serde_json::from_value(json!({
+        "views": [
+            {
+                "id": "8d3c2a36-5f0e-4a55-9d0e-0b5e0a6c8a11",
+                "name": "triage",
+                "owner": "lead@acme.example",
+                "scope": { "type": "group", "group_name": "web" },
+                "filter": { "min_severity": "high", "domains": ["vulnerability"] },
+                "created_at": "2022-06-01T12:00:00Z",
+            },
+            {
+                "id": "0e5d1c0a-8a8b-4b8e-9f3c-2f2b8f6f1d22",
+                "name": "triage",
+                "owner": "Dev@acme.example",
+                "filter": {},
+                "created_at": "2022-06-02T12:00:00Z",
+                "updated_at": "2022-06-03T12:00:00Z",
+            },
+        ],
+    }))
+    .unwrap();
+
+    let shared = &response.views[0];
+    assert!(shared.scope.is_shared());
+    assert_eq!(shared.filter.min_severity, Some(RiskLevel::High));
+    assert_eq!(response.views[1].scope, ViewScope::Private);
+
+    let own = response.find("triage", "dev@acme.example").unwrap();
+    assert_eq!(own.owner, "Dev@acme.example");
+    let other = response.find("triage", "someone@acme.example").unwrap();
+    assert_eq!(other, shared);
+    assert!(response.find("missing", "dev@acme.example").is_none());
+
+    let request = SaveViewRequest::from(shared);
+    assert_eq!(
+        serde_json::to_value(&request).unwrap(),
+        json!({
+            "name": "triage",
+            "scope": { "type": "group", "group_name": "web" },
+            "filter": { "min_severity": "high", "domains": ["vulnerability"] },
+        })
+    );
+}
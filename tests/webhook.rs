@@ -0,0 +1,103 @@
+use phylum_types::types::package::{PackageRef, PackageType};
+use phylum_types::types::timestamp;
+use phylum_types::types::webhook::{self, SignatureError, WebhookDelivery, WebhookEventKind};
+use serde_json::json;
+
+const SIGNATURE: &str = "sha256=10e24130ce219d8905e577af6970b2278827738601dc4b887032875ea9d179bf";
+
+#[test]
+fn delivery() {
+    let delivery: WebhookDelivery = serde_json::from_value(json!({
+        "id": "7b1d2c3e-6f3a-4b9e-9d0c-5a4f1e2d3c4b",
+        "attempt": 2,
+        "sent_at": "2022-06-01T12:00:00Z",
+        "event": {
+            "type": "package_quarantined",
+            "data": {
+                "package": { "name": "left-pad", "version": "1.3.0", "type": "npm" },
+                "quarantined_at": "2022-06-01T11:59:00Z",
+                "release_at": null,
+                "reason": "Published less than 7 days ago",
+            },
+        },
+    }))
+    .unwrap();
+
+    assert!(delivery.is_retry());
+    assert_eq!(delivery.event.kind(), WebhookEventKind::PackageQuarantined);
+    assert_eq!(delivery.event.kind().to_string(), "package_quarantined");
+    match &delivery.event {
+        webhook::WebhookEvent::PackageQuarantined(quarantined) => assert_eq!(
+            quarantined.package,
+            PackageRef::new("left-pad", "1.3.0", PackageType::Npm)
+        ),
+        event => panic!("unexpected event {:?}", event),
+    }
+
+    let value = serde_json::to_value(&delivery).unwrap();
+    assert_eq!(value["event"]["type"], "package_quarantined");
+}
+
+#[test]
+fn event_kind_names_match_serde() {
+    for kind in [
+        WebhookEventKind::JobCompleted,
+        WebhookEventKind::PolicyFailed,
+        WebhookEventKind::CriticalIssue,
+        WebhookEventKind::PackageQuarantined,
+    ] {
+        assert_eq!(serde_json::to_value(kind).unwrap(), kind.name());
+    }
+}
+
+#[test]
+fn signature_header() {
+    let digest = webhook::parse_signature(SIGNATURE).unwrap();
+    assert_eq!(webhook::format_signature(&digest), SIGNATURE);
+
+    assert_eq!(
+        webhook::parse_signature("sha1=10e24130"),
+        Err(SignatureError::Malformed)
+    );
+    assert_eq!(
+        webhook::parse_signature(&SIGNATURE.replace('f', "g")),
+        Err(SignatureError::Malformed)
+    );
+    assert_eq!(
+        webhook::parse_signature(&SIGNATURE.replace("bf", "bé")),
+        Err(SignatureError::Malformed)
+    );
+}
+
+#[test]
+fn timestamp_tolerance() {
+    let now = timestamp::from_epoch_seconds(1_650_000_000).unwrap();
+    assert!(webhook::check_timestamp(1_650_000_000 - 300, &now, 300).is_ok());
+    assert!(webhook::check_timestamp(1_650_000_000 + 300, &now, 300).is_ok());
+    assert_eq!(
+        webhook::check_timestamp(1_650_000_000 - 301, &now, 300),
+        Err(SignatureError::Expired)
+    );
+}
+
+#[cfg(feature = "webhook-verify")]
+#[test]
+fn sign_and_verify() {
+    let secret = b"It's a Secret to Everybody";
+    let body = b"Hello, World!";
+    assert_eq!(webhook::sign(secret, 1_650_000_000, body), SIGNATURE);
+
+    assert!(webhook::verify(secret, SIGNATURE, 1_650_000_000, body).is_ok());
+    assert_eq!(
+        webhook::verify(secret, SIGNATURE, 1_650_000_001, body),
+        Err(SignatureError::Mismatch)
+    );
+    assert_eq!(
+        webhook::verify(secret, SIGNATURE, 1_650_000_000, b"Hello, World?"),
+        Err(SignatureError::Mismatch)
+    );
+    assert_eq!(
+        webhook::verify(secret, "sha256=", 1_650_000_000, body),
+        Err(SignatureError::Malformed)
+    );
+}